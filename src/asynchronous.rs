@@ -0,0 +1,152 @@
+//! The async flavour of the client, enabled with the `async` cargo feature.
+//!
+//! It exposes the same resource groups and takes the same body and params types as the
+//! blocking [`Paystack`](crate::prelude::Paystack), but every method returns a future.
+pub mod bulk_charges;
+pub mod charge;
+pub mod control_panel;
+pub mod customers;
+pub mod dedicated_nuban;
+pub mod disputes;
+pub mod invoices;
+pub mod miscellaneous;
+pub mod payment_pages;
+pub mod plans;
+pub mod products;
+pub mod refund;
+pub mod settlements;
+pub mod subaccounts;
+pub mod subscription;
+pub mod transactions;
+pub mod transactions_split;
+pub mod transfer_recipients;
+pub mod transfers;
+pub mod transfers_control;
+pub mod verification;
+pub mod webhooks;
+
+use crate::{
+    auth_init,
+    builder::{Config, PaystackBuilder},
+    error::PaystackError,
+    secret::{KeyMode, SecretKey},
+    utils::asynchronous::HttpClient,
+    webhooks::Webhooks,
+};
+use std::sync::Arc;
+
+use self::{
+    bulk_charges::BulkCharges, charge::Charge, control_panel::ControlPanel, customers::Customer,
+    dedicated_nuban::DedicatedNuban, disputes::Disputes, invoices::Invoices,
+    miscellaneous::Miscellaneous, payment_pages::PaymentPages, plans::Plans, products::Products,
+    refund::Refunds, settlements::Settlements, subaccounts::Subaccount, subscription::Subscription,
+    transactions::Transaction, transactions_split::TransactionSplit,
+    transfer_recipients::TransferRecipients, transfers::Transfers,
+    transfers_control::TransfersControl, verification::Verification,
+};
+
+/// Async Paystack client. Use it from within an async runtime such as tokio.
+/// Every resource shares one connection pool, and cloning the client is cheap.
+/// ```no_run
+/// use paystack_rs::asynchronous::Paystack;
+/// use paystack_rs::prelude::{Currency, InitializeTransactionBody, Money};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let paystack = Paystack::new("sk_test_xxxx".to_string());
+/// let body = InitializeTransactionBody {
+///     email: "randomemail@gmail.com".to_string(),
+///     amount: Money::from_major(100, Currency::NGN).unwrap(),
+///     ..Default::default()
+/// };
+/// paystack.transaction.initialize_transaction(body).await;
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Paystack {
+    pub transaction: Transaction,
+    pub transaction_split: TransactionSplit,
+    pub refund: Refunds,
+    pub subaccounts: Subaccount,
+    pub dedicated_nuban: DedicatedNuban,
+    pub plans: Plans,
+    pub subscription: Subscription,
+    pub products: Products,
+    pub payment_pages: PaymentPages,
+    pub invoices: Invoices,
+    pub settlements: Settlements,
+    pub transfer_recipients: TransferRecipients,
+    pub transfers: Transfers,
+    pub transfers_control: TransfersControl,
+    pub bulk_charges: BulkCharges,
+    pub control_panel: ControlPanel,
+    pub charge: Charge,
+    pub disputes: Disputes,
+    pub verification: Verification,
+    pub miscellaneous: Miscellaneous,
+    pub customers: Customer,
+    /// Verifies the signature of webhooks sent to this integration
+    pub webhooks: Webhooks,
+    http: Arc<HttpClient>,
+}
+
+impl Paystack {
+    /// Create a client with the default settings.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the TLS backend cannot be initialized, like `reqwest::Client::new`.
+    /// Use [`Paystack::builder`] to handle that error instead.
+    pub fn new(key: String) -> Paystack {
+        let http = HttpClient::new(SecretKey::new(key), Config::default())
+            .expect("failed to initialize HTTP client");
+        Paystack::with_client(http)
+    }
+
+    /// Start configuring a client with a custom base URL, timeouts, proxy, headers or user agent.
+    /// Finish with [`PaystackBuilder::build_async`].
+    pub fn builder(key: String) -> PaystackBuilder {
+        PaystackBuilder::new(key)
+    }
+
+    /// Create a client with the default settings from `PAYSTACK_SECRET_KEY` and, if set,
+    /// `PAYSTACK_BASE_URL`, read from the environment or a `.env` file.
+    /// Use [`PaystackBuilder::from_env`] to configure it further.
+    pub fn from_env() -> Result<Paystack, PaystackError> {
+        PaystackBuilder::from_env()?.build_async()
+    }
+
+    /// Whether the client uses a test or a live key. `None` if the key has neither prefix.
+    pub fn mode(&self) -> Option<KeyMode> {
+        self.http.key.mode()
+    }
+
+    pub(crate) fn with_client(http: HttpClient) -> Paystack {
+        let http = Arc::new(http);
+        Paystack {
+            transaction: auth_init!(Transaction, http),
+            transaction_split: auth_init!(TransactionSplit, http),
+            refund: auth_init!(Refunds, http),
+            subaccounts: auth_init!(Subaccount, http),
+            dedicated_nuban: auth_init!(DedicatedNuban, http),
+            plans: auth_init!(Plans, http),
+            subscription: auth_init!(Subscription, http),
+            products: auth_init!(Products, http),
+            invoices: auth_init!(Invoices, http),
+            settlements: auth_init!(Settlements, http),
+            transfer_recipients: auth_init!(TransferRecipients, http),
+            transfers: auth_init!(Transfers, http),
+            transfers_control: auth_init!(TransfersControl, http),
+            bulk_charges: auth_init!(BulkCharges, http),
+            control_panel: auth_init!(ControlPanel, http),
+            disputes: auth_init!(Disputes, http),
+            verification: auth_init!(Verification, http),
+            miscellaneous: auth_init!(Miscellaneous, http),
+            charge: auth_init!(Charge, http),
+            payment_pages: auth_init!(PaymentPages, http),
+            customers: auth_init!(Customer, http),
+            webhooks: Webhooks::new(http.key.clone()),
+            http,
+        }
+    }
+}
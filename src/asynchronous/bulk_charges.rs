@@ -0,0 +1,85 @@
+use crate::{
+    error::PaystackError,
+    pagination::PageStream,
+    paystack::bulk_charges::{
+        FetchChargesInABatchParams, ListBulkChargesParams, BULK_CHARGES_PATH,
+    },
+    response::PaystackResponse,
+    utils::asynchronous::{make_get_request, HttpClient},
+};
+use serde_json::Value as JSON;
+use std::sync::Arc;
+
+/// The Bulk Charges API allows you create and manage multiple recurring payments from your customers
+#[derive(Debug, Clone)]
+pub struct BulkCharges {
+    pub(crate) http: Arc<HttpClient>,
+}
+
+impl BulkCharges {
+    /// This lists all bulk charge batches created by the integration. Statuses can be active, paused, or complete.
+    pub async fn list_bulk_charges(
+        &self,
+        params: Option<ListBulkChargesParams>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_get_request(&self.http, BULK_CHARGES_PATH, params).await
+    }
+
+    /// Stream every bulk charge batch, fetching further pages as they are needed
+    pub fn list_bulk_charges_stream(
+        &self,
+        params: ListBulkChargesParams,
+    ) -> PageStream<'static, JSON> {
+        let http = self.http.clone();
+        PageStream::new(params, move |params| {
+            let http = http.clone();
+            async move { make_get_request(&http, BULK_CHARGES_PATH, Some(params)).await }
+        })
+    }
+
+    /// This endpoint retrieves a specific batch code.
+    /// It also returns useful information on its progress by way of the `total_charges` and `pending_charges` attributes.
+    /// - id_or_code:
+    ///   An ID or code for the charge whose batches you want to retrieve.
+    pub async fn fetch_bulk_charge_batch(
+        &self,
+        id_or_code: &str,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", BULK_CHARGES_PATH, id_or_code);
+        make_get_request(&self.http, &path, None::<String>).await
+    }
+    /// This endpoint retrieves the charges associated with a specified batch code. Pagination parameters are available.
+    /// You can also filter by status. Charge statuses can be pending, success or failed.
+    /// - id_or_code:
+    ///   An ID or code for the charge whose batches you want to retrieve.
+    pub async fn fetch_charges_in_a_batch(
+        &self,
+        id_or_code: &str,
+        params: FetchChargesInABatchParams,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", BULK_CHARGES_PATH, id_or_code);
+        make_get_request(&self.http, &path, Some(params)).await
+    }
+
+    /// Use this endpoint to pause processing a batch
+    /// - batch_code:
+    ///   The batch code for the bulk charge you want to pause
+    pub async fn pause_bulk_charge_batch(
+        &self,
+        batch_code: &str,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/pause/{}", BULK_CHARGES_PATH, batch_code);
+        make_get_request(&self.http, &path, None::<String>).await
+    }
+
+    /// Use this endpoint to pause processing a batch
+    /// - batch_code:
+    ///   The batch code for the bulk charge you want to pause
+    pub async fn resume_bulk_charge_batch(
+        &self,
+        batch_code: &str,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/resume/{}", BULK_CHARGES_PATH, batch_code);
+        make_get_request(&self.http, &path, None::<String>).await
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{
+    error::PaystackError,
+    paystack::charge::{
+        CreateChargeBody, SubmitAddressBody, SubmitBirthdayBody, SubmitOTPBody, SubmitPhoneBody,
+        SubmitPinBody, CHARGE_PATH,
+    },
+    paystack::transactions::TransactionData,
+    response::PaystackResponse,
+    utils::asynchronous::{make_get_request, make_idempotent_request, make_request, HttpClient},
+    validation,
+};
+use reqwest::Method;
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub struct Charge {
+    pub(crate) http: Arc<HttpClient>,
+}
+
+impl Charge {
+    // TODO: link payment channel here
+    /// Initiate a payment by integrating the [][payment channel] of your choice.
+    pub async fn create_charge(
+        &self,
+        body: CreateChargeBody<'_>,
+    ) -> Result<PaystackResponse<TransactionData>, PaystackError> {
+        body.validate()?;
+        let idempotent = body.reference.is_some();
+        make_idempotent_request(
+            &self.http,
+            CHARGE_PATH,
+            Some(body),
+            Method::POST,
+            idempotent,
+        )
+        .await
+    }
+
+    /// Submit PIN to continue a charge
+    pub async fn submit_pin(
+        &self,
+        body: SubmitPinBody<'_>,
+    ) -> Result<PaystackResponse<TransactionData>, PaystackError> {
+        let path = format!("{}/submit_pin", CHARGE_PATH);
+        make_request(&self.http, &path, Some(body), Method::POST).await
+    }
+
+    /// Submit OTP to complete a charge
+    pub async fn submit_otp(
+        &self,
+        body: SubmitOTPBody<'_>,
+    ) -> Result<PaystackResponse<TransactionData>, PaystackError> {
+        let path = format!("{}/submit_otp", CHARGE_PATH);
+        make_request(&self.http, &path, Some(body), Method::POST).await
+    }
+
+    /// Submit phone when requested
+    pub async fn submit_phone(
+        &self,
+        body: SubmitPhoneBody<'_>,
+    ) -> Result<PaystackResponse<TransactionData>, PaystackError> {
+        let path = format!("{}/submit_phone", CHARGE_PATH);
+        make_request(&self.http, &path, Some(body), Method::POST).await
+    }
+
+    /// Submit birthday when requested
+    pub async fn submit_birthday(
+        &self,
+        body: SubmitBirthdayBody<'_>,
+    ) -> Result<PaystackResponse<TransactionData>, PaystackError> {
+        let path = format!("{}/submit_birthday", CHARGE_PATH);
+        make_request(&self.http, &path, Some(body), Method::POST).await
+    }
+
+    /// Submit address to continue a charge
+    pub async fn submit_address(
+        &self,
+        body: SubmitAddressBody<'_>,
+    ) -> Result<PaystackResponse<TransactionData>, PaystackError> {
+        let path = format!("{}/submit_address", CHARGE_PATH);
+        make_request(&self.http, &path, Some(body), Method::POST).await
+    }
+
+    /// When you get "pending" as a charge status or if there was an exception when calling any of the /charge endpoints,
+    /// wait 10 seconds or more, then make a check to see if its status has changed.
+    /// Don't call too early as you may get a lot more pending than you should.
+    pub async fn check_pending_charge(
+        &self,
+        reference: &str,
+    ) -> Result<PaystackResponse<TransactionData>, PaystackError> {
+        validation::lookup("reference", reference)?;
+        let path = format!("{}/{}", CHARGE_PATH, reference);
+        make_get_request(&self.http, &path, None::<String>).await
+    }
+}
@@ -0,0 +1,34 @@
+use crate::{
+    error::PaystackError,
+    paystack::control_panel::{UpdatePaymentSessionTimeoutBody, CONTROL_PANEL_PATH},
+    response::PaystackResponse,
+    utils::asynchronous::{make_get_request, make_request, HttpClient},
+};
+use reqwest::Method;
+use serde_json::Value as JSON;
+use std::sync::Arc;
+
+/// The Control Panel API allows you manage some settings on your integration
+#[derive(Debug, Clone)]
+pub struct ControlPanel {
+    pub(crate) http: Arc<HttpClient>,
+}
+
+impl ControlPanel {
+    /// Fetch the payment session timeout on your integration
+    pub async fn fetch_payment_session_timeout(
+        &self,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/payment_session_timeout", CONTROL_PANEL_PATH);
+        make_get_request(&self.http, &path, None::<String>).await
+    }
+
+    /// Update the payment session timeout on your integration
+    pub async fn update_payment_session_timeout(
+        &self,
+        body: UpdatePaymentSessionTimeoutBody,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/payment_session_timeout", CONTROL_PANEL_PATH);
+        make_request(&self.http, &path, Some(body), Method::PUT).await
+    }
+}
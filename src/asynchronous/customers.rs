@@ -0,0 +1,90 @@
+use crate::{
+    error::PaystackError,
+    pagination::PageStream,
+    paystack::customers::{
+        CreateCustomerBody, DeactivateAuthorizationBody, ListCustomersParams, UpdateCustomerBody,
+        ValidateCustomerBody, WhitelistOrBlacklistCustomerBody, CUSTOMER_PATH,
+    },
+    response::PaystackResponse,
+    utils::asynchronous::{make_get_request, make_request, HttpClient},
+};
+use reqwest::Method;
+use serde_json::Value as JSON;
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub struct Customer {
+    pub(crate) http: Arc<HttpClient>,
+}
+
+impl Customer {
+    /// Create a customer on your integration
+    pub async fn create_customer(
+        &self,
+        body: CreateCustomerBody<'_>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_request(&self.http, CUSTOMER_PATH, Some(body), Method::POST).await
+    }
+
+    /// List customers available on your integration.
+    pub async fn list_customers(
+        &self,
+        queries: Option<ListCustomersParams>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_get_request(&self.http, CUSTOMER_PATH, queries).await
+    }
+
+    /// Stream every customer, fetching further pages as they are needed
+    pub fn list_customers_stream(&self, params: ListCustomersParams) -> PageStream<'static, JSON> {
+        let http = self.http.clone();
+        PageStream::new(params, move |params| {
+            let http = http.clone();
+            async move { make_get_request(&http, CUSTOMER_PATH, Some(params)).await }
+        })
+    }
+    /// Get details of a customer on your integration.
+    /// takes a parameter email_or_code. An email or customer code for the customer you want to fetch
+    pub async fn fetch_customer(
+        &self,
+        email_or_code: &str,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", CUSTOMER_PATH, email_or_code);
+        make_get_request(&self.http, &path, None::<String>).await
+    }
+
+    pub async fn update_customer(
+        &self,
+        code: &str,
+        body: UpdateCustomerBody<'_>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", CUSTOMER_PATH, code);
+        make_request(&self.http, &path, Some(body), Method::PUT).await
+    }
+
+    pub async fn validate_customer(
+        &self,
+        code: &str,
+        body: ValidateCustomerBody<'_>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}/identification", CUSTOMER_PATH, code);
+        make_request(&self.http, &path, Some(body), Method::POST).await
+    }
+
+    /// Whitelist or blacklist a customer on your integration
+    pub async fn whitelist_or_blacklist_customer(
+        &self,
+        body: WhitelistOrBlacklistCustomerBody<'_>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/set_risk_action", CUSTOMER_PATH);
+        make_request(&self.http, &path, Some(body), Method::POST).await
+    }
+
+    /// Deactivate an authorization when the card needs to be forgotten
+    pub async fn deactivate_authorization(
+        &self,
+        body: DeactivateAuthorizationBody<'_>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/deactivate_authorization", CUSTOMER_PATH);
+        make_request(&self.http, &path, Some(body), Method::POST).await
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{
+    error::PaystackError,
+    paystack::dedicated_nuban::{
+        CreateDedicatedAccountBody, ListDedicatedAccountsParams, RemoveSplitFromDedicatedAcctBody,
+        SplitDedicatedAccountTxBody, DEDICATED_NUBAN_PATH,
+    },
+    response::PaystackResponse,
+    utils::asynchronous::{make_get_request, make_request, HttpClient},
+};
+use reqwest::Method;
+use serde_json::Value as JSON;
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub struct DedicatedNuban {
+    pub(crate) http: Arc<HttpClient>,
+}
+
+impl DedicatedNuban {
+    /// Create a Dedicated NUBAN and assign to a customer
+    /// 💡 Bank Availability: Wema Bank is the only bank currently available
+    pub async fn create_dedicated_account(
+        &self,
+        body: CreateDedicatedAccountBody<'_>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_request(&self.http, DEDICATED_NUBAN_PATH, Some(body), Method::POST).await
+    }
+
+    pub async fn list_dedicated_accounts(
+        &self,
+        params: Option<ListDedicatedAccountsParams<'_>>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_get_request(&self.http, DEDICATED_NUBAN_PATH, Some(params)).await
+    }
+
+    /// Get details of a dedicated account on your integration.
+    pub async fn fetch_dedicated_account(
+        &self,
+        id: &str,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", DEDICATED_NUBAN_PATH, id);
+        make_get_request(&self.http, &path, None::<String>).await
+    }
+
+    /// Deactivate a dedicated account on your integration.
+    pub async fn deactivate_dedicated_account(
+        &self,
+        id: &str,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", DEDICATED_NUBAN_PATH, id);
+        make_request(&self.http, &path, None::<String>, Method::DELETE).await
+    }
+
+    /// Split a dedicated account transaction with one or more accounts
+    pub async fn split_dedicated_account_transaction(
+        &self,
+        body: SplitDedicatedAccountTxBody<'_>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_request(&self.http, DEDICATED_NUBAN_PATH, Some(body), Method::POST).await
+    }
+
+    /// If you've previously set up split payment for transactions on a dedicated account, you can remove it with this endpoint
+    pub async fn remove_split_from_dedicated_acct(
+        &self,
+        body: RemoveSplitFromDedicatedAcctBody<'_>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_request(&self.http, DEDICATED_NUBAN_PATH, Some(body), Method::DELETE).await
+    }
+
+    /// Get available bank providers for Dedicated NUBAN
+    pub async fn fetch_bank_providers(&self) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/available_providers", DEDICATED_NUBAN_PATH);
+        make_get_request(&self.http, &path, None::<String>).await
+    }
+}
@@ -0,0 +1,104 @@
+use crate::{
+    error::PaystackError,
+    pagination::PageStream,
+    paystack::disputes::{
+        AddEvidenceBody, ExportDisputesBody, GetUploadURLParams, ListDisputesParams,
+        ResolveDisputeBody, UpdateDisputeBody, DISPUTE_PATH,
+    },
+    response::PaystackResponse,
+    utils::asynchronous::{make_get_request, make_request, HttpClient},
+};
+use reqwest::Method;
+use serde_json::Value as JSON;
+use std::sync::Arc;
+
+/// The Disputes API allows you manage transaction disputes on your integration
+#[derive(Debug, Clone)]
+pub struct Disputes {
+    pub(crate) http: Arc<HttpClient>,
+}
+
+impl Disputes {
+    /// List disputes filed against you
+    pub async fn list_disputes(
+        &self,
+        params: ListDisputesParams<'_>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_get_request(&self.http, DISPUTE_PATH, Some(params)).await
+    }
+
+    /// Stream every dispute, fetching further pages as they are needed
+    pub fn list_disputes_stream<'a>(&self, params: ListDisputesParams<'a>) -> PageStream<'a, JSON> {
+        let http = self.http.clone();
+        PageStream::new(params, move |params| {
+            let http = http.clone();
+            async move { make_get_request(&http, DISPUTE_PATH, Some(params)).await }
+        })
+    }
+
+    /// Get more details about a dispute.
+    /// - id: The dispute `ID` you want to fetch
+    pub async fn fetch_dispute(&self, id: &str) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", DISPUTE_PATH, id);
+        make_get_request(&self.http, &path, None::<String>).await
+    }
+
+    /// Get more details about a dispute.
+    ///  - id: The transaction `ID` you want to fetch
+    pub async fn list_transaction_disputes(
+        &self,
+        id: &str,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/transaction/{}", DISPUTE_PATH, id);
+        make_get_request(&self.http, &path, None::<String>).await
+    }
+
+    /// Update details of a dispute on your integration
+    pub async fn update_dispute(
+        &self,
+        id: &str,
+        body: UpdateDisputeBody<'_>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", DISPUTE_PATH, id);
+        make_request(&self.http, &path, Some(body), Method::PUT).await
+    }
+
+    /// Provide evidence for a dispute
+    pub async fn add_evidence(
+        &self,
+        id: &str,
+        body: AddEvidenceBody<'_>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}/dispute", DISPUTE_PATH, id);
+        make_request(&self.http, &path, Some(body), Method::POST).await
+    }
+
+    /// Resolve a dispute on your integration
+    pub async fn get_upload_url(
+        &self,
+        id: &str,
+        params: GetUploadURLParams<'_>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}/upload_url", DISPUTE_PATH, id);
+        make_get_request(&self.http, &path, Some(params)).await
+    }
+
+    /// Resolve a dispute on your integration
+    pub async fn resolve_dispute(
+        &self,
+        id: &str,
+        body: ResolveDisputeBody<'_>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}/resolve", DISPUTE_PATH, id);
+        make_request(&self.http, &path, Some(body), Method::PUT).await
+    }
+
+    /// Export disputes available on your integration
+    pub async fn export_disputes(
+        &self,
+        params: ExportDisputesBody<'_>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/export", DISPUTE_PATH);
+        make_get_request(&self.http, &path, Some(params)).await
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{
+    error::PaystackError,
+    pagination::PageStream,
+    paystack::invoices::{CreateInvoiceBody, ListInvoicesParams, UpdateInvoiceBody, INVOICES_PATH},
+    response::PaystackResponse,
+    utils::asynchronous::{make_get_request, make_request, HttpClient},
+};
+use reqwest::Method;
+use serde_json::Value as JSON;
+use std::sync::Arc;
+
+/// The Invoices API allows you issue out and manage payment requests
+#[derive(Debug, Clone)]
+pub struct Invoices {
+    pub(crate) http: Arc<HttpClient>,
+}
+
+impl Invoices {
+    /// Create an invoice for payment on your integration
+    pub async fn create_invoice(
+        &self,
+        body: CreateInvoiceBody<'_>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_request(&self.http, INVOICES_PATH, Some(body), Method::POST).await
+    }
+
+    /// List the invoice available on your integration.
+    pub async fn list_invoices(
+        &self,
+        params: Option<ListInvoicesParams<'_>>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_get_request(&self.http, INVOICES_PATH, params).await
+    }
+
+    /// Stream every invoice, fetching further pages as they are needed
+    pub fn list_invoices_stream<'a>(&self, params: ListInvoicesParams<'a>) -> PageStream<'a, JSON> {
+        let http = self.http.clone();
+        PageStream::new(params, move |params| {
+            let http = http.clone();
+            async move { make_get_request(&http, INVOICES_PATH, Some(params)).await }
+        })
+    }
+
+    /// Get details of an invoice on your integration.
+    /// - id_or_code: Invoice ID or slug
+    pub async fn view_invoice(
+        &self,
+        id_or_code: &str,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", INVOICES_PATH, id_or_code);
+        make_get_request(&self.http, &path, None::<String>).await
+    }
+
+    /// Verify details of an invoice on your integration.
+    /// - id_or_code: Invoice ID or slug
+    pub async fn verify_invoice(
+        &self,
+        id_or_code: &str,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/verify/{}", INVOICES_PATH, id_or_code);
+        make_get_request(&self.http, &path, None::<String>).await
+    }
+
+    /// Send notification of an invoice to your customers
+    /// - id_or_code: Invoice ID or slug
+    pub async fn send_notification(
+        &self,
+        id_or_code: &str,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/notify/{}", INVOICES_PATH, id_or_code);
+        make_request(&self.http, &path, None::<String>, Method::POST).await
+    }
+
+    /// Get invoice metrics for dashboard
+    pub async fn invoice_total(&self) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/totals", INVOICES_PATH);
+        make_request(&self.http, &path, None::<String>, Method::POST).await
+    }
+
+    /// Finalize a Draft Invoice
+    /// - id_or_code: Invoice ID or slug
+    pub async fn finalize_invoice(
+        &self,
+        id_or_code: &str,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/finalize/{}", INVOICES_PATH, id_or_code);
+        make_request(&self.http, &path, None::<String>, Method::POST).await
+    }
+
+    /// Update an invoice details on your integration
+    /// - id_or_code: Invoice ID or slug
+    pub async fn update_invoice(
+        &self,
+        id_or_code: &str,
+        body: UpdateInvoiceBody<'_>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", INVOICES_PATH, id_or_code);
+        make_request(&self.http, &path, Some(body), Method::PUT).await
+    }
+
+    /// Used to archive an invoice. Invoice will no longer be fetched on list or returned on verify.
+    /// - id_or_code: Invoice ID or slug
+    pub async fn archive_invoice(
+        &self,
+        id_or_code: &str,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", INVOICES_PATH, id_or_code);
+        make_request(&self.http, &path, None::<String>, Method::POST).await
+    }
+}
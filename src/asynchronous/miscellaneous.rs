@@ -0,0 +1,61 @@
+use crate::{
+    error::PaystackError,
+    pagination::PageStream,
+    paystack::miscellaneous::{
+        ListBanksParams, ListProvidersParams, ListStatesParams, LIST_BANKS_PATH,
+        LIST_COUNTRIES_PATH, LIST_STATES_PATH,
+    },
+    response::PaystackResponse,
+    utils::asynchronous::{make_get_request, HttpClient},
+};
+use serde_json::Value as JSON;
+use std::sync::Arc;
+
+/// The Miscellaneous API are supporting APIs that can be used to provide more details to other APIs
+#[derive(Debug, Clone)]
+pub struct Miscellaneous {
+    pub(crate) http: Arc<HttpClient>,
+}
+
+impl Miscellaneous {
+    /// Get a list of all supported banks and their properties
+    pub async fn list_banks(
+        &self,
+        params: ListBanksParams<'_>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_get_request(&self.http, LIST_BANKS_PATH, Some(params)).await
+    }
+
+    /// Stream every supported bank, following the `meta.next` cursor as further pages are
+    /// needed. Start from `params.previous` alone to walk back through `meta.previous` instead.
+    pub fn list_banks_stream<'a>(&self, mut params: ListBanksParams<'a>) -> PageStream<'a, JSON> {
+        params.use_cursor = true;
+        let http = self.http.clone();
+        PageStream::cursor(params, move |params| {
+            let http = http.clone();
+            async move { make_get_request(&http, LIST_BANKS_PATH, Some(params)).await }
+        })
+    }
+
+    // TODO: link with dedicated nuban
+    /// Get a list of all providers for [][Dedicated NUBAN]
+    pub async fn list_providers(
+        &self,
+        params: ListProvidersParams,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_get_request(&self.http, LIST_BANKS_PATH, Some(params)).await
+    }
+
+    /// Gets a list of Countries that Paystack currently supports
+    pub async fn list_or_search_countries(&self) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_get_request(&self.http, LIST_COUNTRIES_PATH, None::<String>).await
+    }
+
+    /// Get a list of states for a country for address verification.
+    pub async fn list_states(
+        &self,
+        params: ListStatesParams,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_get_request(&self.http, LIST_STATES_PATH, Some(params)).await
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{
+    error::PaystackError,
+    pagination::PageStream,
+    paystack::payment_pages::{
+        AddProductsBody, CreatePaymentPagesBody, ListPagesParams, UpdatePageBody,
+        PAYMENT_PAGES_PATH,
+    },
+    response::PaystackResponse,
+    utils::asynchronous::{make_get_request, make_request, HttpClient},
+};
+use reqwest::Method;
+use serde_json::Value as JSON;
+use std::sync::Arc;
+
+/// The Payment Pages API provides a quick and secure way to collect payment for products.
+#[derive(Debug, Clone)]
+pub struct PaymentPages {
+    pub(crate) http: Arc<HttpClient>,
+}
+
+impl PaymentPages {
+    /// Create a payment page on your integration
+    pub async fn create_pages(
+        &self,
+        body: CreatePaymentPagesBody<'_>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_request(&self.http, PAYMENT_PAGES_PATH, Some(body), Method::POST).await
+    }
+    /// List payment pages available on your integration.
+    pub async fn list_pages(
+        &self,
+        params: Option<ListPagesParams>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_get_request(&self.http, PAYMENT_PAGES_PATH, params).await
+    }
+
+    /// Stream every payment page, fetching further pages as they are needed
+    pub fn list_pages_stream(&self, params: ListPagesParams) -> PageStream<'static, JSON> {
+        let http = self.http.clone();
+        PageStream::new(params, move |params| {
+            let http = http.clone();
+            async move { make_get_request(&http, PAYMENT_PAGES_PATH, Some(params)).await }
+        })
+    }
+
+    /// Get details of a payment page on your integration.
+    /// id_or_slug: The page ID or slug you want to fetch.
+    pub async fn fetch_page(
+        &self,
+        id_or_slug: &str,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", PAYMENT_PAGES_PATH, id_or_slug);
+        make_get_request(&self.http, &path, None::<String>).await
+    }
+
+    /// Update a payment page details on your integration
+    pub async fn update_page(
+        &self,
+        id_or_slug: &str,
+        body: UpdatePageBody<'_>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", PAYMENT_PAGES_PATH, id_or_slug);
+        make_request(&self.http, &path, Some(body), Method::POST).await
+    }
+
+    /// Check the availability of a slug for a payment page.
+    /// slug: URL slug to be confirmed
+    pub async fn check_slug_availability(
+        &self,
+        slug: &str,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/check_slug_availability/{}", PAYMENT_PAGES_PATH, slug);
+        make_get_request(&self.http, &path, None::<String>).await
+    }
+
+    /// Add products to a payment page
+    pub async fn add_products(
+        &self,
+        id: i128,
+        body: AddProductsBody,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}/product", PAYMENT_PAGES_PATH, id);
+        make_request(&self.http, &path, Some(body), Method::POST).await
+    }
+}
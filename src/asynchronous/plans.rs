@@ -0,0 +1,62 @@
+use crate::{
+    error::PaystackError,
+    pagination::PageStream,
+    paystack::plans::{CreatePlan, ListPlansParams, UpdatePlanBody, PLANS_PATH},
+    response::PaystackResponse,
+    utils::asynchronous::{make_get_request, make_request, HttpClient},
+};
+use reqwest::Method;
+use serde_json::Value as JSON;
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub struct Plans {
+    pub(crate) http: Arc<HttpClient>,
+}
+
+impl Plans {
+    /// Create a plan on your integration
+    pub async fn create_plan(
+        &self,
+        body: CreatePlan,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_request(&self.http, PLANS_PATH, Some(body), Method::POST).await
+    }
+
+    /// List plans available on your integration.
+    pub async fn list_plans(
+        &self,
+        params: Option<ListPlansParams>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_get_request(&self.http, PLANS_PATH, params).await
+    }
+
+    /// Stream every plan, fetching further pages as they are needed
+    pub fn list_plans_stream(&self, params: ListPlansParams) -> PageStream<'static, JSON> {
+        let http = self.http.clone();
+        PageStream::new(params, move |params| {
+            let http = http.clone();
+            async move { make_get_request(&http, PLANS_PATH, Some(params)).await }
+        })
+    }
+
+    /// Get details of a plan on your integration.
+    /// id_or_code: The plan ID or code you want to fetch
+    pub async fn fetch_plan(
+        &self,
+        id_or_code: String,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", PLANS_PATH, id_or_code);
+        make_get_request(&self.http, &path, None::<String>).await
+    }
+
+    /// Update a plan details on your integration
+    pub async fn update_plan(
+        &self,
+        id_or_code: String,
+        body: UpdatePlanBody,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", PLANS_PATH, id_or_code);
+        make_request(&self.http, &path, Some(body), Method::PUT).await
+    }
+}
@@ -0,0 +1,63 @@
+use crate::{
+    error::PaystackError,
+    pagination::PageStream,
+    paystack::products::{CreateProductBody, ListProductsParams, UpdateProductsBody, PRODUCT_PATH},
+    response::PaystackResponse,
+    utils::asynchronous::{make_get_request, make_request, HttpClient},
+};
+use reqwest::Method;
+use serde_json::Value as JSON;
+use std::sync::Arc;
+
+/// The Products API allows you create and manage inventories on your integration
+#[derive(Debug, Clone)]
+pub struct Products {
+    pub(crate) http: Arc<HttpClient>,
+}
+
+impl Products {
+    /// Create a product on your integration
+    pub async fn create_products(
+        &self,
+        body: CreateProductBody,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_request(&self.http, PRODUCT_PATH, Some(body), Method::POST).await
+    }
+
+    /// List products available on your integration.
+    pub async fn list_products(
+        &self,
+        params: Option<ListProductsParams>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_get_request(&self.http, PRODUCT_PATH, params).await
+    }
+
+    /// Stream every product, fetching further pages as they are needed
+    pub fn list_products_stream(&self, params: ListProductsParams) -> PageStream<'static, JSON> {
+        let http = self.http.clone();
+        PageStream::new(params, move |params| {
+            let http = http.clone();
+            async move { make_get_request(&http, PRODUCT_PATH, Some(params)).await }
+        })
+    }
+
+    /// Get details of a product on your integration.
+    /// id: The product ID you want to fetch
+    pub async fn fetch_products(
+        &self,
+        id: String,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", PRODUCT_PATH, id);
+        make_get_request(&self.http, &path, None::<String>).await
+    }
+
+    /// Update a product details on your integration.
+    pub async fn update_products(
+        &self,
+        id: String,
+        body: UpdateProductsBody,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", PRODUCT_PATH, id);
+        make_request(&self.http, &path, Some(body), Method::PUT).await
+    }
+}
@@ -0,0 +1,55 @@
+use crate::{
+    error::PaystackError,
+    pagination::PageStream,
+    paystack::refund::{CreateRefundBody, ListRefundsParams, REFUND_PATH},
+    response::PaystackResponse,
+    utils::asynchronous::{make_get_request, make_request, HttpClient},
+    validation,
+};
+use reqwest::Method;
+use serde_json::Value as JSON;
+use std::sync::Arc;
+
+/// The Refunds API allows you create and manage transaction refunds
+#[derive(Debug, Clone)]
+pub struct Refunds {
+    pub(crate) http: Arc<HttpClient>,
+}
+
+impl Refunds {
+    /// Initiate a refund on your integration
+    pub async fn initiate_refund(
+        &self,
+        body: CreateRefundBody<'_>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        body.validate()?;
+        make_request(&self.http, REFUND_PATH, Some(body), Method::POST).await
+    }
+
+    /// List refunds available on your integration.
+    pub async fn list_refunds(
+        &self,
+        params: Option<ListRefundsParams<'_>>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_get_request(&self.http, REFUND_PATH, params).await
+    }
+
+    /// Stream every refund, fetching further pages as they are needed
+    pub fn list_refunds_stream<'a>(&self, params: ListRefundsParams<'a>) -> PageStream<'a, JSON> {
+        let http = self.http.clone();
+        PageStream::new(params, move |params| {
+            let http = http.clone();
+            async move { make_get_request(&http, REFUND_PATH, Some(params)).await }
+        })
+    }
+    /// Get details of a refund on your integration.
+    /// takes a parameter reference. A transaction reference for the refund you want to fetch
+    pub async fn fetch_refund(
+        &self,
+        reference: &str,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        validation::lookup("reference", reference)?;
+        let path = format!("{}/{}", REFUND_PATH, reference);
+        make_get_request(&self.http, &path, None::<String>).await
+    }
+}
@@ -0,0 +1,61 @@
+use crate::{
+    error::PaystackError,
+    pagination::PageStream,
+    paystack::settlements::{FetchSettleTxBody, FetchSettlementsBody, SETTLEMENTS_PATH},
+    response::PaystackResponse,
+    utils::asynchronous::{make_get_request, HttpClient},
+};
+use serde_json::Value as JSON;
+use std::sync::Arc;
+
+/// The Settlements API allows you gain insights into payouts made by Paystack to your bank account
+#[derive(Debug, Clone)]
+pub struct Settlements {
+    pub(crate) http: Arc<HttpClient>,
+}
+
+impl Settlements {
+    /// Fetch settlements made to your settlement accounts.
+    pub async fn fetch_settlements(
+        &self,
+        params: Option<FetchSettlementsBody<'_>>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_get_request(&self.http, SETTLEMENTS_PATH, Some(params)).await
+    }
+
+    /// Stream every settlement, fetching further pages as they are needed
+    pub fn fetch_settlements_stream<'a>(
+        &self,
+        params: FetchSettlementsBody<'a>,
+    ) -> PageStream<'a, JSON> {
+        let http = self.http.clone();
+        PageStream::new(params, move |params| {
+            let http = http.clone();
+            async move { make_get_request(&http, SETTLEMENTS_PATH, Some(params)).await }
+        })
+    }
+
+    /// Get the transactions that make up a particular settlement
+    pub async fn fetch_settlement_transactions(
+        &self,
+        id: &str,
+        params: Option<FetchSettleTxBody<'_>>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}/transactions", SETTLEMENTS_PATH, id);
+        make_get_request(&self.http, &path, params).await
+    }
+
+    /// Stream every transaction of a settlement, fetching further pages as they are needed
+    pub fn fetch_settlement_transactions_stream<'a>(
+        &self,
+        id: &str,
+        params: FetchSettleTxBody<'a>,
+    ) -> PageStream<'a, JSON> {
+        let path = format!("{}/{}/transactions", SETTLEMENTS_PATH, id);
+        let http = self.http.clone();
+        PageStream::new(params, move |params| {
+            let (http, path) = (http.clone(), path.clone());
+            async move { make_get_request(&http, &path, Some(params)).await }
+        })
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{
+    error::PaystackError,
+    pagination::PageStream,
+    paystack::subaccounts::{
+        CreateSubaccountBody, ListSubaccountParams, UpdateSubaccountBody, SUBACCOUNT_PATH,
+    },
+    response::PaystackResponse,
+    utils::asynchronous::{make_get_request, make_request, HttpClient},
+};
+use reqwest::Method;
+use serde::Serialize;
+use serde_json::Value as JSON;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+/// The Subaccounts API allows you create and manage subaccounts on your integration. Subaccounts can be used to split payment between two accounts (your main account and a sub account)
+#[derive(Debug, Clone)]
+pub struct Subaccount {
+    pub(crate) http: Arc<HttpClient>,
+}
+
+impl Subaccount {
+    /// Create a subacount on your integration
+    pub async fn create_subaccount(
+        &self,
+        body: CreateSubaccountBody<'_>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_request(&self.http, SUBACCOUNT_PATH, Some(body), Method::POST).await
+    }
+
+    /// List subaccounts available on your integration.
+    pub async fn list_subaccounts<T>(
+        &self,
+        params: Option<T>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError>
+    where
+        T: Debug + Serialize,
+    {
+        make_get_request(&self.http, SUBACCOUNT_PATH, params).await
+    }
+
+    /// Stream every subaccount, fetching further pages as they are needed
+    pub fn list_subaccounts_stream(
+        &self,
+        params: ListSubaccountParams,
+    ) -> PageStream<'static, JSON> {
+        let http = self.http.clone();
+        PageStream::new(params, move |params| {
+            let http = http.clone();
+            async move { make_get_request(&http, SUBACCOUNT_PATH, Some(params)).await }
+        })
+    }
+
+    /// Get details of a subaccount on your integration.
+    pub async fn fetch_subaccount(
+        &self,
+        id: &str,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", SUBACCOUNT_PATH, id);
+        make_get_request(&self.http, &path, None::<String>).await
+    }
+
+    /// Update a subaccount details on your integration
+    pub async fn update_subaccount(
+        &self,
+        id: &str,
+        body: UpdateSubaccountBody<'_>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", SUBACCOUNT_PATH, id);
+        make_request(&self.http, &path, Some(body), Method::PUT).await
+    }
+}
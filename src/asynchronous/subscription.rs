@@ -0,0 +1,76 @@
+use crate::{
+    error::PaystackError,
+    pagination::PageStream,
+    paystack::subscription::{
+        CreateSubscriptionBody, DisableSubscriptionBody, EnableSubscriptionBody,
+        ListSubscriptionParams, SUBSCRIPTION_PATH,
+    },
+    response::PaystackResponse,
+    utils::asynchronous::{make_get_request, make_request, HttpClient},
+};
+use reqwest::Method;
+use serde_json::Value as JSON;
+use std::sync::Arc;
+
+/// The Subscriptions API allows you create and manage recurring payment on your integration
+#[derive(Debug, Clone)]
+pub struct Subscription {
+    pub(crate) http: Arc<HttpClient>,
+}
+
+impl Subscription {
+    /// Create a subscription on your integration
+    /// 💡 Email Token We create an email token on each subscription to allow customers cancel their subscriptions from within the invoices sent to their mailboxes. Since they are not authorized, the email tokens are what we use to authenticate the requests over the API.
+    pub async fn create_subscription(
+        &self,
+        body: CreateSubscriptionBody,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_request(&self.http, SUBSCRIPTION_PATH, Some(body), Method::POST).await
+    }
+
+    /// List subscriptions available on your integration.
+    pub async fn list_subscription(
+        &self,
+        params: Option<ListSubscriptionParams>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_get_request(&self.http, SUBSCRIPTION_PATH, params).await
+    }
+
+    /// Stream every subscription, fetching further pages as they are needed
+    pub fn list_subscription_stream(
+        &self,
+        params: ListSubscriptionParams,
+    ) -> PageStream<'static, JSON> {
+        let http = self.http.clone();
+        PageStream::new(params, move |params| {
+            let http = http.clone();
+            async move { make_get_request(&http, SUBSCRIPTION_PATH, Some(params)).await }
+        })
+    }
+    /// Get details of a subscription on your integration.
+    /// id_or_code: The subscription ID or code you want to fetch
+    pub async fn fetch_subscription(
+        &self,
+        id_or_code: String,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", SUBSCRIPTION_PATH, id_or_code);
+        make_get_request(&self.http, &path, None::<String>).await
+    }
+    /// Enable a subscription on your integration
+    pub async fn enable_subscription(
+        &self,
+        body: EnableSubscriptionBody,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/enable", SUBSCRIPTION_PATH);
+        make_request(&self.http, &path, Some(body), Method::POST).await
+    }
+
+    /// Disable a subscription on your integration
+    pub async fn disable_subscription(
+        &self,
+        body: DisableSubscriptionBody,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/disable", SUBSCRIPTION_PATH);
+        make_request(&self.http, &path, Some(body), Method::POST).await
+    }
+}
@@ -0,0 +1,158 @@
+use crate::{
+    error::PaystackError,
+    pagination::PageStream,
+    paystack::transactions::{
+        ChargeAuthorizationBody, CheckAuthorizationBody, ExportTransactionsBody,
+        InitializeTransactionBody, InitializeTransactionData, ListTransactionsParams,
+        PartialDebitBody, TransactionData, TransactionLog, TransactionsTotal,
+        CHARGE_AUTHORIZATION_PATH, CHECK_AUTHORIZATION_PATH, INITIALIZE_TRANSACTION_PATH,
+        TRANSACTION_PATH,
+    },
+    response::PaystackResponse,
+    utils::asynchronous::{make_get_request, make_idempotent_request, make_request, HttpClient},
+    validation,
+};
+use reqwest::Method;
+use serde_json::Value as JSON;
+use std::sync::Arc;
+
+/// The Transactions API allows you create and manage payments on your integration
+#[derive(Debug, Clone)]
+pub struct Transaction {
+    pub(crate) http: Arc<HttpClient>,
+}
+
+impl Transaction {
+    /// Initialize a transaction from your backend
+    pub async fn initialize_transaction(
+        &self,
+        body: InitializeTransactionBody,
+    ) -> Result<PaystackResponse<InitializeTransactionData>, PaystackError> {
+        body.validate()?;
+        let idempotent = body.reference.is_some();
+        make_idempotent_request(
+            &self.http,
+            INITIALIZE_TRANSACTION_PATH,
+            Some(body),
+            Method::POST,
+            idempotent,
+        )
+        .await
+    }
+
+    /// verify a transaction. it takes an argument reference which is the reference_id of a transaction you want to verify
+    pub async fn verify_transaction(
+        &self,
+        reference: String,
+    ) -> Result<PaystackResponse<TransactionData>, PaystackError> {
+        validation::lookup("reference", &reference)?;
+        let path = format!("{}/verify/{}", TRANSACTION_PATH, reference);
+        make_get_request(&self.http, &path, None::<String>).await
+    }
+
+    /// list_transactions lists all the transactions available
+    pub async fn list_transactions(
+        &self,
+        body: ListTransactionsParams,
+    ) -> Result<PaystackResponse<Vec<TransactionData>>, PaystackError> {
+        make_get_request(&self.http, TRANSACTION_PATH, Some(body)).await
+    }
+
+    /// Stream every transaction, fetching further pages as they are needed
+    pub fn list_transactions_stream(
+        &self,
+        params: ListTransactionsParams,
+    ) -> PageStream<'static, TransactionData> {
+        let http = self.http.clone();
+        PageStream::new(params, move |params| {
+            let http = http.clone();
+            async move { make_get_request(&http, TRANSACTION_PATH, Some(params)).await }
+        })
+    }
+
+    /// Get details of a transaction carried out on your integration.
+    pub async fn fetch_transaction(
+        &self,
+        transaction_id: i64,
+    ) -> Result<PaystackResponse<TransactionData>, PaystackError> {
+        let path = format!("{}/{}", TRANSACTION_PATH, transaction_id);
+        make_get_request(&self.http, &path, None::<String>).await
+    }
+
+    /// All authorizations marked as reusable can be charged with this endpoint whenever you need to receive payments.
+    pub async fn charge_authorization(
+        &self,
+        params: ChargeAuthorizationBody,
+    ) -> Result<PaystackResponse<TransactionData>, PaystackError> {
+        params.validate()?;
+        let idempotent = params.reference.is_some();
+        make_idempotent_request(
+            &self.http,
+            CHARGE_AUTHORIZATION_PATH,
+            Some(params),
+            Method::POST,
+            idempotent,
+        )
+        .await
+    }
+    /// All mastercard and visa authorizations can be checked with this endpoint to know if they have funds for the payment you seek.
+    /// This endpoint should be used when you do not know the exact amount to charge a card when rendering a service. It should be used to check if a card has enough funds based on a maximum range value. It is well suited for:
+    ///
+    ///  - [x] Ride hailing services
+    ///  - [x] Logistics services.
+    ///
+    ///
+    /// ⚠️ Warning You shouldn't use this endpoint to check a card for sufficient funds if you are going to charge the user immediately. This is because we hold funds when this endpoint is called which can lead to an insufficient funds error.
+    pub async fn check_authorization(
+        &self,
+        param: CheckAuthorizationBody,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        param.validate()?;
+        make_request(
+            &self.http,
+            CHECK_AUTHORIZATION_PATH,
+            Some(param),
+            Method::POST,
+        )
+        .await
+    }
+
+    /// View the timeline of a transaction
+    pub async fn view_transaction_timeline(
+        &self,
+        id: String,
+    ) -> Result<PaystackResponse<TransactionLog>, PaystackError> {
+        validation::lookup("id", &id)?;
+        let path = format!("{}/timeline/{}", TRANSACTION_PATH, id);
+        make_get_request(&self.http, &path, None::<String>).await
+    }
+
+    /// Total amount received on your account
+    pub async fn transactions_total(
+        &self,
+        params: Option<TransactionsTotal>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/totals", TRANSACTION_PATH);
+        make_get_request(&self.http, &path, params).await
+    }
+
+    /// Export transactions carried out on your integration.
+    pub async fn export_transactions(
+        &self,
+        params: Option<ExportTransactionsBody>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/export", TRANSACTION_PATH);
+        make_get_request(&self.http, &path, params).await
+    }
+
+    /// Retrieve part of a payment from a customer
+    pub async fn partial_debit(
+        &self,
+        body: PartialDebitBody,
+    ) -> Result<PaystackResponse<TransactionData>, PaystackError> {
+        body.validate()?;
+        let path = format!("{}/partial_debit", TRANSACTION_PATH);
+        let idempotent = body.reference.is_some();
+        make_idempotent_request(&self.http, &path, Some(body), Method::POST, idempotent).await
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{
+    error::PaystackError,
+    pagination::PageStream,
+    paystack::transactions_split::{
+        AddOrUpdateSplitSubaccountBody, CreateSplitPaymentBody, ListOrSearchSplitsParams,
+        RemoveSplitSubaccountBody, UpdateSplitBody, SPLIT_PAYMENT_PATH,
+    },
+    response::PaystackResponse,
+    utils::asynchronous::{make_get_request, make_request, HttpClient},
+};
+use reqwest::Method;
+use serde_json::Value as JSON;
+use std::sync::Arc;
+
+/// The Transaction Splits API enables merchants split the settlement for a transaction across their payout account, and one or more Subaccounts.
+#[derive(Debug, Clone)]
+pub struct TransactionSplit {
+    pub(crate) http: Arc<HttpClient>,
+}
+
+impl TransactionSplit {
+    /// Create a split payment on your integration
+    pub async fn create_split(
+        &self,
+        body: CreateSplitPaymentBody<'_>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_request(&self.http, SPLIT_PAYMENT_PATH, Some(body), Method::POST).await
+    }
+
+    /// List/search for the transaction splits available on your integration.
+    pub async fn list_or_search_splits(
+        &self,
+        params: Option<ListOrSearchSplitsParams<'_>>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_get_request(&self.http, SPLIT_PAYMENT_PATH, params).await
+    }
+
+    /// Stream every split, fetching further pages as they are needed
+    pub fn list_or_search_splits_stream<'a>(
+        &self,
+        params: ListOrSearchSplitsParams<'a>,
+    ) -> PageStream<'a, JSON> {
+        let http = self.http.clone();
+        PageStream::new(params, move |params| {
+            let http = http.clone();
+            async move { make_get_request(&http, SPLIT_PAYMENT_PATH, Some(params)).await }
+        })
+    }
+
+    /// Get details of a split on your integration.
+    pub async fn fetch_split(&self, id: &str) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", SPLIT_PAYMENT_PATH, id);
+        make_get_request(&self.http, &path, None::<String>).await
+    }
+
+    /// Update a transaction split details on your integration
+    pub async fn update_split(
+        &self,
+        id: &str,
+        body: UpdateSplitBody<'_>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", SPLIT_PAYMENT_PATH, id);
+        make_request(&self.http, &path, Some(body), Method::PUT).await
+    }
+
+    /// Add a Subaccount to a Transaction Split, or update the share of an existing Subaccount in a Transaction Split
+    pub async fn add_or_update_split_subaccount(
+        &self,
+        id: &str,
+        body: AddOrUpdateSplitSubaccountBody<'_>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}/subaccount/add", SPLIT_PAYMENT_PATH, id);
+        make_request(&self.http, &path, Some(body), Method::POST).await
+    }
+
+    /// Remove a subaccount from a transaction split
+    pub async fn remove_split_subaccount(
+        &self,
+        id: &str,
+        body: RemoveSplitSubaccountBody<'_>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}/subaccount/remove", SPLIT_PAYMENT_PATH, id);
+        make_request(&self.http, &path, Some(body), Method::POST).await
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{
+    error::PaystackError,
+    pagination::PageStream,
+    paystack::transfer_recipients::{
+        BulkCreateTransferRecipient, CreateTransferRecipientBody, ListTransferRecipientsParams,
+        UpdateTransferRecipient, TRANSFER_RECIPIENT_PATH,
+    },
+    response::PaystackResponse,
+    utils::asynchronous::{make_get_request, make_request, HttpClient},
+};
+use reqwest::Method;
+use serde_json::Value as JSON;
+use std::sync::Arc;
+
+/// The Transfer Recipients API allows you create and manage beneficiaries that you send money to
+///  
+/// ```text
+///  - 💡 Feature Availability
+/// This feature is only available to businesses in Nigeria and Ghana.
+/// ```
+#[derive(Debug, Clone)]
+pub struct TransferRecipients {
+    pub(crate) http: Arc<HttpClient>,
+}
+
+impl TransferRecipients {
+    /// Creates a new recipient. A duplicate account number will lead to the retrieval of the existing record.
+    pub async fn create_transfer_recipient(
+        &self,
+        body: CreateTransferRecipientBody<'_>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_request(
+            &self.http,
+            TRANSFER_RECIPIENT_PATH,
+            Some(body),
+            Method::POST,
+        )
+        .await
+    }
+
+    ///Create multiple transfer recipients in batches. A duplicate account number will lead to the retrieval of the existing record.
+    pub async fn bulk_create_transfer_recipient(
+        &self,
+        body: BulkCreateTransferRecipient,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/bulk", TRANSFER_RECIPIENT_PATH);
+        make_request(&self.http, &path, Some(body), Method::POST).await
+    }
+
+    /// List transfer recipients available on your integration
+    pub async fn list_transfer_recipients(
+        &self,
+        params: ListTransferRecipientsParams,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_get_request(&self.http, TRANSFER_RECIPIENT_PATH, Some(params)).await
+    }
+
+    /// Stream every transfer recipient, fetching further pages as they are needed
+    pub fn list_transfer_recipients_stream(
+        &self,
+        params: ListTransferRecipientsParams,
+    ) -> PageStream<'static, JSON> {
+        let http = self.http.clone();
+        PageStream::new(params, move |params| {
+            let http = http.clone();
+            async move { make_get_request(&http, TRANSFER_RECIPIENT_PATH, Some(params)).await }
+        })
+    }
+
+    /// Fetch the details of a transfer recipient
+    ///  - id_or_code: An ID or code for the recipient whose details you want to receive.
+    pub async fn fetch_transfer_recipient(
+        &self,
+        id_or_code: &str,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", TRANSFER_RECIPIENT_PATH, id_or_code);
+        make_get_request(&self.http, &path, None::<String>).await
+    }
+
+    /// Update an existing recipient. An duplicate account number will lead to the retrieval of the existing record.
+    pub async fn update_transfer_recipient(
+        &self,
+        body: UpdateTransferRecipient<'_>,
+        id_or_code: &str,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", TRANSFER_RECIPIENT_PATH, id_or_code);
+        make_request(&self.http, &path, Some(body), Method::PUT).await
+    }
+
+    /// Deletes a transfer recipient (sets the transfer recipient to inactive)
+    pub async fn delete_transfer_recipient(
+        &self,
+        id_or_code: &str,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", TRANSFER_RECIPIENT_PATH, id_or_code);
+        make_request(&self.http, &path, None::<String>, Method::DELETE).await
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{
+    error::PaystackError,
+    pagination::PageStream,
+    paystack::transfers::{
+        FinalizeTransferBody, InitiateBulkTransferBody, InitiateTransferBody, ListTransfersParams,
+        TRANSFERS_PATH,
+    },
+    response::PaystackResponse,
+    utils::asynchronous::{make_get_request, make_idempotent_request, make_request, HttpClient},
+    validation,
+};
+use reqwest::Method;
+use serde_json::Value as JSON;
+use std::sync::Arc;
+
+/// The Transfers API allows you automate sending money on your integration
+/// - 💡 Feature Availability
+///   This feature is only available to businesses in Nigeria and Ghana.
+#[derive(Debug, Clone)]
+pub struct Transfers {
+    pub(crate) http: Arc<HttpClient>,
+}
+
+impl Transfers {
+    /// Status of transfer object returned will be `pending` if OTP is disabled.
+    /// In the event that an OTP is required, status will read `otp`.
+    pub async fn initiate_transfers(
+        &self,
+        body: InitiateTransferBody<'_>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        body.validate()?;
+        let idempotent = body.reference.is_some();
+        make_idempotent_request(
+            &self.http,
+            TRANSFERS_PATH,
+            Some(body),
+            Method::POST,
+            idempotent,
+        )
+        .await
+    }
+
+    /// Finalize an initiated transfer
+    pub async fn finalize_transfer(
+        &self,
+        body: FinalizeTransferBody<'_>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/finalize_transfer", TRANSFERS_PATH);
+        make_request(&self.http, &path, Some(body), Method::POST).await
+    }
+
+    /// You need to disable the Transfers OTP requirement to use this endpoint.
+    pub async fn initiate_bulk_transfer(
+        &self,
+        body: InitiateBulkTransferBody<'_>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        body.validate()?;
+        let path = format!("{}/bulk", TRANSFERS_PATH);
+        make_request(&self.http, &path, Some(body), Method::POST).await
+    }
+
+    /// List the transfers made on your integration.
+    pub async fn list_transfers(
+        &self,
+        params: ListTransfersParams<'_>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_get_request(&self.http, TRANSFERS_PATH, Some(params)).await
+    }
+
+    /// Stream every transfer, fetching further pages as they are needed
+    pub fn list_transfers_stream<'a>(
+        &self,
+        params: ListTransfersParams<'a>,
+    ) -> PageStream<'a, JSON> {
+        let http = self.http.clone();
+        PageStream::new(params, move |params| {
+            let http = http.clone();
+            async move { make_get_request(&http, TRANSFERS_PATH, Some(params)).await }
+        })
+    }
+
+    /// Get details of a transfer on your integration.
+    pub async fn fetch_transfer(
+        &self,
+        id_or_code: &str,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        validation::lookup("id_or_code", id_or_code)?;
+        let path = format!("{}/{}", TRANSFERS_PATH, id_or_code);
+        make_get_request(&self.http, &path, None::<String>).await
+    }
+
+    /// Verify the status of a transfer on your integration.
+    pub async fn verify_transfer(
+        &self,
+        reference: &str,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        validation::lookup("reference", reference)?;
+        let path = format!("{}/verify/{}", TRANSFERS_PATH, reference);
+        make_get_request(&self.http, &path, None::<String>).await
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{
+    error::PaystackError,
+    paystack::transfers_control::{
+        FinalizeDisableTransferOTPBody, ResendTransfersOTPBody, TRANSFERS_CONTROL_PATH,
+    },
+    response::PaystackResponse,
+    utils::asynchronous::{make_get_request, make_request, HttpClient},
+};
+use reqwest::Method;
+use serde_json::Value as JSON;
+use std::sync::Arc;
+
+/// The Transfers Control API allows you manage settings of your transfers
+#[derive(Debug, Clone)]
+pub struct TransfersControl {
+    pub(crate) http: Arc<HttpClient>,
+}
+
+impl TransfersControl {
+    /// Fetch the available balance on your integration
+    pub async fn check_balance(&self) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_get_request(&self.http, TRANSFERS_CONTROL_PATH, None::<String>).await
+    }
+
+    /// Fetch all pay-ins and pay-outs that occured on your integration
+    pub async fn fetch_balance_ledger(&self) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_get_request(&self.http, TRANSFERS_CONTROL_PATH, None::<String>).await
+    }
+
+    /// Generates a new OTP and sends to customer in the event they are having trouble receiving one.
+    /// - 💡  Feature Availability
+    ///   This feature is only available to businesses in Nigeria and Ghana.
+    pub async fn resend_transfers_otp(
+        &self,
+        body: ResendTransfersOTPBody<'_>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/resend_otp", TRANSFERS_CONTROL_PATH);
+        make_request(&self.http, &path, Some(body), Method::POST).await
+    }
+
+    /// This is used in the event that you want to be able to complete transfers programmatically without use of OTPs.
+    /// No arguments required. You will get an OTP to complete the request.
+    /// - 💡  Feature Availability
+    ///   This feature is only available to businesses in Nigeria and Ghana.
+    pub async fn disable_transfers_otp(&self) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/disable_otp", TRANSFERS_CONTROL_PATH);
+        make_request(&self.http, &path, None::<String>, Method::POST).await
+    }
+
+    /// Finalize the request to disable OTP on your transfers.
+    /// - 💡  Feature Availability
+    ///   This feature is only available to businesses in Nigeria and Ghana.
+    pub async fn finalize_disable_transfers_otp(
+        &self,
+        body: FinalizeDisableTransferOTPBody<'_>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/disable_otp_finalize", TRANSFERS_CONTROL_PATH);
+        make_request(&self.http, &path, Some(body), Method::POST).await
+    }
+
+    /// In the event that a customer wants to stop being able to complete transfers programmatically, this endpoint helps turn OTP requirement back on.
+    /// No arguments required.
+    /// - 💡  Feature Availability
+    ///   This feature is only available to businesses in Nigeria and Ghana.
+    pub async fn enable_transfers_otp(&self) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/enable_otp", TRANSFERS_CONTROL_PATH);
+        make_request(&self.http, &path, None::<String>, Method::POST).await
+    }
+}
@@ -0,0 +1,49 @@
+use crate::{
+    error::PaystackError,
+    paystack::verification::{
+        ResolveAcctNoBody, VerifyBVNBody, RESOLVE_ACCT_NO_PATH, RESOLVE_CARD_BIN_PATH,
+        VERIFY_BVN_MATCH_PATH,
+    },
+    response::PaystackResponse,
+    utils::asynchronous::{make_get_request, make_request, HttpClient},
+};
+use reqwest::Method;
+use serde_json::Value as JSON;
+use std::sync::Arc;
+
+/// The Verification API allows you perform KYC processes.
+///
+/// *NB: due to regulations, Paystack has disabled this service.*
+///  - 💡 Feature Availability
+///    This feature is only available to businesses in Nigeria.
+#[derive(Debug, Clone)]
+pub struct Verification {
+    pub(crate) http: Arc<HttpClient>,
+}
+
+impl Verification {
+    /// Check if an account number and BVN are linked
+    pub async fn verify_bvn_match(
+        &self,
+        body: VerifyBVNBody<'_>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_request(&self.http, VERIFY_BVN_MATCH_PATH, Some(body), Method::POST).await
+    }
+
+    /// Confirm an account belongs to the right customer
+    pub async fn resolve_account_number(
+        &self,
+        params: ResolveAcctNoBody<'_>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_get_request(&self.http, RESOLVE_ACCT_NO_PATH, Some(params)).await
+    }
+
+    /// Get more information about a customer's card
+    pub async fn resolve_card_bin(
+        &self,
+        bin: &str,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", RESOLVE_CARD_BIN_PATH, bin);
+        make_get_request(&self.http, &path, None::<String>).await
+    }
+}
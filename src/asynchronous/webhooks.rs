@@ -0,0 +1,275 @@
+use crate::{
+    asynchronous::Paystack,
+    paystack::{
+        disputes::DisputeData,
+        refund::RefundData,
+        subscription::{SubscriptionData, SubscriptionInvoice},
+        transactions::TransactionData,
+        transfers::TransferData,
+    },
+    webhooks::{claim, with_events, Event, HandlerError, IpAllowlist, SeenEvents},
+};
+use futures_util::future::BoxFuture;
+use reqwest::{header::HeaderMap, StatusCode};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    future::Future,
+    net::IpAddr,
+};
+
+/// Handles one kind of event. Implemented for closures that take the client and the event and
+/// return a future, e.g. an `async move` block.
+pub trait EventHandler: Send + Sync {
+    /// Process the event, failing to have Paystack send it again later
+    fn handle(
+        &self,
+        paystack: Paystack,
+        event: Event,
+    ) -> BoxFuture<'static, Result<(), HandlerError>>;
+}
+
+impl<F, Fut> EventHandler for F
+where
+    F: Fn(Paystack, Event) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<(), HandlerError>> + Send + 'static,
+{
+    fn handle(
+        &self,
+        paystack: Paystack,
+        event: Event,
+    ) -> BoxFuture<'static, Result<(), HandlerError>> {
+        Box::pin(self(paystack, event))
+    }
+}
+
+/// The async flavour of [`WebhookRouter`](crate::webhooks::WebhookRouter). Handlers get their
+/// own clone of the client, so they can await calls to Paystack.
+/// ```rust
+/// use paystack_rs::asynchronous::{webhooks::WebhookRouter, Paystack};
+/// use reqwest::{header::HeaderMap, StatusCode};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let paystack = Paystack::new("sk_test_xxxx".to_string());
+/// let router = WebhookRouter::new(paystack).on_charge_success(|paystack, transaction| async move {
+///     let verified = paystack.transaction.verify_transaction(transaction.reference).await?;
+///     println!("fulfilling order for {}", verified.data.amount);
+///     Ok(())
+/// });
+///
+/// let body = br#"{"event":"charge.success","data":{"reference":"qTPrJoy9Bx"}}"#;
+/// assert_eq!(router.handle(body, &HeaderMap::new()).await, StatusCode::UNAUTHORIZED);
+/// # }
+/// ```
+pub struct WebhookRouter {
+    paystack: Paystack,
+    handlers: HashMap<String, Box<dyn EventHandler>>,
+    /// The events whose handler takes the model of their `data` rather than the [`Event`]
+    typed: HashSet<&'static str>,
+    fallback: Option<Box<dyn EventHandler>>,
+    allowlist: Option<IpAllowlist>,
+    seen: Option<Box<dyn SeenEvents>>,
+}
+
+/// Registers a typed handler for each event whose `data` has a model, one event at a time
+macro_rules! typed_handlers {
+    () => {};
+    ($(#[doc = $doc:literal])* $name:literal => $variant:ident($data:ty) => $method:ident, $($rest:tt)*) => {
+        impl WebhookRouter {
+            #[doc = concat!("Handle `", $name, "`:")]
+            $(#[doc = $doc])*
+            pub fn $method<F, Fut>(self, handler: F) -> WebhookRouter
+            where
+                F: Fn(Paystack, $data) -> Fut + Send + Sync + 'static,
+                Fut: Future<Output = Result<(), HandlerError>> + Send + 'static,
+            {
+                let mut router = self.on($name, move |paystack: Paystack, event: Event| {
+                    let handled = match event {
+                        Event::$variant(data) => Ok(handler(paystack, data)),
+                        other => Err(format!("{} data does not fit its model", other.name())),
+                    };
+                    async move {
+                        match handled {
+                            Ok(handled) => handled.await,
+                            Err(err) => Err(err.into()),
+                        }
+                    }
+                });
+                router.typed.insert($name);
+                router
+            }
+        }
+        typed_handlers! { $($rest)* }
+    };
+    ($(#[doc = $doc:literal])* $name:literal => $variant:ident($data:ty), $($rest:tt)*) => {
+        typed_handlers! { $($rest)* }
+    };
+}
+
+impl WebhookRouter {
+    /// Route webhooks verified with the client's secret key
+    pub fn new(paystack: Paystack) -> WebhookRouter {
+        WebhookRouter {
+            paystack,
+            handlers: HashMap::new(),
+            typed: HashSet::new(),
+            fallback: None,
+            allowlist: None,
+            seen: None,
+        }
+    }
+
+    /// Handle every event named `event`. Registering another handler for the same event
+    /// replaces this one.
+    pub fn on<H>(mut self, event: &str, handler: H) -> WebhookRouter
+    where
+        H: EventHandler + 'static,
+    {
+        self.handlers.insert(event.to_string(), Box::new(handler));
+        self.typed.remove(event);
+        self
+    }
+
+    /// Handle the events no other handler is registered for, and the known events whose `data`
+    /// doesn't fit the model their typed handler takes, as [`Event::Unknown`]. Without one the
+    /// former are acknowledged and dropped, and the latter fail with a 500.
+    pub fn fallback<H>(mut self, handler: H) -> WebhookRouter
+    where
+        H: EventHandler + 'static,
+    {
+        self.fallback = Some(Box::new(handler));
+        self
+    }
+
+    /// Only accept webhooks sent from the allowlist's addresses, which makes
+    /// [`WebhookRouter::handle`] reject them all in favour of [`WebhookRouter::handle_from`]
+    pub fn allowlist(mut self, allowlist: IpAllowlist) -> WebhookRouter {
+        self.allowlist = Some(allowlist);
+        self
+    }
+
+    /// Skip the events `seen` already recorded. The store is called from the task handling the
+    /// webhook, so keep it quick.
+    pub fn seen_events<S>(mut self, seen: S) -> WebhookRouter
+    where
+        S: SeenEvents + 'static,
+    {
+        self.seen = Some(Box::new(seen));
+        self
+    }
+
+    /// Verify, parse and dispatch a webhook, returning the status to answer Paystack with
+    pub async fn handle(&self, body: &[u8], headers: &HeaderMap) -> StatusCode {
+        self.dispatch(None, body, headers).await
+    }
+
+    /// Like [`WebhookRouter::handle`], first checking the sender against the allowlist
+    pub async fn handle_from(&self, peer: IpAddr, body: &[u8], headers: &HeaderMap) -> StatusCode {
+        self.dispatch(Some(peer), body, headers).await
+    }
+
+    async fn dispatch(&self, peer: Option<IpAddr>, body: &[u8], headers: &HeaderMap) -> StatusCode {
+        let accepted = self
+            .paystack
+            .webhooks
+            .accept(self.allowlist.as_ref(), peer, body, headers);
+        let event = match accepted {
+            Ok(event) => event,
+            Err(err) => {
+                tracing::warn!(error = %err, "rejected webhook");
+                return err.status();
+            }
+        };
+        let claim = match claim(self.seen.as_deref(), &event) {
+            Ok(claim) => claim,
+            Err(status) => return status,
+        };
+        let name = event.name().to_string();
+        let typed = self.typed.contains(name.as_str());
+        let handler = match (self.handlers.get(&name), &self.fallback) {
+            // A typed handler can't take data that doesn't fit its model, so the fallback gets
+            // it as it was sent. Without a fallback the typed handler fails it instead.
+            (Some(_), Some(fallback)) if typed && event.is_malformed() => Some(fallback),
+            (handler, fallback) => handler.or(fallback.as_ref()),
+        };
+        let handler = match handler {
+            Some(handler) => handler,
+            None => {
+                claim.keep();
+                return StatusCode::OK;
+            }
+        };
+        match handler.handle(self.paystack.clone(), event).await {
+            Ok(()) => {
+                claim.keep();
+                StatusCode::OK
+            }
+            Err(err) => {
+                tracing::warn!(event = %name, error = %err, "webhook handler failed");
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+with_events!(typed_handlers);
+
+impl fmt::Debug for WebhookRouter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebhookRouter")
+            .field("events", &self.handlers.keys().collect::<Vec<_>>())
+            .field("fallback", &self.fallback.is_some())
+            .field("allowlist", &self.allowlist)
+            .field("seen", &self.seen.is_some())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::webhooks::MemorySeenEvents;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    #[tokio::test]
+    async fn malformed_event_fails_without_fallback_and_reaches_fallback() {
+        let paystack = Paystack::new("sk_test_xxxx".to_string());
+        let body =
+            br#"{"event":"transfer.success","data":{"transfer_code":"TRF_1","amount":[50000]}}"#;
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "x-paystack-signature",
+            paystack.webhooks.sign(body).parse().unwrap(),
+        );
+        let transferred = Arc::new(AtomicUsize::new(0));
+        let counter = transferred.clone();
+        let router = WebhookRouter::new(paystack.clone())
+            .seen_events(MemorySeenEvents::new())
+            .on_transfer_success(move |_, _| {
+                counter.fetch_add(1, Ordering::SeqCst);
+                async { Ok(()) }
+            });
+
+        for _ in 0..2 {
+            assert_eq!(
+                router.handle(body, &headers).await,
+                StatusCode::INTERNAL_SERVER_ERROR
+            );
+        }
+
+        let fallen_back = Arc::new(AtomicUsize::new(0));
+        let counter = fallen_back.clone();
+        let router = router.fallback(move |_, event: Event| {
+            assert_eq!(event.name(), "transfer.success");
+            counter.fetch_add(1, Ordering::SeqCst);
+            async { Ok(()) }
+        });
+        assert_eq!(router.handle(body, &headers).await, StatusCode::OK);
+        assert_eq!(fallen_back.load(Ordering::SeqCst), 1);
+        assert_eq!(transferred.load(Ordering::SeqCst), 0);
+    }
+}
@@ -0,0 +1,269 @@
+use crate::{
+    error::PaystackError,
+    rate_limit::RateLimiter,
+    retry::RetryPolicy,
+    secret::{KeyMode, SecretKey},
+};
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    Proxy, Url,
+};
+use std::time::Duration;
+
+/// The Paystack API every client talks to unless told otherwise
+pub const DEFAULT_BASE_URL: &str = "https://api.paystack.co";
+/// The environment variable [`PaystackBuilder::from_env`] reads the secret key from
+pub const SECRET_KEY_ENV: &str = "PAYSTACK_SECRET_KEY";
+/// The environment variable [`PaystackBuilder::from_env`] reads an optional base URL from
+pub const BASE_URL_ENV: &str = "PAYSTACK_BASE_URL";
+const DEFAULT_USER_AGENT: &str = concat!("paystack-rs/", env!("CARGO_PKG_VERSION"));
+
+/// HTTP settings shared by every resource struct of a client
+#[derive(Debug, Clone)]
+pub(crate) struct Config {
+    /// Base URL without a trailing slash, e.g. `https://api.paystack.co`
+    pub(crate) base_url: String,
+    pub(crate) connect_timeout: Option<Duration>,
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) proxy: Option<Proxy>,
+    pub(crate) headers: HeaderMap,
+    pub(crate) user_agent: String,
+    pub(crate) retry: RetryPolicy,
+    pub(crate) rate_limiter: Option<RateLimiter>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            base_url: DEFAULT_BASE_URL.to_string(),
+            connect_timeout: None,
+            timeout: None,
+            proxy: None,
+            headers: HeaderMap::new(),
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            retry: RetryPolicy::default(),
+            rate_limiter: None,
+        }
+    }
+}
+
+impl Config {
+    /// Full URL of an endpoint, given its path, e.g. `/transaction/initialize`
+    pub(crate) fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    #[cfg(feature = "blocking")]
+    pub(crate) fn blocking_client(&self) -> reqwest::Result<reqwest::blocking::Client> {
+        let mut builder = reqwest::blocking::Client::builder()
+            .user_agent(self.user_agent.as_str())
+            .default_headers(self.headers.clone());
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(connect_timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(proxy.clone());
+        }
+        builder.build()
+    }
+
+    #[cfg(feature = "async")]
+    pub(crate) fn async_client(&self) -> reqwest::Result<reqwest::Client> {
+        let mut builder = reqwest::Client::builder()
+            .user_agent(self.user_agent.as_str())
+            .default_headers(self.headers.clone());
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(connect_timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(proxy.clone());
+        }
+        builder.build()
+    }
+}
+
+/// Configure how a [`Paystack`](crate::prelude::Paystack) client talks to the API.
+/// ```rust
+/// use paystack_rs::prelude::PaystackBuilder;
+/// use std::time::Duration;
+///
+/// let paystack = PaystackBuilder::new("sk_test_xxxx".to_string())
+///     .base_url("http://localhost:8080")
+///     .connect_timeout(Duration::from_secs(5))
+///     .timeout(Duration::from_secs(30))
+///     .user_agent("my-shop/1.0")
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug)]
+pub struct PaystackBuilder {
+    key: SecretKey,
+    base_url: String,
+    config: Config,
+    deny_live_keys: bool,
+}
+
+impl PaystackBuilder {
+    /// Start configuring a client that authenticates with the given secret key
+    pub fn new(key: String) -> PaystackBuilder {
+        PaystackBuilder::with_key(SecretKey::new(key))
+    }
+
+    fn with_key(key: SecretKey) -> PaystackBuilder {
+        PaystackBuilder {
+            key,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            config: Config::default(),
+            deny_live_keys: false,
+        }
+    }
+
+    /// Start configuring a client from the environment: the secret key from `PAYSTACK_SECRET_KEY`
+    /// and, if set, the base URL from `PAYSTACK_BASE_URL`. Variables missing from the process
+    /// environment are read from a `.env` file in the working directory or one of its parents.
+    ///
+    /// Fails if the key is missing or is neither an `sk_test_` nor an `sk_live_` key.
+    pub fn from_env() -> Result<PaystackBuilder, PaystackError> {
+        match dotenvy::dotenv() {
+            Ok(_) => {}
+            Err(err) if err.not_found() => {}
+            Err(err) => {
+                return Err(PaystackError::Config(format!(
+                    "could not load .env: {}",
+                    err
+                )))
+            }
+        }
+
+        let key = SecretKey::new(std::env::var(SECRET_KEY_ENV).map_err(|err| {
+            PaystackError::Config(format!("could not read {}: {}", SECRET_KEY_ENV, err))
+        })?);
+        if key.mode().is_none() {
+            return Err(PaystackError::Config(format!(
+                "{} must start with sk_test_ or sk_live_",
+                SECRET_KEY_ENV
+            )));
+        }
+
+        let mut builder = PaystackBuilder::with_key(key);
+        if let Ok(base_url) = std::env::var(BASE_URL_ENV) {
+            builder.base_url = base_url;
+        }
+        Ok(builder)
+    }
+
+    /// Send requests to this URL instead of `https://api.paystack.co`, e.g. a local stub or a recording server
+    pub fn base_url(mut self, base_url: &str) -> PaystackBuilder {
+        self.base_url = base_url.to_string();
+        self
+    }
+
+    /// How long to wait for a connection to Paystack to be established
+    pub fn connect_timeout(mut self, timeout: Duration) -> PaystackBuilder {
+        self.config.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// How long to wait for a whole request, from connecting until the response body has been read
+    pub fn timeout(mut self, timeout: Duration) -> PaystackBuilder {
+        self.config.timeout = Some(timeout);
+        self
+    }
+
+    /// Route every request through this proxy, e.g. a corporate egress proxy
+    pub fn proxy(mut self, proxy: Proxy) -> PaystackBuilder {
+        self.config.proxy = Some(proxy);
+        self
+    }
+
+    /// Add a header to every request. The `Authorization` header is always set from the secret key.
+    pub fn header(mut self, name: HeaderName, value: HeaderValue) -> PaystackBuilder {
+        self.config.headers.insert(name, value);
+        self
+    }
+
+    /// Replace the default `paystack-rs/<version>` user agent
+    pub fn user_agent(mut self, user_agent: &str) -> PaystackBuilder {
+        self.config.user_agent = user_agent.to_string();
+        self
+    }
+
+    /// Replace the default [`RetryPolicy`], e.g. with [`RetryPolicy::none`] to send every request once
+    pub fn retry_policy(mut self, retry: RetryPolicy) -> PaystackBuilder {
+        self.config.retry = retry;
+        self
+    }
+
+    /// Hold requests back so they stay within the budgets of a [`RateLimiter`]. Off by default.
+    pub fn rate_limiter(mut self, rate_limiter: RateLimiter) -> PaystackBuilder {
+        self.config.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Refuse to build a client with an `sk_live_` key unless this is a release build, so that
+    /// tests and local runs can't move real money by accident
+    /// ```rust
+    /// use paystack_rs::prelude::PaystackBuilder;
+    ///
+    /// let built = PaystackBuilder::new("sk_live_xxxx".to_string())
+    ///     .deny_live_keys_in_debug_builds()
+    ///     .build();
+    /// assert_eq!(built.is_err(), cfg!(debug_assertions));
+    /// ```
+    pub fn deny_live_keys_in_debug_builds(mut self) -> PaystackBuilder {
+        self.deny_live_keys = true;
+        self
+    }
+
+    /// Build a blocking client
+    #[cfg(feature = "blocking")]
+    pub fn build(self) -> Result<crate::prelude::Paystack, PaystackError> {
+        let (key, config) = self.finish()?;
+        let http = crate::utils::HttpClient::new(key, config)?;
+        Ok(crate::prelude::Paystack::with_client(http))
+    }
+
+    /// Build an async client
+    #[cfg(feature = "async")]
+    pub fn build_async(self) -> Result<crate::asynchronous::Paystack, PaystackError> {
+        let (key, config) = self.finish()?;
+        let http = crate::utils::asynchronous::HttpClient::new(key, config)?;
+        Ok(crate::asynchronous::Paystack::with_client(http))
+    }
+
+    /// Validate the settings that can't be checked as they are set
+    fn finish(mut self) -> Result<(SecretKey, Config), PaystackError> {
+        let base_url = Url::parse(&self.base_url).map_err(|err| {
+            PaystackError::Config(format!("invalid base url {}: {}", self.base_url, err))
+        })?;
+        if !matches!(base_url.scheme(), "http" | "https") {
+            return Err(PaystackError::Config(format!(
+                "invalid base url {}: scheme must be http or https",
+                self.base_url
+            )));
+        }
+        crate::utils::bearer_header(&self.key)?;
+        if self.deny_live_keys && cfg!(debug_assertions) && self.key.mode() == Some(KeyMode::Live) {
+            return Err(PaystackError::Config(
+                "refusing to use a live secret key in a debug build".to_string(),
+            ));
+        }
+        self.config.base_url = self.base_url.trim_end_matches('/').to_string();
+        Ok((self.key, self.config))
+    }
+}
+
+/// State of a request body builder that still lacks a required field or choice, such as the
+/// amount of a transaction. `build` is only available once every one of them is [`Set`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Missing;
+
+/// State of a request body builder whose required field or choice has been given
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Set;
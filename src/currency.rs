@@ -0,0 +1,144 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{fmt, str::FromStr};
+
+/// A currency Paystack accepts or reports, written as its ISO 4217 code. Codes this version of
+/// the crate doesn't know yet come back from Paystack as `Unknown` instead of failing to
+/// deserialize.
+/// ```rust
+/// use paystack_rs::prelude::Currency;
+///
+/// let currency: Currency = "kes".parse().unwrap();
+/// assert_eq!(currency, Currency::KES);
+/// assert_eq!(currency.to_string(), "KES");
+///
+/// let currency: Currency = serde_json::from_str(r#""XAF""#).unwrap();
+/// assert_eq!(currency, Currency::Unknown("XAF".to_string()));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub enum Currency {
+    /// Nigerian naira
+    #[default]
+    NGN,
+    /// Ghanaian cedi
+    GHS,
+    /// United States dollar
+    USD,
+    /// South African rand
+    ZAR,
+    /// Kenyan shilling
+    KES,
+    /// West African CFA franc, used in Côte d'Ivoire
+    XOF,
+    /// Egyptian pound
+    EGP,
+    /// A currency this version of the crate doesn't know about, with its code as Paystack sent it
+    Unknown(String),
+}
+
+/// A string that is not a currency code
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseCurrencyError(String);
+
+impl Currency {
+    /// The currencies this version of the crate knows about
+    pub const KNOWN: [Currency; 7] = [
+        Currency::NGN,
+        Currency::GHS,
+        Currency::USD,
+        Currency::ZAR,
+        Currency::KES,
+        Currency::XOF,
+        Currency::EGP,
+    ];
+
+    /// The ISO 4217 code, e.g. `NGN`
+    pub fn code(&self) -> &str {
+        match self {
+            Currency::NGN => "NGN",
+            Currency::GHS => "GHS",
+            Currency::USD => "USD",
+            Currency::ZAR => "ZAR",
+            Currency::KES => "KES",
+            Currency::XOF => "XOF",
+            Currency::EGP => "EGP",
+            Currency::Unknown(code) => code,
+        }
+    }
+
+    /// How many decimal places Paystack expects amounts in this currency to have, i.e. how many
+    /// minor units make up one major unit as a power of ten.
+    ///
+    /// This is Paystack's convention, not the ISO 4217 minor unit: Paystack takes every amount
+    /// in hundredths, so this is 2 for every currency, even XOF, which has no minor unit in
+    /// ISO 4217. Don't use it to format amounts for anything but Paystack.
+    /// ```rust
+    /// use paystack_rs::prelude::{Currency, Money};
+    ///
+    /// assert_eq!(Currency::XOF.exponent(), 2);
+    /// // 5,000 francs are sent to Paystack as 500,000
+    /// assert_eq!(Money::from_major(5_000, Currency::XOF).unwrap().minor(), 500_000);
+    /// ```
+    pub fn exponent(&self) -> u32 {
+        match self {
+            Currency::NGN
+            | Currency::GHS
+            | Currency::USD
+            | Currency::ZAR
+            | Currency::KES
+            | Currency::XOF
+            | Currency::EGP
+            | Currency::Unknown(_) => 2,
+        }
+    }
+
+    pub(crate) fn minor_units_per_major(&self) -> i64 {
+        10_i64.pow(self.exponent())
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl FromStr for Currency {
+    type Err = ParseCurrencyError;
+
+    /// Any three letter code, in any case. Codes this crate doesn't know become `Unknown`.
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        if code.len() != 3 || !code.bytes().all(|b| b.is_ascii_alphabetic()) {
+            return Err(ParseCurrencyError(code.to_string()));
+        }
+        let code = code.to_ascii_uppercase();
+        Ok(Currency::KNOWN
+            .iter()
+            .find(|currency| currency.code() == code)
+            .cloned()
+            .unwrap_or(Currency::Unknown(code)))
+    }
+}
+
+impl fmt::Display for ParseCurrencyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} is not a three letter currency code", self.0)
+    }
+}
+
+impl std::error::Error for ParseCurrencyError {}
+
+impl Serialize for Currency {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.code())
+    }
+}
+
+impl<'de> Deserialize<'de> for Currency {
+    /// Never fails on a string, so that a currency Paystack adds later doesn't break a response
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = String::deserialize(deserializer)?;
+        Ok(code
+            .parse()
+            .unwrap_or_else(|_| Currency::Unknown(code.clone())))
+    }
+}
@@ -0,0 +1,193 @@
+use reqwest::{Method, StatusCode};
+use serde::Deserialize;
+use serde_json::Value as JSON;
+use std::fmt;
+
+/// The body Paystack sends back when it rejects a request, e.g. `{"status": false, "message": "Invalid key"}`
+#[derive(Debug, Deserialize)]
+pub struct PaystackErrorBody {
+    /// Always `false` for an error body
+    pub status: bool,
+    /// Human readable reason for the failure
+    pub message: String,
+    /// Validation errors keyed by the offending field, when Paystack sends them
+    pub errors: Option<JSON>,
+    /// Machine readable error code, when Paystack sends one
+    pub code: Option<String>,
+}
+
+/// Every way a call to Paystack can fail
+#[derive(Debug)]
+pub enum PaystackError {
+    /// The request could not be sent, or the response could not be read
+    Transport {
+        /// HTTP method of the failed request
+        method: Method,
+        /// URL of the failed request, without query string or credentials
+        url: String,
+        /// The underlying transport error
+        source: reqwest::Error,
+    },
+    /// The request body could not be serialized to JSON
+    Serialize {
+        /// HTTP method of the failed request
+        method: Method,
+        /// URL of the failed request, without query string or credentials
+        url: String,
+        /// The underlying serialization error
+        source: serde_json::Error,
+    },
+    /// The request parameters could not be encoded into a query string
+    QueryEncode {
+        /// HTTP method of the failed request
+        method: Method,
+        /// URL of the failed request, without query string or credentials
+        url: String,
+        /// The underlying encoding error
+        source: serde_urlencoded::ser::Error,
+    },
+    /// Paystack responded with a non-success status and a body that is not a Paystack error body
+    Http {
+        /// HTTP status of the response
+        status: StatusCode,
+        /// Raw response body
+        body: String,
+    },
+    /// Paystack rejected the request with its `{status: false, message, ...}` error body
+    Api {
+        /// HTTP status of the response
+        status: StatusCode,
+        /// The `message` field of the error body
+        message: String,
+        /// The `errors` field of the error body, if any
+        errors: Option<JSON>,
+        /// The `code` field of the error body, if any
+        code: Option<String>,
+    },
+    /// The response body could not be deserialized into the expected type
+    Deserialize(serde_json::Error),
+    /// The request was rejected locally before it was sent to Paystack
+    Validation(String),
+    /// The client could not be built from the settings it was given
+    Config(String),
+}
+
+impl PaystackError {
+    #[cfg(any(feature = "blocking", feature = "async"))]
+    pub(crate) fn transport(method: Method, url: &str, source: reqwest::Error) -> Self {
+        PaystackError::Transport {
+            method,
+            url: redact_url(url),
+            source: source.without_url(),
+        }
+    }
+
+    #[cfg(any(feature = "blocking", feature = "async"))]
+    pub(crate) fn serialize(method: Method, url: &str, source: serde_json::Error) -> Self {
+        PaystackError::Serialize {
+            method,
+            url: redact_url(url),
+            source,
+        }
+    }
+
+    #[cfg(any(feature = "blocking", feature = "async"))]
+    pub(crate) fn query_encode(
+        method: Method,
+        url: &str,
+        source: serde_urlencoded::ser::Error,
+    ) -> Self {
+        PaystackError::QueryEncode {
+            method,
+            url: redact_url(url),
+            source,
+        }
+    }
+
+    pub(crate) fn validation(msg: impl Into<String>) -> Self {
+        PaystackError::Validation(msg.into())
+    }
+
+    /// The HTTP status Paystack responded with, if the request got that far
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            PaystackError::Http { status, .. } | PaystackError::Api { status, .. } => Some(*status),
+            PaystackError::Transport { source, .. } => source.status(),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for PaystackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PaystackError::Transport {
+                method,
+                url,
+                source,
+            } => write!(f, "error sending {} request to {}: {}", method, url, source),
+            PaystackError::Serialize {
+                method,
+                url,
+                source,
+            } => write!(
+                f,
+                "could not serialize body of {} request to {}: {}",
+                method, url, source
+            ),
+            PaystackError::QueryEncode {
+                method,
+                url,
+                source,
+            } => write!(
+                f,
+                "could not encode query of {} request to {}: {}",
+                method, url, source
+            ),
+            PaystackError::Http { status, body } => {
+                write!(f, "paystack responded with {}: {}", status, body)
+            }
+            PaystackError::Api {
+                status, message, ..
+            } => write!(f, "paystack rejected the request ({}): {}", status, message),
+            PaystackError::Deserialize(err) => {
+                write!(f, "could not deserialize paystack response: {}", err)
+            }
+            PaystackError::Validation(msg) => write!(f, "invalid request: {}", msg),
+            PaystackError::Config(msg) => write!(f, "invalid client configuration: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for PaystackError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PaystackError::Transport { source, .. } => Some(source),
+            PaystackError::Serialize { source, .. } => Some(source),
+            PaystackError::QueryEncode { source, .. } => Some(source),
+            PaystackError::Deserialize(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for PaystackError {
+    fn from(err: serde_json::Error) -> Self {
+        PaystackError::Deserialize(err)
+    }
+}
+
+/// Strip the query string and any credentials from a URL so it is safe to log
+#[cfg(any(feature = "blocking", feature = "async"))]
+fn redact_url(url: &str) -> String {
+    match reqwest::Url::parse(url) {
+        Ok(mut parsed) => {
+            parsed.set_query(None);
+            parsed.set_fragment(None);
+            let _ = parsed.set_username("");
+            let _ = parsed.set_password(None);
+            parsed.to_string()
+        }
+        Err(_) => url.split('?').next().unwrap_or_default().to_string(),
+    }
+}
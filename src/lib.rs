@@ -1,11 +1,35 @@
+#![cfg_attr(not(any(feature = "blocking", feature = "async")), allow(dead_code))]
+#[cfg(feature = "async")]
+pub mod asynchronous;
+mod builder;
+mod currency;
+pub mod error;
+mod money;
+mod pagination;
 mod paystack;
+mod rate_limit;
+mod response;
+mod retry;
+mod secret;
 mod utils;
+mod validation;
+pub mod webhooks;
 pub mod prelude {
+    pub use crate::builder::{
+        Missing, PaystackBuilder, Set, BASE_URL_ENV, DEFAULT_BASE_URL, SECRET_KEY_ENV,
+    };
+    pub use crate::currency::{Currency, ParseCurrencyError};
+    pub use crate::error::*;
+    pub use crate::money::{Money, MoneyError};
+    #[cfg(feature = "async")]
+    pub use crate::pagination::PageStream;
+    #[cfg(feature = "blocking")]
+    pub use crate::pagination::Paginator;
+    pub use crate::pagination::{CursorParams, PageParams};
     pub use crate::paystack::bulk_charges::*;
     pub use crate::paystack::charge::*;
     pub use crate::paystack::control_panel::*;
     pub use crate::paystack::customers::*;
-    pub use crate::paystack::customers::*;
     pub use crate::paystack::dedicated_nuban::*;
     pub use crate::paystack::disputes::*;
     pub use crate::paystack::invoices::*;
@@ -23,5 +47,16 @@ pub mod prelude {
     pub use crate::paystack::transfers;
     pub use crate::paystack::transfers_control::*;
     pub use crate::paystack::verification::*;
+    #[cfg(feature = "blocking")]
     pub use crate::paystack::Paystack;
+    pub use crate::rate_limit::RateLimiter;
+    pub use crate::response::{Meta, PaystackResponse};
+    pub use crate::retry::RetryPolicy;
+    pub use crate::secret::{KeyMode, SecretKey};
+    pub use crate::webhooks::{
+        Event, FileSeenEvents, HandlerError, IpAllowlist, MemorySeenEvents, SeenEvents,
+        SignedWebhook, WebhookError, WebhookSimulator, Webhooks,
+    };
+    #[cfg(feature = "blocking")]
+    pub use crate::webhooks::{EventHandler, WebhookRouter};
 }
@@ -0,0 +1,247 @@
+use crate::currency::Currency;
+use serde::{ser::SerializeMap, Serialize, Serializer};
+use std::fmt;
+
+/// An amount of money in the minor unit of its currency (kobo, pesewas, cents, ...), which is what
+/// Paystack expects in every request. Build it from major units or a decimal string to avoid
+/// sending naira where kobo are expected.
+/// ```rust
+/// use paystack_rs::prelude::{Currency, Money};
+///
+/// let price = Money::from_major(1_500, Currency::NGN).unwrap();
+/// assert_eq!(price.minor(), 150_000);
+///
+/// let fee = Money::from_decimal("100.50", Currency::NGN).unwrap();
+/// let total = price.checked_add(&fee).unwrap();
+/// assert_eq!(total.to_string(), "1600.50 NGN");
+/// ```
+/// A body sends its main amount with the currency next to it:
+/// ```rust
+/// use paystack_rs::prelude::{Currency, InitializeTransactionBody, Money};
+///
+/// let body = InitializeTransactionBody {
+///     email: "customer@example.com".to_string(),
+///     amount: Money::from_decimal("25.50", Currency::GHS).unwrap(),
+///     ..Default::default()
+/// };
+/// let json = serde_json::to_value(&body).unwrap();
+/// assert_eq!(json["amount"], 2550);
+/// assert_eq!(json["currency"], "GHS");
+/// ```
+/// There is no currency to fall back on, so the integration's default currency never applies
+/// to these bodies. The endpoints that take no currency, such as list filters, dispute refunds
+/// and payment pages, get only the minor units, and their fields say which currency the amount
+/// has to be in. Amounts that go with the body's main amount, like a subaccount's
+/// `transaction_charge`, are checked to be in its currency:
+/// ```rust
+/// use paystack_rs::prelude::{Currency, InitializeTransactionBody, Money, PaystackError};
+///
+/// let body = InitializeTransactionBody {
+///     email: "customer@example.com".to_string(),
+///     amount: Money::from_decimal("25.50", Currency::GHS).unwrap(),
+///     subaccount: Some("ACCT_8f4s1eq7ml6rlzj".to_string()),
+///     transaction_charge: Some(Money::from_minor(7_000, Currency::NGN)),
+///     ..Default::default()
+/// };
+/// assert!(matches!(body.validate(), Err(PaystackError::Validation(_))));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Money {
+    minor: i64,
+    currency: Currency,
+}
+
+/// Why an amount could not be built or computed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MoneyError {
+    /// The amount does not fit in an `i64` of minor units
+    Overflow,
+    /// The two amounts are in different currencies
+    CurrencyMismatch(Currency, Currency),
+    /// The decimal string is not a valid amount in the currency, e.g. `"12.345"` naira
+    InvalidDecimal(String),
+}
+
+impl Money {
+    /// An amount already in minor units, e.g. `Money::from_minor(150_000, Currency::NGN)` for ₦1,500
+    pub fn from_minor(minor: i64, currency: Currency) -> Money {
+        Money { minor, currency }
+    }
+
+    /// An amount in major units, e.g. `Money::from_major(1_500, Currency::NGN)` for ₦1,500
+    pub fn from_major(major: i64, currency: Currency) -> Result<Money, MoneyError> {
+        let minor = major
+            .checked_mul(currency.minor_units_per_major())
+            .ok_or(MoneyError::Overflow)?;
+        Ok(Money { minor, currency })
+    }
+
+    /// An amount written in major units with at most [`Currency::exponent`] decimal places,
+    /// e.g. `Money::from_decimal("1500.50", Currency::NGN)`
+    pub fn from_decimal(amount: &str, currency: Currency) -> Result<Money, MoneyError> {
+        let invalid = || MoneyError::InvalidDecimal(amount.to_string());
+        let trimmed = amount.trim();
+        let (negative, digits) = match trimmed.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, trimmed),
+        };
+        let (whole, fraction) = match digits.split_once('.') {
+            Some((whole, fraction)) => (whole, fraction),
+            None => (digits, ""),
+        };
+        let exponent = currency.exponent() as usize;
+        let all_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+        if (whole.is_empty() && fraction.is_empty())
+            || !all_digits(whole)
+            || !all_digits(fraction)
+            || fraction.len() > exponent
+            || (digits.contains('.') && fraction.is_empty())
+        {
+            return Err(invalid());
+        }
+
+        let parse = |part: &str| -> Result<i64, MoneyError> {
+            if part.is_empty() {
+                Ok(0)
+            } else {
+                part.parse().map_err(|_| MoneyError::Overflow)
+            }
+        };
+        let fraction = parse(fraction)? * 10_i64.pow((exponent - fraction.len()) as u32);
+        let minor = parse(whole)?
+            .checked_mul(currency.minor_units_per_major())
+            .and_then(|minor| minor.checked_add(fraction))
+            .ok_or(MoneyError::Overflow)?;
+        Ok(Money {
+            minor: if negative { -minor } else { minor },
+            currency,
+        })
+    }
+
+    /// The amount in minor units
+    pub fn minor(&self) -> i64 {
+        self.minor
+    }
+
+    /// The currency of the amount
+    pub fn currency(&self) -> &Currency {
+        &self.currency
+    }
+
+    /// The sum of two amounts in the same currency
+    pub fn checked_add(&self, other: &Money) -> Result<Money, MoneyError> {
+        self.same_currency(other)?;
+        self.with_minor(self.minor.checked_add(other.minor))
+    }
+
+    /// The difference of two amounts in the same currency
+    pub fn checked_sub(&self, other: &Money) -> Result<Money, MoneyError> {
+        self.same_currency(other)?;
+        self.with_minor(self.minor.checked_sub(other.minor))
+    }
+
+    /// The amount multiplied by a whole number, e.g. a unit price by a quantity
+    pub fn checked_mul(&self, factor: i64) -> Result<Money, MoneyError> {
+        self.with_minor(self.minor.checked_mul(factor))
+    }
+
+    fn same_currency(&self, other: &Money) -> Result<(), MoneyError> {
+        if self.currency == other.currency {
+            Ok(())
+        } else {
+            Err(MoneyError::CurrencyMismatch(
+                self.currency.clone(),
+                other.currency.clone(),
+            ))
+        }
+    }
+
+    fn with_minor(&self, minor: Option<i64>) -> Result<Money, MoneyError> {
+        Ok(Money {
+            minor: minor.ok_or(MoneyError::Overflow)?,
+            currency: self.currency.clone(),
+        })
+    }
+}
+
+impl fmt::Display for Money {
+    /// The amount in major units followed by the currency code, e.g. `1500.50 NGN`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let exponent = self.currency.exponent();
+        let per_major = self.currency.minor_units_per_major().unsigned_abs();
+        let sign = if self.minor < 0 { "-" } else { "" };
+        let minor = self.minor.unsigned_abs();
+        if exponent == 0 {
+            write!(f, "{}{} {}", sign, minor, self.currency)
+        } else {
+            write!(
+                f,
+                "{}{}.{:0width$} {}",
+                sign,
+                minor / per_major,
+                minor % per_major,
+                self.currency,
+                width = exponent as usize
+            )
+        }
+    }
+}
+
+impl fmt::Display for MoneyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MoneyError::Overflow => write!(f, "amount does not fit in an i64 of minor units"),
+            MoneyError::CurrencyMismatch(left, right) => {
+                write!(f, "cannot combine {} and {} amounts", left, right)
+            }
+            MoneyError::InvalidDecimal(amount) => write!(f, "invalid amount {:?}", amount),
+        }
+    }
+}
+
+impl std::error::Error for MoneyError {}
+
+impl Serialize for Money {
+    /// Just the minor units, for fields whose currency is given elsewhere
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(self.minor)
+    }
+}
+
+/// Serialize a flattened `Money` field as the body's `amount` and `currency`
+pub(crate) fn amount_with_currency<S: Serializer>(
+    money: &Money,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    with_currency("amount", money, serializer)
+}
+
+/// Like [`amount_with_currency`], leaving both fields out when there is no amount
+pub(crate) fn optional_amount_with_currency<S: Serializer>(
+    money: &Option<Money>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match money {
+        Some(money) => with_currency("amount", money, serializer),
+        None => serializer.serialize_map(Some(0))?.end(),
+    }
+}
+
+/// Serialize a flattened `Money` field as the body's `price` and `currency`
+pub(crate) fn price_with_currency<S: Serializer>(
+    money: &Money,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    with_currency("price", money, serializer)
+}
+
+fn with_currency<S: Serializer>(
+    key: &'static str,
+    money: &Money,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut map = serializer.serialize_map(Some(2))?;
+    map.serialize_entry(key, &money.minor)?;
+    map.serialize_entry("currency", &money.currency)?;
+    map.end()
+}
@@ -0,0 +1,672 @@
+use crate::paystack::{
+    bulk_charges::ListBulkChargesParams,
+    customers::ListCustomersParams,
+    disputes::ListDisputesParams,
+    invoices::ListInvoicesParams,
+    miscellaneous::ListBanksParams,
+    payment_pages::ListPagesParams,
+    plans::ListPlansParams,
+    products::ListProductsParams,
+    refund::ListRefundsParams,
+    settlements::{FetchSettleTxBody, FetchSettlementsBody},
+    subaccounts::ListSubaccountParams,
+    subscription::ListSubscriptionParams,
+    transactions::ListTransactionsParams,
+    transactions_split::ListOrSearchSplitsParams,
+    transfer_recipients::ListTransferRecipientsParams,
+    transfers::ListTransfersParams,
+};
+#[cfg(any(feature = "blocking", feature = "async"))]
+use crate::{
+    error::PaystackError,
+    response::{Meta, PaystackResponse},
+};
+#[cfg(any(feature = "blocking", feature = "async"))]
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+#[cfg(feature = "async")]
+use {
+    futures_util::stream::{self, BoxStream, Stream, StreamExt},
+    std::{
+        future::Future,
+        pin::Pin,
+        task::{Context, Poll},
+    },
+};
+
+/// Query parameters of a page based `list_*` endpoint. The paginators use it to ask for one page
+/// after another, leaving every other filter (`per_page`, `from`, `to`, ...) as it was set.
+pub trait PageParams {
+    /// The page the parameters ask for, if set
+    fn page(&self) -> Option<u64>;
+    /// Ask for another page
+    fn set_page(&mut self, page: u64);
+}
+
+/// Implement [`PageParams`] for parameter structs with a `page: Option<integer>` field
+macro_rules! impl_page_params {
+    ($($params: ty),* $(,)?) => {
+        $(
+            impl $crate::pagination::PageParams for $params {
+                fn page(&self) -> Option<u64> {
+                    self.page.and_then(|page| u64::try_from(page).ok())
+                }
+
+                fn set_page(&mut self, page: u64) {
+                    self.page = Some(page as _);
+                }
+            }
+        )*
+    };
+}
+
+impl_page_params!(
+    ListBulkChargesParams,
+    ListCustomersParams,
+    ListDisputesParams<'_>,
+    ListInvoicesParams<'_>,
+    ListPagesParams,
+    ListPlansParams,
+    ListProductsParams,
+    ListRefundsParams<'_>,
+    FetchSettlementsBody<'_>,
+    FetchSettleTxBody<'_>,
+    ListSubaccountParams,
+    ListTransactionsParams,
+    ListOrSearchSplitsParams<'_>,
+    ListTransferRecipientsParams,
+    ListTransfersParams<'_>,
+);
+
+impl PageParams for ListSubscriptionParams {
+    fn page(&self) -> Option<u64> {
+        u64::try_from(self.page).ok()
+    }
+
+    fn set_page(&mut self, page: u64) {
+        self.page = page as i128;
+    }
+}
+
+/// Query parameters of a cursor based `list_*` endpoint, which hands out `meta.next` and
+/// `meta.previous` cursors instead of page numbers. The paginators follow `meta.next`, or
+/// `meta.previous` when the parameters start from a `previous` cursor and no `next` one.
+pub trait CursorParams {
+    /// The cursor of the page after which to list, if set
+    fn next(&self) -> Option<&str>;
+    /// The cursor of the page before which to list, if set
+    fn previous(&self) -> Option<&str>;
+    /// Ask for the page after `cursor`
+    fn set_next(&mut self, cursor: Option<String>);
+    /// Ask for the page before `cursor`
+    fn set_previous(&mut self, cursor: Option<String>);
+}
+
+impl CursorParams for ListBanksParams<'_> {
+    fn next(&self) -> Option<&str> {
+        self.next.as_deref()
+    }
+
+    fn previous(&self) -> Option<&str> {
+        self.previous.as_deref()
+    }
+
+    fn set_next(&mut self, cursor: Option<String>) {
+        self.next = cursor;
+    }
+
+    fn set_previous(&mut self, cursor: Option<String>) {
+        self.previous = cursor;
+    }
+}
+
+/// Move page based parameters on to the next page. `false` if the page just fetched was the last.
+#[cfg(any(feature = "blocking", feature = "async"))]
+fn next_page<P: PageParams>(params: &mut P, meta: &Meta, count: u64) -> bool {
+    let page = params.page().unwrap_or(1);
+    let more = match (meta.page_count, meta.per_page) {
+        (Some(page_count), _) => page < page_count,
+        (None, Some(per_page)) => count >= per_page,
+        (None, None) => true,
+    };
+    if more {
+        params.set_page(page + 1);
+    }
+    more
+}
+
+/// Move cursor based parameters on to `meta.next`. `false` if there is no page after this one.
+#[cfg(any(feature = "blocking", feature = "async"))]
+fn next_cursor<P: CursorParams>(params: &mut P, meta: &Meta, _count: u64) -> bool {
+    match meta.next.as_deref() {
+        Some(next) if !next.is_empty() && params.next() != Some(next) => {
+            params.set_next(Some(next.to_string()));
+            params.set_previous(None);
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Move cursor based parameters on to `meta.previous`. `false` if there is no page before this one.
+#[cfg(any(feature = "blocking", feature = "async"))]
+fn previous_cursor<P: CursorParams>(params: &mut P, meta: &Meta, _count: u64) -> bool {
+    match meta.previous.as_deref() {
+        Some(previous) if !previous.is_empty() && params.previous() != Some(previous) => {
+            params.set_previous(Some(previous.to_string()));
+            params.set_next(None);
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Which of the cursor steps to follow, going by where the parameters start from
+#[cfg(any(feature = "blocking", feature = "async"))]
+fn cursor_step<P: CursorParams>(params: &P) -> Advance<P> {
+    if params.previous().is_some() && params.next().is_none() {
+        previous_cursor::<P>
+    } else {
+        next_cursor::<P>
+    }
+}
+
+/// Moves the parameters on to the page after the one just fetched, given that page's `meta` and
+/// number of items. Returns `false` if that page was the last one.
+#[cfg(any(feature = "blocking", feature = "async"))]
+type Advance<P> = fn(&mut P, &Meta, u64) -> bool;
+
+/// Where a paginator is in the list, shared by the blocking and the async one
+#[cfg(any(feature = "blocking", feature = "async"))]
+struct PageState<T, P> {
+    params: P,
+    advance: Advance<P>,
+    buffer: VecDeque<T>,
+    done: bool,
+    remaining: Option<usize>,
+}
+
+#[cfg(any(feature = "blocking", feature = "async"))]
+impl<T, P> PageState<T, P> {
+    fn new(params: P, advance: Advance<P>) -> Self {
+        PageState {
+            params,
+            advance,
+            buffer: VecDeque::new(),
+            done: false,
+            remaining: None,
+        }
+    }
+
+    /// Start from the `page` the parameters ask for, or from the first one
+    fn pages(mut params: P) -> Self
+    where
+        P: PageParams,
+    {
+        let page = params.page().unwrap_or(1).max(1);
+        params.set_page(page);
+        PageState::new(params, next_page::<P>)
+    }
+
+    /// Start from the cursor the parameters ask for, or from the start of the list
+    fn cursors(params: P) -> Self
+    where
+        P: CursorParams,
+    {
+        let advance = cursor_step(&params);
+        PageState::new(params, advance)
+    }
+
+    /// The next item already fetched, unless the cap has been reached
+    fn pop(&mut self) -> Option<T> {
+        if self.remaining == Some(0) {
+            return None;
+        }
+        let item = self.buffer.pop_front()?;
+        if let Some(remaining) = &mut self.remaining {
+            *remaining -= 1;
+        }
+        Some(item)
+    }
+
+    /// Whether another page has to be fetched before the next item can be returned
+    fn needs_page(&self) -> bool {
+        self.buffer.is_empty() && !self.done && self.remaining != Some(0)
+    }
+
+    /// Take in a fetched page and move on to the next one, unless it was the last one
+    fn push_page(&mut self, res: PaystackResponse<Vec<T>>) {
+        let count = res.data.len() as u64;
+        let meta = res.meta.unwrap_or_default();
+        self.done = count == 0 || !(self.advance)(&mut self.params, &meta, count);
+        self.buffer.extend(res.data);
+    }
+}
+
+/// A lazy iterator over every item of a `list_*` endpoint, fetching the next page only once the
+/// items of the current one have been used up. It stops after the last page, after
+/// [`Paginator::max_items`] items, or after the first error.
+/// ```no_run
+/// use paystack_rs::prelude::{ListTransactionsParams, Paystack};
+///
+/// let paystack = Paystack::new("sk_test_xxxx".to_string());
+/// let params = ListTransactionsParams {
+///     per_page: Some(100),
+///     ..Default::default()
+/// };
+/// for transaction in paystack.transaction.list_transactions_iter(params).max_items(500) {
+///     match transaction {
+///         Ok(transaction) => println!("{} {}", transaction.reference, transaction.status),
+///         Err(err) => eprintln!("could not list transactions: {}", err),
+///     }
+/// }
+/// ```
+#[cfg(feature = "blocking")]
+pub struct Paginator<'a, T, P> {
+    state: PageState<T, P>,
+    #[allow(clippy::type_complexity)]
+    fetch: Box<dyn FnMut(&P) -> Result<PaystackResponse<Vec<T>>, PaystackError> + 'a>,
+}
+
+#[cfg(feature = "blocking")]
+impl<'a, T, P> Paginator<'a, T, P> {
+    /// Walk a page based endpoint, fetching each page with `fetch`
+    pub(crate) fn new<F>(params: P, fetch: F) -> Self
+    where
+        P: PageParams,
+        F: FnMut(&P) -> Result<PaystackResponse<Vec<T>>, PaystackError> + 'a,
+    {
+        Paginator {
+            state: PageState::pages(params),
+            fetch: Box::new(fetch),
+        }
+    }
+
+    /// Walk a cursor based endpoint, fetching each page with `fetch`
+    pub(crate) fn cursor<F>(params: P, fetch: F) -> Self
+    where
+        P: CursorParams,
+        F: FnMut(&P) -> Result<PaystackResponse<Vec<T>>, PaystackError> + 'a,
+    {
+        Paginator {
+            state: PageState::cursors(params),
+            fetch: Box::new(fetch),
+        }
+    }
+
+    /// Stop after this many items, however many more there are
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.state.remaining = Some(max_items);
+        self
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<T, P> Iterator for Paginator<'_, T, P> {
+    type Item = Result<T, PaystackError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.state.needs_page() {
+            match (self.fetch)(&self.state.params) {
+                Ok(res) => self.state.push_page(res),
+                Err(err) => {
+                    self.state.done = true;
+                    return Some(Err(err));
+                }
+            }
+        }
+        self.state.pop().map(Ok)
+    }
+}
+
+/// The async counterpart of [`Paginator`]: a [`Stream`] over every item of a `list_*` endpoint
+/// that fetches the next page only once the items of the current one have been used up.
+/// ```no_run
+/// use futures::StreamExt;
+/// use paystack_rs::asynchronous::Paystack;
+/// use paystack_rs::prelude::ListCustomersParams;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let paystack = Paystack::new("sk_test_xxxx".to_string());
+/// let mut customers = paystack
+///     .customers
+///     .list_customers_stream(ListCustomersParams::default())
+///     .max_items(200);
+/// while let Some(customer) = customers.next().await {
+///     match customer {
+///         Ok(customer) => println!("{}", customer["email"]),
+///         Err(err) => eprintln!("could not list customers: {}", err),
+///     }
+/// }
+/// # }
+/// ```
+#[cfg(feature = "async")]
+pub struct PageStream<'a, T> {
+    inner: BoxStream<'a, Result<T, PaystackError>>,
+    remaining: Option<usize>,
+}
+
+#[cfg(feature = "async")]
+impl<'a, T: Send + 'a> PageStream<'a, T> {
+    /// Walk a page based endpoint, fetching each page with `fetch`
+    pub(crate) fn new<P, F, Fut>(params: P, fetch: F) -> Self
+    where
+        P: PageParams + Clone + Send + 'a,
+        F: Fn(P) -> Fut + Send + 'a,
+        Fut: Future<Output = Result<PaystackResponse<Vec<T>>, PaystackError>> + Send + 'a,
+    {
+        PageStream::from_state(PageState::pages(params), fetch)
+    }
+
+    /// Walk a cursor based endpoint, fetching each page with `fetch`
+    pub(crate) fn cursor<P, F, Fut>(params: P, fetch: F) -> Self
+    where
+        P: CursorParams + Clone + Send + 'a,
+        F: Fn(P) -> Fut + Send + 'a,
+        Fut: Future<Output = Result<PaystackResponse<Vec<T>>, PaystackError>> + Send + 'a,
+    {
+        PageStream::from_state(PageState::cursors(params), fetch)
+    }
+
+    fn from_state<P, F, Fut>(state: PageState<T, P>, fetch: F) -> Self
+    where
+        P: Clone + Send + 'a,
+        F: Fn(P) -> Fut + Send + 'a,
+        Fut: Future<Output = Result<PaystackResponse<Vec<T>>, PaystackError>> + Send + 'a,
+    {
+        let inner = stream::unfold((state, fetch), |(mut state, fetch)| async move {
+            while state.needs_page() {
+                match fetch(state.params.clone()).await {
+                    Ok(res) => state.push_page(res),
+                    Err(err) => {
+                        state.done = true;
+                        return Some((Err(err), (state, fetch)));
+                    }
+                }
+            }
+            let item = state.pop()?;
+            Some((Ok(item), (state, fetch)))
+        });
+        PageStream {
+            inner: inner.boxed(),
+            remaining: None,
+        }
+    }
+
+    /// Stop after this many items, however many more there are
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.remaining = Some(max_items);
+        self
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T> Stream for PageStream<'_, T> {
+    type Item = Result<T, PaystackError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.remaining == Some(0) {
+            return Poll::Ready(None);
+        }
+        let item = self.inner.poll_next_unpin(cx);
+        if let (Poll::Ready(Some(_)), Some(remaining)) = (&item, &mut self.remaining) {
+            *remaining -= 1;
+        }
+        item
+    }
+}
+
+#[cfg(all(test, any(feature = "blocking", feature = "async")))]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    /// Page `number`, holding `count` items numbered after it, e.g. 21 and 22 on page 2
+    fn page(number: u64, count: u64, meta: Option<Meta>) -> PaystackResponse<Vec<u64>> {
+        PaystackResponse {
+            status: true,
+            message: "Transactions retrieved".to_string(),
+            data: (1..=count).map(|item| number * 10 + item).collect(),
+            meta,
+        }
+    }
+
+    fn page_count(page_count: u64) -> Option<Meta> {
+        Some(Meta {
+            page_count: Some(page_count),
+            ..Default::default()
+        })
+    }
+
+    #[cfg(feature = "blocking")]
+    mod blocking {
+        use super::*;
+        use crate::paystack::transactions::ListTransactionsParams;
+
+        /// Walk the pages `fetch` serves, returning the items and the pages asked for
+        fn walk<F>(
+            params: ListTransactionsParams,
+            max_items: Option<usize>,
+            fetch: F,
+        ) -> (Vec<Result<u64, PaystackError>>, Vec<u64>)
+        where
+            F: Fn(u64) -> Result<PaystackResponse<Vec<u64>>, PaystackError>,
+        {
+            let requested = Arc::new(Mutex::new(Vec::new()));
+            let log = requested.clone();
+            let mut paginator = Paginator::new(params, move |params: &ListTransactionsParams| {
+                let page = params.page().unwrap();
+                log.lock().unwrap().push(page);
+                fetch(page)
+            });
+            if let Some(max_items) = max_items {
+                paginator = paginator.max_items(max_items);
+            }
+            let items = paginator.collect();
+            let requested = requested.lock().unwrap().clone();
+            (items, requested)
+        }
+
+        fn values(items: Vec<Result<u64, PaystackError>>) -> Vec<u64> {
+            items.into_iter().map(Result::unwrap).collect()
+        }
+
+        #[test]
+        fn follows_pages_until_the_last_one() {
+            let (items, requested) =
+                walk(Default::default(), None, |n| Ok(page(n, 2, page_count(3))));
+            assert_eq!(values(items), [11, 12, 21, 22, 31, 32]);
+            assert_eq!(requested, [1, 2, 3]);
+        }
+
+        #[test]
+        fn starts_from_the_page_asked_for() {
+            let params = ListTransactionsParams {
+                page: Some(2),
+                ..Default::default()
+            };
+            let (items, requested) = walk(params, None, |n| Ok(page(n, 1, page_count(3))));
+            assert_eq!(values(items), [21, 31]);
+            assert_eq!(requested, [2, 3]);
+        }
+
+        #[test]
+        fn stops_on_a_short_page_without_a_page_count() {
+            let meta = Some(Meta {
+                per_page: Some(2),
+                ..Default::default()
+            });
+            let (items, requested) = walk(Default::default(), None, |n| {
+                Ok(page(n, if n == 1 { 2 } else { 1 }, meta.clone()))
+            });
+            assert_eq!(values(items), [11, 12, 21]);
+            assert_eq!(requested, [1, 2]);
+        }
+
+        #[test]
+        fn stops_on_an_empty_page_without_meta() {
+            let (items, requested) = walk(Default::default(), None, |n| {
+                Ok(page(n, if n < 3 { 1 } else { 0 }, None))
+            });
+            assert_eq!(values(items), [11, 21]);
+            assert_eq!(requested, [1, 2, 3]);
+        }
+
+        #[test]
+        fn max_items_truncates_without_fetching_further_pages() {
+            let (items, requested) = walk(Default::default(), Some(3), |n| {
+                Ok(page(n, 2, page_count(10)))
+            });
+            assert_eq!(values(items), [11, 12, 21]);
+            assert_eq!(requested, [1, 2]);
+        }
+
+        #[test]
+        fn stops_after_an_error() {
+            let (items, requested) = walk(Default::default(), None, |n| match n {
+                1 => Ok(page(n, 1, page_count(3))),
+                _ => Err(PaystackError::validation("page unavailable")),
+            });
+            assert_eq!(items.len(), 2);
+            assert!(matches!(items[0], Ok(11)));
+            assert!(matches!(items[1], Err(PaystackError::Validation(_))));
+            assert_eq!(requested, [1, 2]);
+        }
+    }
+
+    #[cfg(feature = "blocking")]
+    mod cursors {
+        use super::*;
+        use crate::paystack::miscellaneous::ListBanksParams;
+
+        fn params(next: Option<&str>, previous: Option<&str>) -> ListBanksParams<'static> {
+            ListBanksParams {
+                country: "nigeria",
+                use_cursor: true,
+                per_page: 2,
+                next: next.map(str::to_string),
+                previous: previous.map(str::to_string),
+                gateway: None,
+                ttype: "",
+                currency: None,
+            }
+        }
+
+        /// The `next` and `previous` cursors a request asked for
+        type Cursors = (Option<String>, Option<String>);
+
+        fn cursors(next: Option<&str>, previous: Option<&str>) -> Option<Meta> {
+            Some(Meta {
+                next: next.map(str::to_string),
+                previous: previous.map(str::to_string),
+                ..Default::default()
+            })
+        }
+
+        /// Walk a list of three pages, `a`, `b` and `c`, returning the items and the
+        /// `(next, previous)` cursors each request asked for
+        fn walk(params: ListBanksParams<'static>) -> (Vec<u64>, Vec<Cursors>) {
+            let requested = Arc::new(Mutex::new(Vec::new()));
+            let log = requested.clone();
+            let paginator = Paginator::cursor(params, move |params: &ListBanksParams| {
+                log.lock()
+                    .unwrap()
+                    .push((params.next.clone(), params.previous.clone()));
+                let cursor = params.next.as_deref().or(params.previous.as_deref());
+                Ok(match cursor {
+                    None | Some("a") => page(1, 2, cursors(Some("b"), None)),
+                    Some("b") => page(2, 2, cursors(Some("c"), Some("a"))),
+                    // The last page points back at itself
+                    _ => page(3, 1, cursors(Some("c"), Some("b"))),
+                })
+            });
+            let items = paginator.map(Result::unwrap).collect();
+            let requested = requested.lock().unwrap().clone();
+            (items, requested)
+        }
+
+        fn cursor(next: Option<&str>, previous: Option<&str>) -> Cursors {
+            (next.map(str::to_string), previous.map(str::to_string))
+        }
+
+        #[test]
+        fn follows_next_cursors_until_they_stop_moving() {
+            let (items, requested) = walk(params(None, None));
+            assert_eq!(items, [11, 12, 21, 22, 31]);
+            assert_eq!(
+                requested,
+                [
+                    cursor(None, None),
+                    cursor(Some("b"), None),
+                    cursor(Some("c"), None)
+                ]
+            );
+        }
+
+        #[test]
+        fn follows_previous_cursors_when_starting_from_one() {
+            let (items, requested) = walk(params(None, Some("c")));
+            assert_eq!(items, [31, 21, 22, 11, 12]);
+            assert_eq!(
+                requested,
+                [
+                    cursor(None, Some("c")),
+                    cursor(None, Some("b")),
+                    cursor(None, Some("a"))
+                ]
+            );
+        }
+
+        #[test]
+        fn stops_without_meta() {
+            let mut fetched = 0;
+            let paginator = Paginator::cursor(params(None, None), |_: &ListBanksParams| {
+                fetched += 1;
+                Ok(page(1, 2, None))
+            });
+            assert_eq!(paginator.count(), 2);
+            assert_eq!(fetched, 1);
+        }
+    }
+
+    #[cfg(feature = "async")]
+    mod stream {
+        use super::*;
+        use crate::paystack::transactions::ListTransactionsParams;
+        use futures_util::StreamExt;
+
+        async fn walk(max_items: Option<usize>, pages: u64) -> (Vec<u64>, Vec<u64>) {
+            let requested = Arc::new(Mutex::new(Vec::new()));
+            let log = requested.clone();
+            let mut stream = PageStream::new(
+                ListTransactionsParams::default(),
+                move |params: ListTransactionsParams| {
+                    let page_number = params.page().unwrap();
+                    log.lock().unwrap().push(page_number);
+                    async move { Ok(page(page_number, 2, page_count(pages))) }
+                },
+            );
+            if let Some(max_items) = max_items {
+                stream = stream.max_items(max_items);
+            }
+            let items = stream.map(Result::unwrap).collect().await;
+            let requested = requested.lock().unwrap().clone();
+            (items, requested)
+        }
+
+        #[tokio::test]
+        async fn follows_pages_until_the_last_one() {
+            let (items, requested) = walk(None, 2).await;
+            assert_eq!(items, [11, 12, 21, 22]);
+            assert_eq!(requested, [1, 2]);
+        }
+
+        #[tokio::test]
+        async fn max_items_truncates_without_fetching_further_pages() {
+            let (items, requested) = walk(Some(3), 10).await;
+            assert_eq!(items, [11, 12, 21]);
+            assert_eq!(requested, [1, 2]);
+        }
+    }
+}
@@ -20,39 +20,57 @@ pub mod transfers;
 pub mod transfers_control;
 pub mod verification;
 
-/// Initialize a struct with bearer auth key
+/// Initialize a struct with the client's shared HTTP client, which also holds the secret key
 #[macro_export]
 macro_rules! auth_init {
-    ($Foo: ident, $auth: expr) => {
+    ($Foo: ident, $http: expr) => {
         $Foo {
-            bearer_auth: $auth.to_string(),
-            ..Default::default()
+            http: $http.clone(),
         }
     };
 }
 
-use bulk_charges::BulkCharges;
-use charge::Charge;
-use control_panel::ControlPanel;
-use dedicated_nuban::DedicatedNuban;
-use disputes::Disputes;
-use invoices::Invoices;
-use miscellaneous::Miscellaneous;
-use payment_pages::PaymentPages;
-use plans::Plans;
-use products::Products;
-use refund::Refunds;
-use settlements::Settlements;
-use subaccounts::Subaccount;
-use subscription::Subscription;
-use transactions::Transaction;
-use transactions_split::TransactionSplit;
-use transfer_recipients::TransferRecipients;
-use transfers::Transfers;
-use transfers_control::TransfersControl;
-use verification::Verification;
+#[cfg(feature = "blocking")]
+use crate::{
+    builder::{Config, PaystackBuilder},
+    error::PaystackError,
+    secret::{KeyMode, SecretKey},
+    utils::HttpClient,
+    webhooks::Webhooks,
+};
+#[cfg(feature = "blocking")]
+use std::sync::Arc;
 
-#[derive(Default)]
+#[cfg(feature = "blocking")]
+use self::{
+    bulk_charges::BulkCharges, charge::Charge, control_panel::ControlPanel, customers::Customer,
+    dedicated_nuban::DedicatedNuban, disputes::Disputes, invoices::Invoices,
+    miscellaneous::Miscellaneous, payment_pages::PaymentPages, plans::Plans, products::Products,
+    refund::Refunds, settlements::Settlements, subaccounts::Subaccount, subscription::Subscription,
+    transactions::Transaction, transactions_split::TransactionSplit,
+    transfer_recipients::TransferRecipients, transfers::Transfers,
+    transfers_control::TransfersControl, verification::Verification,
+};
+
+/// Blocking Paystack client. Every resource shares one connection pool, and cloning
+/// the client is cheap, so a clone can be handed to each worker thread.
+/// ```no_run
+/// use paystack_rs::prelude::Paystack;
+/// use std::thread;
+///
+/// let paystack = Paystack::new("sk_test_xxxx".to_string());
+/// let workers: Vec<_> = (0..2)
+///     .map(|_| {
+///         let paystack = paystack.clone();
+///         thread::spawn(move || paystack.transaction.transactions_total(None).is_err())
+///     })
+///     .collect();
+/// for worker in workers {
+///     worker.join().unwrap();
+/// }
+/// ```
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
 pub struct Paystack {
     pub transaction: Transaction,
     pub transaction_split: TransactionSplit,
@@ -74,32 +92,69 @@ pub struct Paystack {
     pub disputes: Disputes,
     pub verification: Verification,
     pub miscellaneous: Miscellaneous,
+    pub customers: Customer,
+    /// Verifies the signature of webhooks sent to this integration
+    pub webhooks: Webhooks,
+    http: Arc<HttpClient>,
 }
 
+#[cfg(feature = "blocking")]
 impl Paystack {
+    /// Create a client with the default settings.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the TLS backend cannot be initialized, like `reqwest::blocking::Client::new`.
+    /// Use [`Paystack::builder`] to handle that error instead.
     pub fn new(key: String) -> Paystack {
-        let formatted_bearer = format!("Bearer {}", key);
+        let http = HttpClient::new(SecretKey::new(key), Config::default())
+            .expect("failed to initialize HTTP client");
+        Paystack::with_client(http)
+    }
+
+    /// Start configuring a client with a custom base URL, timeouts, proxy, headers or user agent
+    pub fn builder(key: String) -> PaystackBuilder {
+        PaystackBuilder::new(key)
+    }
+
+    /// Create a client with the default settings from `PAYSTACK_SECRET_KEY` and, if set,
+    /// `PAYSTACK_BASE_URL`, read from the environment or a `.env` file.
+    /// Use [`PaystackBuilder::from_env`] to configure it further.
+    pub fn from_env() -> Result<Paystack, PaystackError> {
+        PaystackBuilder::from_env()?.build()
+    }
+
+    /// Whether the client uses a test or a live key. `None` if the key has neither prefix.
+    pub fn mode(&self) -> Option<KeyMode> {
+        self.http.key.mode()
+    }
+
+    pub(crate) fn with_client(http: HttpClient) -> Paystack {
+        let http = Arc::new(http);
         Paystack {
-            transaction: auth_init!(Transaction, formatted_bearer),
-            transaction_split: auth_init!(TransactionSplit, formatted_bearer),
-            refund: auth_init!(Refunds, formatted_bearer),
-            subaccounts: auth_init!(Subaccount, formatted_bearer),
-            dedicated_nuban: auth_init!(DedicatedNuban, formatted_bearer),
-            plans: auth_init!(Plans, formatted_bearer),
-            subscription: auth_init!(Subscription, formatted_bearer),
-            products: auth_init!(Products, formatted_bearer),
-            invoices: auth_init!(Invoices, formatted_bearer),
-            settlements: auth_init!(Settlements, formatted_bearer),
-            transfer_recipients: auth_init!(TransferRecipients, formatted_bearer),
-            transfers: auth_init!(Transfers, formatted_bearer),
-            transfers_control: auth_init!(TransfersControl, formatted_bearer),
-            bulk_charges: auth_init!(BulkCharges, formatted_bearer),
-            control_panel: auth_init!(ControlPanel, formatted_bearer),
-            disputes: auth_init!(Disputes, formatted_bearer),
-            verification: auth_init!(Verification, formatted_bearer),
-            miscellaneous: auth_init!(Miscellaneous, formatted_bearer),
-            charge: auth_init!(Charge, formatted_bearer),
-            payment_pages: auth_init!(PaymentPages, formatted_bearer),
+            transaction: auth_init!(Transaction, http),
+            transaction_split: auth_init!(TransactionSplit, http),
+            refund: auth_init!(Refunds, http),
+            subaccounts: auth_init!(Subaccount, http),
+            dedicated_nuban: auth_init!(DedicatedNuban, http),
+            plans: auth_init!(Plans, http),
+            subscription: auth_init!(Subscription, http),
+            products: auth_init!(Products, http),
+            invoices: auth_init!(Invoices, http),
+            settlements: auth_init!(Settlements, http),
+            transfer_recipients: auth_init!(TransferRecipients, http),
+            transfers: auth_init!(Transfers, http),
+            transfers_control: auth_init!(TransfersControl, http),
+            bulk_charges: auth_init!(BulkCharges, http),
+            control_panel: auth_init!(ControlPanel, http),
+            disputes: auth_init!(Disputes, http),
+            verification: auth_init!(Verification, http),
+            miscellaneous: auth_init!(Miscellaneous, http),
+            charge: auth_init!(Charge, http),
+            payment_pages: auth_init!(PaymentPages, http),
+            customers: auth_init!(Customer, http),
+            webhooks: Webhooks::new(http.key.clone()),
+            http,
         }
     }
 }
@@ -1,12 +1,22 @@
-use crate::utils::make_get_request;
+#[cfg(feature = "blocking")]
+use crate::{
+    error::PaystackError,
+    pagination::Paginator,
+    response::PaystackResponse,
+    utils::{make_get_request, HttpClient},
+};
 use chrono::{DateTime, Local};
-use reqwest::blocking::Response;
 use serde::Serialize;
+#[cfg(feature = "blocking")]
+use serde_json::Value as JSON;
+#[cfg(feature = "blocking")]
+use std::sync::Arc;
 
+#[cfg(feature = "blocking")]
 /// The Bulk Charges API allows you create and manage multiple recurring payments from your customers
-#[derive(Debug, Default)]
+#[derive(Debug, Clone)]
 pub struct BulkCharges {
-    pub(crate) bearer_auth: String,
+    pub(crate) http: Arc<HttpClient>,
 }
 
 // #[derive(Debug, Serialize)]
@@ -22,7 +32,7 @@ pub enum BulkChargesStatus {
     PENDING,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct ListBulkChargesParams {
     /// Specify how many records you want to retrieve per page. If not specify we use a default value of 50.
     #[serde(rename = "perPage")]
@@ -49,15 +59,16 @@ pub struct FetchChargesInABatchParams {
     /// A timestamp at which to stop listing product e.g. 2016-09-24T00:00:05.000Z, 2016-09-21
     pub to: Option<DateTime<Local>>,
 }
-const BULK_CHARGES_URL: &str = "https://api.paystack.co/bulkcharge";
+pub(crate) const BULK_CHARGES_PATH: &str = "/bulkcharge";
+#[cfg(feature = "blocking")]
 impl BulkCharges {
     // FIXME: the docs dont say what it is here, hence I wont be implementing this method until the docs are clear
-    // pub fn initiate_bulk_charges(&self, body: InitiateBulkChargesBody) -> Result<Response, String> {
+    // pub fn initiate_bulk_charges(&self, body: InitiateBulkChargesBody) -> Result<PaystackResponse<JSON>, PaystackError> {
     //     let res = make_request(
     //         &self.bearer_auth,
-    //         BULK_CHARGES_URL,
+    //         BULK_CHARGES_PATH,
     //         Some(body),
-    //         REQUEST::POST,
+    //         Method::POST,
     //     );
     //     return res;
     // }
@@ -66,49 +77,64 @@ impl BulkCharges {
     pub fn list_bulk_charges(
         &self,
         params: Option<ListBulkChargesParams>,
-    ) -> Result<Response, String> {
-        let res = make_get_request(&self.bearer_auth, BULK_CHARGES_URL, params);
-        return res;
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_get_request(&self.http, BULK_CHARGES_PATH, params)
+    }
+
+    /// Iterate over every bulk charge batch, fetching further pages as they are needed
+    pub fn list_bulk_charges_iter(
+        &self,
+        params: ListBulkChargesParams,
+    ) -> Paginator<'static, JSON, ListBulkChargesParams> {
+        let http = self.http.clone();
+        Paginator::new(params, move |params| {
+            make_get_request(&http, BULK_CHARGES_PATH, Some(params))
+        })
     }
 
     /// This endpoint retrieves a specific batch code.
     /// It also returns useful information on its progress by way of the `total_charges` and `pending_charges` attributes.
     /// - id_or_code:
-    /// An ID or code for the charge whose batches you want to retrieve.
-    pub fn fetch_bulk_charge_batch(&self, id_or_code: &str) -> Result<Response, String> {
-        let url = format!("{}/{}", BULK_CHARGES_URL, id_or_code);
-        let res = make_get_request(&self.bearer_auth, &url, None::<String>);
-        return res;
+    ///   An ID or code for the charge whose batches you want to retrieve.
+    pub fn fetch_bulk_charge_batch(
+        &self,
+        id_or_code: &str,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", BULK_CHARGES_PATH, id_or_code);
+        make_get_request(&self.http, &path, None::<String>)
     }
     /// This endpoint retrieves the charges associated with a specified batch code. Pagination parameters are available.
     /// You can also filter by status. Charge statuses can be pending, success or failed.
     /// - id_or_code:
-    /// An ID or code for the charge whose batches you want to retrieve.
+    ///   An ID or code for the charge whose batches you want to retrieve.
     pub fn fetch_charges_in_a_batch(
         &self,
         id_or_code: &str,
         params: FetchChargesInABatchParams,
-    ) -> Result<Response, String> {
-        let url = format!("{}/{}", BULK_CHARGES_URL, id_or_code);
-        let res = make_get_request(&self.bearer_auth, &url, Some(params));
-        return res;
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", BULK_CHARGES_PATH, id_or_code);
+        make_get_request(&self.http, &path, Some(params))
     }
 
     /// Use this endpoint to pause processing a batch
     /// - batch_code:
-    /// The batch code for the bulk charge you want to pause
-    pub fn pause_bulk_charge_batch(&self, batch_code: &str) -> Result<Response, String> {
-        let url = format!("{}/pause/{}", BULK_CHARGES_URL, batch_code);
-        let res = make_get_request(&self.bearer_auth, &url, None::<String>);
-        return res;
+    ///   The batch code for the bulk charge you want to pause
+    pub fn pause_bulk_charge_batch(
+        &self,
+        batch_code: &str,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/pause/{}", BULK_CHARGES_PATH, batch_code);
+        make_get_request(&self.http, &path, None::<String>)
     }
 
     /// Use this endpoint to pause processing a batch
     /// - batch_code:
-    /// The batch code for the bulk charge you want to pause
-    pub fn resume_bulk_charge_batch(&self, batch_code: &str) -> Result<Response, String> {
-        let url = format!("{}/resume/{}", BULK_CHARGES_URL, batch_code);
-        let res = make_get_request(&self.bearer_auth, &url, None::<String>);
-        return res;
+    ///   The batch code for the bulk charge you want to pause
+    pub fn resume_bulk_charge_batch(
+        &self,
+        batch_code: &str,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/resume/{}", BULK_CHARGES_PATH, batch_code);
+        make_get_request(&self.http, &path, None::<String>)
     }
 }
@@ -1,21 +1,38 @@
+use crate::builder::{Missing, Set};
+use crate::error::PaystackError;
+use crate::money::{amount_with_currency, Money};
+use crate::utils::REDACTED;
+use crate::validation;
+#[cfg(feature = "blocking")]
+use crate::{
+    prelude::TransactionData,
+    response::PaystackResponse,
+    utils::{make_get_request, make_idempotent_request, make_request, HttpClient},
+};
 use chrono::{DateTime, Local};
-use reqwest::blocking::Response;
+#[cfg(feature = "blocking")]
+use reqwest::Method;
 use serde::Serialize;
 use serde_json::Value as JSON;
+use std::fmt;
+use std::marker::PhantomData;
+#[cfg(feature = "blocking")]
+use std::sync::Arc;
 
-use crate::utils::{make_get_request, make_request, REQUEST};
-
-#[derive(Debug, Default)]
+#[cfg(feature = "blocking")]
+#[derive(Debug, Clone)]
 pub struct Charge {
-    pub(crate) bearer_auth: String,
+    pub(crate) http: Arc<HttpClient>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Serialize)]
 pub struct CreateChargeBody<'a> {
     /// Customer's email address
     pub email: &'a str,
-    /// Amount should be in kobo if currency is `NGN`, pesewas, if currency is `GHS`, and cents, if currency is `ZAR`
-    pub amount: &'a str,
+    /// Amount and currency to charge, sent as `amount` in minor units and `currency`, which
+    /// mobile money charges need
+    #[serde(flatten, serialize_with = "amount_with_currency")]
+    pub amount: Money,
     /// Bank account to charge (don't send if charging an authorization code)
     pub bank: Option<JSON>,
     /// An authorization code to charge (don't send if charging a bank account)
@@ -25,6 +42,7 @@ pub struct CreateChargeBody<'a> {
     /// A JSON object
     pub metadata: Option<JSON>,
     /// Unique transaction reference. Only -, .`, = and alphanumeric characters allowed.
+    /// Setting it lets the client safely retry the request if Paystack fails to respond.
     pub reference: Option<&'a str>,
     /// USSD type to charge (don't send if charging an authorization code, bank or card)
     pub ussd: Option<JSON>,
@@ -35,7 +53,189 @@ pub struct CreateChargeBody<'a> {
     pub device_id: Option<&'a str>,
 }
 
-#[derive(Debug, Serialize)]
+impl fmt::Debug for CreateChargeBody<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CreateChargeBody")
+            .field("email", &self.email)
+            .field("amount", &self.amount)
+            .field("bank", &self.bank)
+            .field("authorization_code", &self.authorization_code)
+            .field("pin", &self.pin.map(|_| REDACTED))
+            .field("metadata", &self.metadata)
+            .field("reference", &self.reference)
+            .field("ussd", &self.ussd)
+            .field("mobile_money", &self.mobile_money)
+            .field("device_id", &self.device_id)
+            .finish()
+    }
+}
+
+impl<'a> CreateChargeBody<'a> {
+    /// Start building a charge of `amount` to the customer at `email`
+    pub fn builder(email: &'a str, amount: Money) -> CreateChargeBuilder<'a, Missing> {
+        CreateChargeBuilder {
+            body: CreateChargeBody {
+                email,
+                amount,
+                bank: None,
+                authorization_code: None,
+                pin: None,
+                metadata: None,
+                reference: None,
+                ussd: None,
+                mobile_money: None,
+                device_id: None,
+            },
+            method: PhantomData,
+        }
+    }
+
+    /// Check the email, amount, reference and metadata before they are sent. Also run by the
+    /// builder.
+    pub fn validate(&self) -> Result<(), PaystackError> {
+        validation::email("email", self.email)?;
+        validation::amount("amount", &self.amount)?;
+        if let Some(reference) = self.reference {
+            validation::reference("reference", reference)?;
+        }
+        if let Some(device_id) = self.device_id {
+            validation::reference("device_id", device_id)?;
+        }
+        if let Some(metadata) = &self.metadata {
+            validation::metadata("metadata", &metadata.to_string())?;
+        }
+        Ok(())
+    }
+}
+
+/// Builds a [`CreateChargeBody`]. Exactly one way of paying, a bank account, an authorization
+/// code, USSD or mobile money, has to be chosen before the charge can be built.
+/// ```rust
+/// use paystack_rs::prelude::{CreateChargeBody, Currency, Money};
+/// use serde_json::json;
+///
+/// let body = CreateChargeBody::builder("customer@email.com", Money::from_minor(10_000, Currency::NGN))
+///     .bank(json!({"code": "057", "account_number": "0000000000"}))
+///     .reference("order-1042")
+///     .build()
+///     .unwrap();
+/// assert!(body.authorization_code.is_none());
+///
+/// // Mobile money is charged in the currency of the wallet
+/// let body = CreateChargeBody::builder("customer@email.com", Money::from_minor(10_000, Currency::GHS))
+///     .mobile_money(json!({"phone": "0551234987", "provider": "mtn"}))
+///     .build()
+///     .unwrap();
+/// let json = serde_json::to_value(&body).unwrap();
+/// assert_eq!((json["amount"].as_i64(), json["currency"].as_str()), (Some(10_000), Some("GHS")));
+///
+/// // A PIN unlocks an authorization code, not a bank account
+/// let body = CreateChargeBody::builder("customer@email.com", Money::from_minor(10_000, Currency::NGN))
+///     .bank(json!({"code": "057", "account_number": "0000000000"}))
+///     .pin("1234")
+///     .build();
+/// assert!(body.is_err());
+/// ```
+///
+/// Choosing a second way of paying does not compile:
+/// ```compile_fail
+/// use paystack_rs::prelude::{CreateChargeBody, Currency, Money};
+/// use serde_json::json;
+///
+/// CreateChargeBody::builder("customer@email.com", Money::from_minor(10_000, Currency::NGN))
+///     .authorization_code("AUTH_72btv547")
+///     .bank(json!({"code": "057", "account_number": "0000000000"}));
+/// ```
+#[derive(Debug)]
+pub struct CreateChargeBuilder<'a, M> {
+    body: CreateChargeBody<'a>,
+    method: PhantomData<M>,
+}
+
+impl<'a> CreateChargeBuilder<'a, Missing> {
+    fn method(self) -> CreateChargeBuilder<'a, Set> {
+        CreateChargeBuilder {
+            body: self.body,
+            method: PhantomData,
+        }
+    }
+
+    /// Charge a bank account, given as `{"code": ..., "account_number": ...}`
+    pub fn bank(mut self, bank: JSON) -> CreateChargeBuilder<'a, Set> {
+        self.body.bank = Some(bank);
+        self.method()
+    }
+
+    /// Charge a card the customer paid with before
+    pub fn authorization_code(mut self, code: &'a str) -> CreateChargeBuilder<'a, Set> {
+        self.body.authorization_code = Some(code);
+        self.method()
+    }
+
+    /// Charge through USSD, given as `{"type": ...}`
+    pub fn ussd(mut self, ussd: JSON) -> CreateChargeBuilder<'a, Set> {
+        self.body.ussd = Some(ussd);
+        self.method()
+    }
+
+    /// Charge a mobile money wallet, given as `{"phone": ..., "provider": ...}`
+    pub fn mobile_money(mut self, mobile_money: JSON) -> CreateChargeBuilder<'a, Set> {
+        self.body.mobile_money = Some(mobile_money);
+        self.method()
+    }
+}
+
+impl<'a, M> CreateChargeBuilder<'a, M> {
+    /// PIN of a non-reusable authorization code
+    pub fn pin(mut self, pin: &'a str) -> CreateChargeBuilder<'a, M> {
+        self.body.pin = Some(pin);
+        self
+    }
+
+    /// Custom data to attach to the charge
+    pub fn metadata(mut self, metadata: JSON) -> CreateChargeBuilder<'a, M> {
+        self.body.metadata = Some(metadata);
+        self
+    }
+
+    /// Unique reference of the charge, which also lets the client retry the request safely
+    pub fn reference(mut self, reference: &'a str) -> CreateChargeBuilder<'a, M> {
+        self.body.reference = Some(reference);
+        self
+    }
+
+    /// Identifier of the device the customer pays from
+    pub fn device_id(mut self, device_id: &'a str) -> CreateChargeBuilder<'a, M> {
+        self.body.device_id = Some(device_id);
+        self
+    }
+}
+
+impl<'a> CreateChargeBuilder<'a, Set> {
+    /// Check the fields that depend on the way of paying and return the body
+    pub fn build(self) -> Result<CreateChargeBody<'a>, PaystackError> {
+        let body = self.body;
+        if body.pin.is_some() && body.authorization_code.is_none() {
+            return Err(PaystackError::validation(
+                "pin is only sent with an authorization code",
+            ));
+        }
+        body.validate()?;
+        Ok(body)
+    }
+}
+
+/// The PIN is left out of `Debug` output, so bodies can be logged safely.
+/// ```rust
+/// use paystack_rs::prelude::SubmitPinBody;
+///
+/// let body = SubmitPinBody {
+///     pin: "1234",
+///     reference: "5bwib5v6anhe9xa",
+/// };
+/// assert!(!format!("{:?}", body).contains("1234"));
+/// ```
+#[derive(Serialize)]
 pub struct SubmitPinBody<'a> {
     /// PIN submitted by user
     pub pin: &'a str,
@@ -43,7 +243,16 @@ pub struct SubmitPinBody<'a> {
     pub reference: &'a str,
 }
 
-#[derive(Debug, Serialize)]
+impl fmt::Debug for SubmitPinBody<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SubmitPinBody")
+            .field("pin", &REDACTED)
+            .field("reference", &self.reference)
+            .finish()
+    }
+}
+
+#[derive(Serialize)]
 pub struct SubmitOTPBody<'a> {
     /// OTP submitted by user
     pub otp: &'a str,
@@ -51,6 +260,15 @@ pub struct SubmitOTPBody<'a> {
     pub reference: &'a str,
 }
 
+impl fmt::Debug for SubmitOTPBody<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SubmitOTPBody")
+            .field("otp", &REDACTED)
+            .field("reference", &self.reference)
+            .finish()
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct SubmitPhoneBody<'a> {
     /// Phone submitted by user
@@ -81,56 +299,80 @@ pub struct SubmitAddressBody<'a> {
     pub zipcode: &'a str,
 }
 
-const CHARGE_URL: &str = "https://api.paystack.co/charge";
+pub(crate) const CHARGE_PATH: &str = "/charge";
+#[cfg(feature = "blocking")]
 impl Charge {
     // TODO: link payment channel here
     /// Initiate a payment by integrating the [][payment channel] of your choice.
-    pub fn create_charge(&self, body: CreateChargeBody) -> Result<Response, String> {
-        let res = make_request(&self.bearer_auth, CHARGE_URL, Some(body), REQUEST::POST);
-        return res;
+    pub fn create_charge(
+        &self,
+        body: CreateChargeBody,
+    ) -> Result<PaystackResponse<TransactionData>, PaystackError> {
+        body.validate()?;
+        let idempotent = body.reference.is_some();
+        make_idempotent_request(
+            &self.http,
+            CHARGE_PATH,
+            Some(body),
+            Method::POST,
+            idempotent,
+        )
     }
 
     /// Submit PIN to continue a charge
-    pub fn submit_pin(&self, body: SubmitPinBody) -> Result<Response, String> {
-        let url = format!("{}/submit_pin", CHARGE_URL);
-        let res = make_request(&self.bearer_auth, &url, Some(body), REQUEST::POST);
-        return res;
+    pub fn submit_pin(
+        &self,
+        body: SubmitPinBody,
+    ) -> Result<PaystackResponse<TransactionData>, PaystackError> {
+        let path = format!("{}/submit_pin", CHARGE_PATH);
+        make_request(&self.http, &path, Some(body), Method::POST)
     }
 
     /// Submit OTP to complete a charge
-    pub fn submit_otp(&self, body: SubmitOTPBody) -> Result<Response, String> {
-        let url = format!("{}/submit_otp", CHARGE_URL);
-        let res = make_request(&self.bearer_auth, &url, Some(body), REQUEST::POST);
-        return res;
+    pub fn submit_otp(
+        &self,
+        body: SubmitOTPBody,
+    ) -> Result<PaystackResponse<TransactionData>, PaystackError> {
+        let path = format!("{}/submit_otp", CHARGE_PATH);
+        make_request(&self.http, &path, Some(body), Method::POST)
     }
 
     /// Submit phone when requested
-    pub fn submit_phone(&self, body: SubmitPhoneBody) -> Result<Response, String> {
-        let url = format!("{}/submit_phone", CHARGE_URL);
-        let res = make_request(&self.bearer_auth, &url, Some(body), REQUEST::POST);
-        return res;
+    pub fn submit_phone(
+        &self,
+        body: SubmitPhoneBody,
+    ) -> Result<PaystackResponse<TransactionData>, PaystackError> {
+        let path = format!("{}/submit_phone", CHARGE_PATH);
+        make_request(&self.http, &path, Some(body), Method::POST)
     }
 
     /// Submit birthday when requested
-    pub fn submit_birthday(&self, body: SubmitBirthdayBody) -> Result<Response, String> {
-        let url = format!("{}/submit_birthday", CHARGE_URL);
-        let res = make_request(&self.bearer_auth, &url, Some(body), REQUEST::POST);
-        return res;
+    pub fn submit_birthday(
+        &self,
+        body: SubmitBirthdayBody,
+    ) -> Result<PaystackResponse<TransactionData>, PaystackError> {
+        let path = format!("{}/submit_birthday", CHARGE_PATH);
+        make_request(&self.http, &path, Some(body), Method::POST)
     }
 
     /// Submit address to continue a charge
-    pub fn submit_address(&self, body: SubmitAddressBody) -> Result<Response, String> {
-        let url = format!("{}/submit_address", CHARGE_URL);
-        let res = make_request(&self.bearer_auth, &url, Some(body), REQUEST::POST);
-        return res;
+    pub fn submit_address(
+        &self,
+        body: SubmitAddressBody,
+    ) -> Result<PaystackResponse<TransactionData>, PaystackError> {
+        let path = format!("{}/submit_address", CHARGE_PATH);
+        make_request(&self.http, &path, Some(body), Method::POST)
     }
 
     /// When you get "pending" as a charge status or if there was an exception when calling any of the /charge endpoints,
     /// wait 10 seconds or more, then make a check to see if its status has changed.
     /// Don't call too early as you may get a lot more pending than you should.
-    pub fn check_pending_charge(&self, reference: &str) -> Result<Response, String> {
-        let url = format!("{}/{}", CHARGE_URL, reference);
-        let res = make_get_request(&self.bearer_auth, &url, None::<String>);
-        return res;
+    pub fn check_pending_charge(
+        &self,
+        reference: &str,
+    ) -> Result<PaystackResponse<TransactionData>, PaystackError> {
+        validation::lookup("reference", reference)?;
+        let path = format!("{}/{}", CHARGE_PATH, reference);
+        make_get_request(&self.http, &path, None::<String>)
     }
 }
@@ -1,12 +1,22 @@
-use reqwest::blocking::Response;
+#[cfg(feature = "blocking")]
+use crate::{
+    error::PaystackError,
+    response::PaystackResponse,
+    utils::{make_get_request, make_request, HttpClient},
+};
+#[cfg(feature = "blocking")]
+use reqwest::Method;
 use serde::Serialize;
+#[cfg(feature = "blocking")]
+use serde_json::Value as JSON;
+#[cfg(feature = "blocking")]
+use std::sync::Arc;
 
-use crate::utils::{make_get_request, make_request, REQUEST};
-
+#[cfg(feature = "blocking")]
 /// The Control Panel API allows you manage some settings on your integration
-#[derive(Debug, Default)]
+#[derive(Debug, Clone)]
 pub struct ControlPanel {
-    pub(crate) bearer_auth: String,
+    pub(crate) http: Arc<HttpClient>,
 }
 
 #[derive(Debug, Serialize)]
@@ -15,22 +25,21 @@ pub struct UpdatePaymentSessionTimeoutBody {
     pub timeout: i64,
 }
 
-const CONTROL_PANEL_URL: &str = "https://api.paystack.co/integration";
+pub(crate) const CONTROL_PANEL_PATH: &str = "/integration";
+#[cfg(feature = "blocking")]
 impl ControlPanel {
     /// Fetch the payment session timeout on your integration
-    pub fn fetch_payment_session_timeout(&self) -> Result<Response, String> {
-        let url = format!("{}/payment_session_timeout", CONTROL_PANEL_URL);
-        let res = make_get_request(&self.bearer_auth, &url, None::<String>);
-        return res;
+    pub fn fetch_payment_session_timeout(&self) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/payment_session_timeout", CONTROL_PANEL_PATH);
+        make_get_request(&self.http, &path, None::<String>)
     }
 
     /// Update the payment session timeout on your integration
     pub fn update_payment_session_timeout(
         &self,
         body: UpdatePaymentSessionTimeoutBody,
-    ) -> Result<Response, String> {
-        let url = format!("{}/payment_session_timeout", CONTROL_PANEL_URL);
-        let res = make_request(&self.bearer_auth, &url, Some(body), REQUEST::PUT);
-        return res;
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/payment_session_timeout", CONTROL_PANEL_PATH);
+        make_request(&self.http, &path, Some(body), Method::PUT)
     }
 }
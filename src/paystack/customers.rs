@@ -1,12 +1,22 @@
-use crate::utils::*;
+#[cfg(feature = "blocking")]
+use crate::{
+    error::PaystackError,
+    pagination::Paginator,
+    response::PaystackResponse,
+    utils::{make_get_request, make_request, HttpClient},
+};
 use chrono::{prelude::DateTime, Utc};
-use reqwest::blocking::Response;
+#[cfg(feature = "blocking")]
+use reqwest::Method;
 use serde::Serialize;
 use serde_json::Value;
-use std::fmt::Debug;
-#[derive(Default, Debug)]
+#[cfg(feature = "blocking")]
+use std::sync::Arc;
+
+#[cfg(feature = "blocking")]
+#[derive(Clone, Debug)]
 pub struct Customer {
-    pub(crate) bearer_auth: String,
+    pub(crate) http: Arc<HttpClient>,
 }
 
 #[derive(Debug, Serialize)]
@@ -23,9 +33,10 @@ pub struct CreateCustomerBody<'a> {
     pub metadata: Option<Value>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct ListCustomersParams {
     /// Specify how many records you want to retrieve per page. If not specify we use a default value of 50.
+    #[serde(rename = "perPage")]
     pub per_page: Option<i64>,
     /// Specify exactly what page you want to retrieve. If not specify we use a default value of 1.
     pub page: Option<i64>,
@@ -82,64 +93,78 @@ pub struct DeactivateAuthorizationBody<'a> {
     pub authorization_code: &'a str,
 }
 
-const CUSTOMER_URL: &str = "https://api.paystack.co/customer";
+pub(crate) const CUSTOMER_PATH: &str = "/customer";
+#[cfg(feature = "blocking")]
 impl Customer {
     /// Create a customer on your integration
-    pub fn create_customer(&self, body: CreateCustomerBody) -> Result<Response, String> {
-        let res = make_request(&self.bearer_auth, CUSTOMER_URL, Some(body), REQUEST::POST);
-        return res;
+    pub fn create_customer(
+        &self,
+        body: CreateCustomerBody,
+    ) -> Result<PaystackResponse<Value>, PaystackError> {
+        make_request(&self.http, CUSTOMER_PATH, Some(body), Method::POST)
     }
 
     /// List customers available on your integration.
-    pub fn list_customers(&self, queries: Option<ListCustomersParams>) -> Result<Response, String> {
-        let res = make_get_request(&self.bearer_auth, CUSTOMER_URL, queries);
-        return res;
+    pub fn list_customers(
+        &self,
+        queries: Option<ListCustomersParams>,
+    ) -> Result<PaystackResponse<Value>, PaystackError> {
+        make_get_request(&self.http, CUSTOMER_PATH, queries)
+    }
+
+    /// Iterate over every customer, fetching further pages as they are needed
+    pub fn list_customers_iter(
+        &self,
+        params: ListCustomersParams,
+    ) -> Paginator<'static, Value, ListCustomersParams> {
+        let http = self.http.clone();
+        Paginator::new(params, move |params| {
+            make_get_request(&http, CUSTOMER_PATH, Some(params))
+        })
     }
     /// Get details of a customer on your integration.
     /// takes a parameter email_or_code. An email or customer code for the customer you want to fetch
-    pub fn fetch_customer(&self, email_or_code: &str) -> Result<Response, String> {
-        let url = format!("{}/{}", CUSTOMER_URL.to_owned(), email_or_code);
-        let res = make_get_request(&self.bearer_auth, &url, None::<String>);
-        return res;
+    pub fn fetch_customer(
+        &self,
+        email_or_code: &str,
+    ) -> Result<PaystackResponse<Value>, PaystackError> {
+        let path = format!("{}/{}", CUSTOMER_PATH, email_or_code);
+        make_get_request(&self.http, &path, None::<String>)
     }
 
     pub fn update_customer(
         &self,
         code: &str,
         body: UpdateCustomerBody,
-    ) -> Result<Response, String> {
-        let url = format!("{}/{}", CUSTOMER_URL.to_owned(), code);
-        let res = make_request(&self.bearer_auth, &url, Some(body), REQUEST::PUT);
-        return res;
+    ) -> Result<PaystackResponse<Value>, PaystackError> {
+        let path = format!("{}/{}", CUSTOMER_PATH, code);
+        make_request(&self.http, &path, Some(body), Method::PUT)
     }
 
     pub fn validate_customer(
         &self,
         code: &str,
         body: ValidateCustomerBody,
-    ) -> Result<Response, String> {
-        let url = format!("{}/{}/identification", CUSTOMER_URL.to_owned(), code);
-        let res = make_request(&self.bearer_auth, &url, Some(body), REQUEST::POST);
-        return res;
+    ) -> Result<PaystackResponse<Value>, PaystackError> {
+        let path = format!("{}/{}/identification", CUSTOMER_PATH, code);
+        make_request(&self.http, &path, Some(body), Method::POST)
     }
 
     /// Whitelist or blacklist a customer on your integration
     pub fn whitelist_or_blacklist_customer(
         &self,
         body: WhitelistOrBlacklistCustomerBody,
-    ) -> Result<Response, String> {
-        let url = format!("{}/set_risk_action", CUSTOMER_URL.to_owned());
-        let res = make_request(&self.bearer_auth, &url, Some(body), REQUEST::POST);
-        return res;
+    ) -> Result<PaystackResponse<Value>, PaystackError> {
+        let path = format!("{}/set_risk_action", CUSTOMER_PATH);
+        make_request(&self.http, &path, Some(body), Method::POST)
     }
 
     /// Deactivate an authorization when the card needs to be forgotten
     pub fn deactivate_authorization(
         &self,
         body: DeactivateAuthorizationBody,
-    ) -> Result<Response, String> {
-        let url = format!("{}/deactivate_authorization", CUSTOMER_URL.to_owned());
-        let res = make_request(&self.bearer_auth, &url, Some(body), REQUEST::POST);
-        return res;
+    ) -> Result<PaystackResponse<Value>, PaystackError> {
+        let path = format!("{}/deactivate_authorization", CUSTOMER_PATH);
+        make_request(&self.http, &path, Some(body), Method::POST)
     }
 }
@@ -1,12 +1,23 @@
-use crate::{prelude::Currency, utils::*};
-use reqwest::blocking::Response;
+use crate::prelude::Currency;
+#[cfg(feature = "blocking")]
+use crate::{
+    error::PaystackError,
+    response::PaystackResponse,
+    utils::{make_get_request, make_request, HttpClient},
+};
+#[cfg(feature = "blocking")]
+use reqwest::Method;
 use serde::Serialize;
-use std::fmt::Debug;
+#[cfg(feature = "blocking")]
+use serde_json::Value as JSON;
+#[cfg(feature = "blocking")]
+use std::sync::Arc;
 
-const DEDICATED_NUBAN_URL: &str = "https://api.paystack.co/dedicated_account";
-#[derive(Debug, Serialize, Default)]
+pub(crate) const DEDICATED_NUBAN_PATH: &str = "/dedicated_account";
+#[cfg(feature = "blocking")]
+#[derive(Debug, Clone)]
 pub struct DedicatedNuban {
-    pub(crate) bearer_auth: String,
+    pub(crate) http: Arc<HttpClient>,
 }
 
 #[derive(Debug, Serialize)]
@@ -52,76 +63,61 @@ pub struct RemoveSplitFromDedicatedAcctBody<'a> {
     pub account_number: &'a str,
 }
 /// The Dedicated NUBAN API enables Nigerian merchants manage unique payment accounts of their cuctomers.
+#[cfg(feature = "blocking")]
 impl DedicatedNuban {
     /// Create a Dedicated NUBAN and assign to a customer
     /// 💡 Bank Availability: Wema Bank is the only bank currently available
     pub fn create_dedicated_account(
         &self,
         body: CreateDedicatedAccountBody,
-    ) -> Result<Response, String> {
-        let res = make_request(
-            &self.bearer_auth,
-            DEDICATED_NUBAN_URL,
-            Some(body),
-            REQUEST::POST,
-        );
-        return res;
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_request(&self.http, DEDICATED_NUBAN_PATH, Some(body), Method::POST)
     }
 
     pub fn list_dedicated_accounts(
         &self,
         params: Option<ListDedicatedAccountsParams>,
-    ) -> Result<Response, String> {
-        let res = make_get_request(&self.bearer_auth, DEDICATED_NUBAN_URL, Some(params));
-        return res;
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_get_request(&self.http, DEDICATED_NUBAN_PATH, Some(params))
     }
 
     /// Get details of a dedicated account on your integration.
-    pub fn fetch_dedicated_account(&self, id: &str) -> Result<Response, String> {
-        let url = format!("{}/{}", DEDICATED_NUBAN_URL, id);
-        let res = make_get_request(&self.bearer_auth, &url, None::<String>);
-        return res;
+    pub fn fetch_dedicated_account(
+        &self,
+        id: &str,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", DEDICATED_NUBAN_PATH, id);
+        make_get_request(&self.http, &path, None::<String>)
     }
 
     /// Deactivate a dedicated account on your integration.
-    pub fn deactivate_dedicated_account(&self, id: &str) -> Result<Response, String> {
-        let url = format!("{}/{}", DEDICATED_NUBAN_URL, id);
-        let res = make_request(&self.bearer_auth, &url, None::<String>, REQUEST::DELETE);
-        return res;
+    pub fn deactivate_dedicated_account(
+        &self,
+        id: &str,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", DEDICATED_NUBAN_PATH, id);
+        make_request(&self.http, &path, None::<String>, Method::DELETE)
     }
 
     /// Split a dedicated account transaction with one or more accounts
     pub fn split_dedicated_account_transaction(
         &self,
         body: SplitDedicatedAccountTxBody,
-    ) -> Result<Response, String> {
-        let res = make_request(
-            &self.bearer_auth,
-            &DEDICATED_NUBAN_URL,
-            Some(body),
-            REQUEST::POST,
-        );
-        return res;
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_request(&self.http, DEDICATED_NUBAN_PATH, Some(body), Method::POST)
     }
 
     /// If you've previously set up split payment for transactions on a dedicated account, you can remove it with this endpoint
     pub fn remove_split_from_dedicated_acct(
         &self,
         body: RemoveSplitFromDedicatedAcctBody,
-    ) -> Result<Response, String> {
-        let res = make_request(
-            &self.bearer_auth,
-            DEDICATED_NUBAN_URL,
-            Some(body),
-            REQUEST::DELETE,
-        );
-        return res;
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_request(&self.http, DEDICATED_NUBAN_PATH, Some(body), Method::DELETE)
     }
 
     /// Get available bank providers for Dedicated NUBAN
-    pub fn fetch_bank_providers(&self) -> Result<Response, String> {
-        let url = format!("{}/available_providers", DEDICATED_NUBAN_URL);
-        let res = make_get_request(&self.bearer_auth, &url, None::<String>);
-        return res;
+    pub fn fetch_bank_providers(&self) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/available_providers", DEDICATED_NUBAN_PATH);
+        make_get_request(&self.http, &path, None::<String>)
     }
 }
@@ -1,16 +1,30 @@
-use chrono::{DateTime, Local};
-use reqwest::blocking::Response;
-use serde::Serialize;
-
-use crate::utils::{make_get_request, make_request, REQUEST};
+use crate::currency::Currency;
+use crate::money::Money;
+use crate::paystack::transactions::{TransactionCustomer, TransactionData};
+use crate::response::timestamp;
+#[cfg(feature = "blocking")]
+use crate::{
+    error::PaystackError,
+    pagination::Paginator,
+    response::PaystackResponse,
+    utils::{make_get_request, make_request, HttpClient},
+};
+use chrono::{DateTime, Local, Utc};
+#[cfg(feature = "blocking")]
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JSON;
+#[cfg(feature = "blocking")]
+use std::sync::Arc;
 
+#[cfg(feature = "blocking")]
 /// The Disputes API allows you manage transaction disputes on your integration
-#[derive(Debug, Default)]
+#[derive(Debug, Clone)]
 pub struct Disputes {
-    pub(crate) bearer_auth: String,
+    pub(crate) http: Arc<HttpClient>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum DisputeStatus {
     AwaitingMerchantFeedback,
@@ -20,7 +34,7 @@ pub enum DisputeStatus {
     Declined,
     MerchantAccepted,
 }
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ListDisputesParams<'a> {
     /// A timestamp from which to start listing product e.g. 2016-09-24T00:00:05.000Z, 2016-09-21
     pub from: DateTime<Local>,
@@ -38,8 +52,9 @@ pub struct ListDisputesParams<'a> {
 
 #[derive(Debug, Serialize)]
 pub struct UpdateDisputeBody<'a> {
-    /// the amount to refund, in **kobo** if currency is `NGN`, **pesewas**, if currency is `GHS`, and **cents**, if currency is `ZAR`
-    pub refund_amount: i64,
+    /// the amount to refund, sent in minor units without its currency. Paystack refunds in the
+    /// currency of the disputed transaction, so the amount must be in that currency.
+    pub refund_amount: Money,
     // TODO: link to upload dispute url
     /// filename of attachment returned via response from upload url(GET /dispute/:id/upload_url)
     pub uploaded_filename: Option<&'a str>,
@@ -73,8 +88,9 @@ pub struct ResolveDisputeBody<'a> {
     pub resolution: &'a str,
     /// Reason for resolving
     pub message: &'a str,
-    /// the amount to refund, in **kobo** if currency is `NGN`, **pesewas**, if currency is `GHS`, and **cents**, if currency is `ZAR`
-    pub refund_amount: i64,
+    /// the amount to refund, sent in minor units without its currency. Paystack refunds in the
+    /// currency of the disputed transaction, so the amount must be in that currency.
+    pub refund_amount: Money,
     /// filename of attachment returned via response from upload url(GET /dispute/:id/upload_url)
     pub uploaded_filename: &'a str,
     /// Evidence Id for fraud claims
@@ -97,62 +113,147 @@ pub struct ExportDisputesBody<'a> {
     pub status: Option<DisputeStatus>,
 }
 
-const DISPUTE_URL: &str = "https://api.paystack.co/dispute";
+/// A dispute, as sent in the `charge.dispute.*` webhooks and returned by the Disputes API
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DisputeData {
+    /// Paystack's ID for the dispute
+    pub id: i64,
+    /// `test` or `live`
+    pub domain: Option<String>,
+    /// e.g. `awaiting-merchant-feedback`, `awaiting-bank-feedback`, `pending` or `resolved`
+    pub status: String,
+    /// `merchant-accepted` or `declined` once the dispute is resolved
+    pub resolution: Option<String>,
+    /// e.g. `chargeback` or `fraud`
+    pub category: Option<String>,
+    /// Amount the customer is asking back, in the minor unit of `currency`
+    pub refund_amount: Option<i64>,
+    /// Currency of the disputed transaction
+    pub currency: Option<Currency>,
+    /// The disputed transaction
+    pub transaction: Option<TransactionData>,
+    /// Reference of the disputed transaction, when the transaction itself is left out
+    pub transaction_reference: Option<String>,
+    /// The customer who raised the dispute
+    pub customer: Option<TransactionCustomer>,
+    /// First six digits of the card
+    pub bin: Option<String>,
+    /// Last four digits of the card
+    pub last4: Option<String>,
+    /// Evidence added so far, if any
+    pub evidence: Option<JSON>,
+    /// Files uploaded for the dispute, if any
+    pub attachments: Option<JSON>,
+    /// Note left by whoever resolved the dispute
+    pub note: Option<String>,
+    /// Every status the dispute went through
+    pub history: Vec<JSON>,
+    /// Messages exchanged about the dispute
+    pub messages: Vec<JSON>,
+    /// When the integration has to respond by
+    #[serde(alias = "dueAt")]
+    #[serde(serialize_with = "timestamp")]
+    pub due_at: Option<DateTime<Utc>>,
+    /// When the dispute was resolved
+    #[serde(alias = "resolvedAt")]
+    #[serde(serialize_with = "timestamp")]
+    pub resolved_at: Option<DateTime<Utc>>,
+    /// When the dispute was raised
+    #[serde(alias = "createdAt")]
+    #[serde(serialize_with = "timestamp")]
+    pub created_at: Option<DateTime<Utc>>,
+    /// When the dispute last changed
+    #[serde(alias = "updatedAt")]
+    #[serde(serialize_with = "timestamp")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+pub(crate) const DISPUTE_PATH: &str = "/dispute";
+#[cfg(feature = "blocking")]
 impl Disputes {
     /// List disputes filed against you
-    pub fn list_disputes(&self, params: ListDisputesParams) -> Result<Response, String> {
-        let res = make_get_request(&self.bearer_auth, DISPUTE_URL, Some(params));
-        return res;
+    pub fn list_disputes(
+        &self,
+        params: ListDisputesParams,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_get_request(&self.http, DISPUTE_PATH, Some(params))
+    }
+
+    /// Iterate over every dispute, fetching further pages as they are needed
+    pub fn list_disputes_iter<'a>(
+        &self,
+        params: ListDisputesParams<'a>,
+    ) -> Paginator<'a, JSON, ListDisputesParams<'a>> {
+        let http = self.http.clone();
+        Paginator::new(params, move |params| {
+            make_get_request(&http, DISPUTE_PATH, Some(params))
+        })
     }
 
     /// Get more details about a dispute.
     /// - id: The dispute `ID` you want to fetch
-    pub fn fetch_dispute(&self, id: &str) -> Result<Response, String> {
-        let url = format!("{}/{}", DISPUTE_URL, id);
-        let res = make_get_request(&self.bearer_auth, &url, None::<String>);
-        return res;
+    pub fn fetch_dispute(&self, id: &str) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", DISPUTE_PATH, id);
+        make_get_request(&self.http, &path, None::<String>)
     }
 
     /// Get more details about a dispute.
     ///  - id: The transaction `ID` you want to fetch
-    pub fn list_transaction_disputes(&self, id: &str) -> Result<Response, String> {
-        let url = format!("{}/transaction/{}", DISPUTE_URL, id);
-        let res = make_get_request(&self.bearer_auth, &url, None::<String>);
-        return res;
+    pub fn list_transaction_disputes(
+        &self,
+        id: &str,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/transaction/{}", DISPUTE_PATH, id);
+        make_get_request(&self.http, &path, None::<String>)
     }
 
     /// Update details of a dispute on your integration
-    pub fn update_dispute(&self, id: &str, body: UpdateDisputeBody) -> Result<Response, String> {
-        let url = format!("{}/{}", DISPUTE_URL, id);
-        let res = make_request(&self.bearer_auth, &url, Some(body), REQUEST::PUT);
-        return res;
+    pub fn update_dispute(
+        &self,
+        id: &str,
+        body: UpdateDisputeBody,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", DISPUTE_PATH, id);
+        make_request(&self.http, &path, Some(body), Method::PUT)
     }
 
     /// Provide evidence for a dispute
-    pub fn add_evidence(&self, id: &str, body: AddEvidenceBody) -> Result<Response, String> {
-        let url = format!("{}/{}/dispute", DISPUTE_URL, id);
-        let res = make_request(&self.bearer_auth, &url, Some(body), REQUEST::POST);
-        return res;
+    pub fn add_evidence(
+        &self,
+        id: &str,
+        body: AddEvidenceBody,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}/dispute", DISPUTE_PATH, id);
+        make_request(&self.http, &path, Some(body), Method::POST)
     }
 
     /// Resolve a dispute on your integration
-    pub fn get_upload_url(&self, id: &str, params: GetUploadURLParams) -> Result<Response, String> {
-        let url = format!("{}/{}/upload_url", DISPUTE_URL, id);
-        let res = make_get_request(&self.bearer_auth, &url, Some(params));
-        return res;
+    pub fn get_upload_url(
+        &self,
+        id: &str,
+        params: GetUploadURLParams,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}/upload_url", DISPUTE_PATH, id);
+        make_get_request(&self.http, &path, Some(params))
     }
 
     /// Resolve a dispute on your integration
-    pub fn resolve_dispute(&self, id: &str, body: ResolveDisputeBody) -> Result<Response, String> {
-        let url = format!("{}/{}/resolve", DISPUTE_URL, id);
-        let res = make_request(&self.bearer_auth, &url, Some(body), REQUEST::PUT);
-        return res;
+    pub fn resolve_dispute(
+        &self,
+        id: &str,
+        body: ResolveDisputeBody,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}/resolve", DISPUTE_PATH, id);
+        make_request(&self.http, &path, Some(body), Method::PUT)
     }
 
     /// Export disputes available on your integration
-    pub fn export_disputes(&self, params: ExportDisputesBody) -> Result<Response, String> {
-        let url = format!("{}/export", DISPUTE_URL);
-        let res = make_get_request(&self.bearer_auth, &url, Some(params));
-        return res;
+    pub fn export_disputes(
+        &self,
+        params: ExportDisputesBody,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/export", DISPUTE_PATH);
+        make_get_request(&self.http, &path, Some(params))
     }
 }
@@ -1,16 +1,25 @@
+use crate::error::PaystackError;
+use crate::money::{amount_with_currency, Money};
+use crate::prelude::{Currency, Status};
+#[cfg(feature = "blocking")]
 use crate::{
-    prelude::{Currency, Status},
-    utils::{make_get_request, make_request, REQUEST},
+    pagination::Paginator,
+    response::PaystackResponse,
+    utils::{make_get_request, make_request, HttpClient},
 };
 use chrono::{DateTime, Local};
-use reqwest::blocking::Response;
+#[cfg(feature = "blocking")]
+use reqwest::Method;
 use serde::Serialize;
-use serde_json::Value as JSON;
+use serde_json::{json, Value as JSON};
+#[cfg(feature = "blocking")]
+use std::sync::Arc;
 
-#[derive(Debug, Default)]
+#[cfg(feature = "blocking")]
+#[derive(Debug, Clone)]
 /// The Invoices API allows you issue out and manage payment requests
 pub struct Invoices {
-    pub(crate) bearer_auth: String,
+    pub(crate) http: Arc<HttpClient>,
 }
 
 #[derive(Debug, Serialize)]
@@ -18,7 +27,9 @@ pub struct CreateInvoiceBody<'a> {
     /// Customer id or code
     pub customer: &'a str,
     /// Payment request amount. Only useful if line items and tax values are ignored. endpoint will throw a friendly warning if neither is available.
-    pub amount: i64,
+    /// Sent as `amount` in minor units and `currency`.
+    #[serde(flatten, serialize_with = "amount_with_currency")]
+    pub amount: Money,
     /// ISO 8601 representation of request due date
     pub due_date: DateTime<Local>,
     /// A short description of the payment request
@@ -27,8 +38,6 @@ pub struct CreateInvoiceBody<'a> {
     pub line_items: Option<Vec<JSON>>,
     /// Array of taxes to be charged in the format [{"name":"VAT", "amount":2000}]
     pub tax: Option<Vec<JSON>>,
-    /// Specify the currency of the invoice. Allowed values are NGN, GHS, ZAR and USD. Defaults to NGN
-    pub currency: Option<Currency>,
     /// Indicates whether Paystack sends an email notification to customer. Defaults to true
     pub send_notification: Option<bool>,
     /// Indicate if request should be saved as draft. Defaults to false and overrides send_notification
@@ -39,7 +48,132 @@ pub struct CreateInvoiceBody<'a> {
     pub invoice_number: Option<i64>,
 }
 
-#[derive(Debug, Serialize)]
+impl<'a> CreateInvoiceBody<'a> {
+    /// Start building an invoice of `amount` for `customer`, an id or code, payable by `due_date`
+    pub fn builder(
+        customer: &'a str,
+        amount: Money,
+        due_date: DateTime<Local>,
+    ) -> CreateInvoiceBuilder<'a> {
+        CreateInvoiceBuilder {
+            body: CreateInvoiceBody {
+                customer,
+                amount,
+                due_date,
+                description: None,
+                line_items: None,
+                tax: None,
+                send_notification: None,
+                draft: None,
+                has_invoice: None,
+                invoice_number: None,
+            },
+            mismatch: None,
+        }
+    }
+}
+
+/// Builds a [`CreateInvoiceBody`], checking that line items and taxes are in the invoice's
+/// currency. A draft may still set `send_notification`, which isn't checked: Paystack lets
+/// `draft` override it, so the draft is saved without being sent either way.
+/// ```rust
+/// use chrono::{Duration, Local};
+/// use paystack_rs::prelude::{CreateInvoiceBody, Currency, Money};
+///
+/// let due = Local::now() + Duration::days(7);
+/// let body = CreateInvoiceBody::builder("CUS_xwaj0txjryg393b", Money::from_minor(300_000, Currency::NGN), due)
+///     .line_item("Consulting", Money::from_minor(250_000, Currency::NGN))
+///     .tax("VAT", Money::from_minor(50_000, Currency::NGN))
+///     .build()
+///     .unwrap();
+/// assert_eq!(body.line_items.unwrap()[0]["amount"], 250_000);
+///
+/// // A line item in another currency than the invoice is rejected
+/// let body = CreateInvoiceBody::builder("CUS_xwaj0txjryg393b", Money::from_minor(300_000, Currency::NGN), due)
+///     .line_item("Consulting", Money::from_minor(250_000, Currency::GHS))
+///     .build();
+/// assert!(body.is_err());
+/// ```
+#[derive(Debug)]
+pub struct CreateInvoiceBuilder<'a> {
+    body: CreateInvoiceBody<'a>,
+    /// The first line item or tax in another currency than the invoice
+    mismatch: Option<String>,
+}
+
+impl<'a> CreateInvoiceBuilder<'a> {
+    /// A short description of the payment request
+    pub fn description(mut self, description: &'a str) -> CreateInvoiceBuilder<'a> {
+        self.body.description = Some(description);
+        self
+    }
+
+    /// Add an item named `name` costing `amount`
+    pub fn line_item(mut self, name: &str, amount: Money) -> CreateInvoiceBuilder<'a> {
+        self.body
+            .line_items
+            .get_or_insert_with(Vec::new)
+            .push(json!({"name": name, "amount": amount.minor()}));
+        self.check_currency("line item", name, &amount);
+        self
+    }
+
+    /// Add a tax named `name` of `amount`
+    pub fn tax(mut self, name: &str, amount: Money) -> CreateInvoiceBuilder<'a> {
+        self.body
+            .tax
+            .get_or_insert_with(Vec::new)
+            .push(json!({"name": name, "amount": amount.minor()}));
+        self.check_currency("tax", name, &amount);
+        self
+    }
+
+    /// Whether Paystack emails the invoice to the customer
+    pub fn send_notification(mut self, send: bool) -> CreateInvoiceBuilder<'a> {
+        self.body.send_notification = Some(send);
+        self
+    }
+
+    /// Save the invoice as a draft instead of sending it, whatever `send_notification` says
+    pub fn draft(mut self) -> CreateInvoiceBuilder<'a> {
+        self.body.draft = Some(true);
+        self
+    }
+
+    /// Number the invoice even if it has no line items or taxes
+    pub fn has_invoice(mut self, has_invoice: bool) -> CreateInvoiceBuilder<'a> {
+        self.body.has_invoice = Some(has_invoice);
+        self
+    }
+
+    /// Number of the invoice, from which later invoices continue counting
+    pub fn invoice_number(mut self, number: i64) -> CreateInvoiceBuilder<'a> {
+        self.body.invoice_number = Some(number);
+        self
+    }
+
+    fn check_currency(&mut self, kind: &str, name: &str, amount: &Money) {
+        if self.mismatch.is_none() && amount.currency() != self.body.amount.currency() {
+            self.mismatch = Some(format!(
+                "{} {} is in {} but the invoice is in {}",
+                kind,
+                name,
+                amount.currency(),
+                self.body.amount.currency()
+            ));
+        }
+    }
+
+    /// Check the fields that depend on each other and return the body
+    pub fn build(self) -> Result<CreateInvoiceBody<'a>, PaystackError> {
+        if let Some(mismatch) = self.mismatch {
+            return Err(PaystackError::validation(mismatch));
+        }
+        Ok(self.body)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
 pub struct ListInvoicesParams<'a> {
     #[serde(rename = "perPage")]
     /// Specify how many records you want to retrieve per page. If not specify we use a default value of 50.
@@ -65,7 +199,9 @@ pub struct UpdateInvoiceBody<'a> {
     /// Customer id or code
     pub customer: &'a str,
     /// Payment request amount. Only useful if line items and tax values are ignored. endpoint will throw a friendly warning if neither is available.
-    pub amount: i64,
+    /// Sent as `amount` in minor units and `currency`.
+    #[serde(flatten, serialize_with = "amount_with_currency")]
+    pub amount: Money,
     /// ISO 8601 representation of request due date
     pub due_date: Option<DateTime<Local>>,
     /// A short description of the payment request
@@ -74,8 +210,6 @@ pub struct UpdateInvoiceBody<'a> {
     pub line_items: Option<Vec<JSON>>,
     /// Array of taxes to be charged in the format [{"name":"VAT", "amount":2000}]
     pub tax: Option<Vec<JSON>>,
-    /// Specify the currency of the invoice. Allowed values are NGN, GHS, ZAR and USD. Defaults to NGN
-    pub currency: Option<Currency>,
     /// Indicates whether Paystack sends an email notification to customer. Defaults to true
     pub send_notification: Option<bool>,
     /// Indicate if request should be saved as draft. Defaults to false and overrides send_notification
@@ -85,57 +219,77 @@ pub struct UpdateInvoiceBody<'a> {
     /// Auto increment for subsequent invoices continue from this point.
     pub invoice_number: Option<i64>,
 }
-const INVOICES_URL: &str = "https://api.paystack.com/paymentrequest";
+pub(crate) const INVOICES_PATH: &str = "/paymentrequest";
+#[cfg(feature = "blocking")]
 impl Invoices {
     /// Create an invoice for payment on your integration
-    pub fn create_invoice(&self, body: CreateInvoiceBody) -> Result<Response, String> {
-        let res = make_request(&self.bearer_auth, INVOICES_URL, Some(body), REQUEST::POST);
-        return res;
+    pub fn create_invoice(
+        &self,
+        body: CreateInvoiceBody,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_request(&self.http, INVOICES_PATH, Some(body), Method::POST)
     }
 
     /// List the invoice available on your integration.
-    pub fn list_invoices(&self, params: Option<ListInvoicesParams>) -> Result<Response, String> {
-        let res = make_get_request(&self.bearer_auth, INVOICES_URL, params);
-        return res;
+    pub fn list_invoices(
+        &self,
+        params: Option<ListInvoicesParams>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_get_request(&self.http, INVOICES_PATH, params)
+    }
+
+    /// Iterate over every invoice, fetching further pages as they are needed
+    pub fn list_invoices_iter<'a>(
+        &self,
+        params: ListInvoicesParams<'a>,
+    ) -> Paginator<'a, JSON, ListInvoicesParams<'a>> {
+        let http = self.http.clone();
+        Paginator::new(params, move |params| {
+            make_get_request(&http, INVOICES_PATH, Some(params))
+        })
     }
 
     /// Get details of an invoice on your integration.
     /// - id_or_code: Invoice ID or slug
-    pub fn view_invoice(&self, id_or_code: &str) -> Result<Response, String> {
-        let url = format!("{}/{}", INVOICES_URL, id_or_code);
-        let res = make_get_request(&self.bearer_auth, &url, None::<String>);
-        return res;
+    pub fn view_invoice(&self, id_or_code: &str) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", INVOICES_PATH, id_or_code);
+        make_get_request(&self.http, &path, None::<String>)
     }
 
     /// Verify details of an invoice on your integration.
     /// - id_or_code: Invoice ID or slug
-    pub fn verify_invoice(&self, id_or_code: &str) -> Result<Response, String> {
-        let url = format!("{}/verify/{}", INVOICES_URL, id_or_code);
-        let res = make_get_request(&self.bearer_auth, &url, None::<String>);
-        return res;
+    pub fn verify_invoice(
+        &self,
+        id_or_code: &str,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/verify/{}", INVOICES_PATH, id_or_code);
+        make_get_request(&self.http, &path, None::<String>)
     }
 
     /// Send notification of an invoice to your customers
     /// - id_or_code: Invoice ID or slug
-    pub fn send_notification(&self, id_or_code: &str) -> Result<Response, String> {
-        let url = format!("{}/notify/{}", INVOICES_URL, id_or_code);
-        let res = make_request(&self.bearer_auth, &url, None::<String>, REQUEST::POST);
-        return res;
+    pub fn send_notification(
+        &self,
+        id_or_code: &str,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/notify/{}", INVOICES_PATH, id_or_code);
+        make_request(&self.http, &path, None::<String>, Method::POST)
     }
 
     /// Get invoice metrics for dashboard
-    pub fn invoice_total(&self) -> Result<Response, String> {
-        let url = format!("{}/totals", INVOICES_URL);
-        let res = make_request(&self.bearer_auth, &url, None::<String>, REQUEST::POST);
-        return res;
+    pub fn invoice_total(&self) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/totals", INVOICES_PATH);
+        make_request(&self.http, &path, None::<String>, Method::POST)
     }
 
     /// Finalize a Draft Invoice
     /// - id_or_code: Invoice ID or slug
-    pub fn finalize_invoice(&self, id_or_code: &str) -> Result<Response, String> {
-        let url = format!("{}/finalize/{}", INVOICES_URL, id_or_code);
-        let res = make_request(&self.bearer_auth, &url, None::<String>, REQUEST::POST);
-        return res;
+    pub fn finalize_invoice(
+        &self,
+        id_or_code: &str,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/finalize/{}", INVOICES_PATH, id_or_code);
+        make_request(&self.http, &path, None::<String>, Method::POST)
     }
 
     /// Update an invoice details on your integration
@@ -144,17 +298,18 @@ impl Invoices {
         &self,
         id_or_code: &str,
         body: UpdateInvoiceBody,
-    ) -> Result<Response, String> {
-        let url = format!("{}/{}", INVOICES_URL, id_or_code);
-        let res = make_request(&self.bearer_auth, &url, Some(body), REQUEST::PUT);
-        return res;
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", INVOICES_PATH, id_or_code);
+        make_request(&self.http, &path, Some(body), Method::PUT)
     }
 
     /// Used to archive an invoice. Invoice will no longer be fetched on list or returned on verify.
     /// - id_or_code: Invoice ID or slug
-    pub fn archive_invoice(&self, id_or_code: &str) -> Result<Response, String> {
-        let url = format!("{}/{}", INVOICES_URL, id_or_code);
-        let res = make_request(&self.bearer_auth, &url, None::<String>, REQUEST::POST);
-        return res;
+    pub fn archive_invoice(
+        &self,
+        id_or_code: &str,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", INVOICES_PATH, id_or_code);
+        make_request(&self.http, &path, None::<String>, Method::POST)
     }
 }
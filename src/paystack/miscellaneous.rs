@@ -1,24 +1,34 @@
-use reqwest::blocking::Response;
+use crate::prelude::Currency;
+#[cfg(feature = "blocking")]
+use crate::{
+    error::PaystackError,
+    pagination::Paginator,
+    response::PaystackResponse,
+    utils::{make_get_request, HttpClient},
+};
 use serde::Serialize;
+#[cfg(feature = "blocking")]
+use serde_json::Value as JSON;
+#[cfg(feature = "blocking")]
+use std::sync::Arc;
 
-use crate::{prelude::Currency, utils::make_get_request};
-
+#[cfg(feature = "blocking")]
 /// The Miscellaneous API are supporting APIs that can be used to provide more details to other APIs
-#[derive(Debug, Default)]
+#[derive(Debug, Clone)]
 pub struct Miscellaneous {
-    pub(crate) bearer_auth: String,
+    pub(crate) http: Arc<HttpClient>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Gateway {
     Emandate,
     DigitalBankMandate,
 }
-const LIST_BANKS_URL: &str = "https://api.paystack.co/bank";
-const LIST_COUNTRIES_URL: &str = "https://api.paystack.co/country";
-const LIST_STATES_URL: &str = "https://api.paystack.co/address_verification/states";
-#[derive(Debug, Serialize)]
+pub(crate) const LIST_BANKS_PATH: &str = "/bank";
+pub(crate) const LIST_COUNTRIES_PATH: &str = "/country";
+pub(crate) const LIST_STATES_PATH: &str = "/address_verification/states";
+#[derive(Debug, Clone, Serialize)]
 pub struct ListBanksParams<'a> {
     /// The country from which to obtain the list of supported banks. e.g `country=ghana` or `country=nigeria`
     pub country: &'a str,
@@ -28,9 +38,9 @@ pub struct ListBanksParams<'a> {
     /// Specify how many records you want to retrieve per page. If not specify we use a default value of 50.
     pub per_page: i64,
     /// A cursor that indicates your place in the list. It can be used to fetch the next page of the list
-    pub next: Option<&'a str>,
+    pub next: Option<String>,
     /// A cursor that indicates your place in the list. It should be used to fetch the previous page of the list after an intial next request
-    pub previous: Option<&'a str>,
+    pub previous: Option<String>,
     /// The gateway type of the bank. It can be one of these: [emandate, digitalbankmandate]
     pub gateway: Option<Gateway>,
     /// Type of financial channel. For Ghanaian channels, please use either **mobile_money** for mobile money channels OR **ghipps** for bank channels
@@ -51,29 +61,48 @@ pub struct ListStatesParams {
     /// The country code of the states to list. It is gotten after the charge request.
     pub country: i64,
 }
+#[cfg(feature = "blocking")]
 impl Miscellaneous {
     /// Get a list of all supported banks and their properties
-    pub fn list_banks(&self, params: ListBanksParams) -> Result<Response, String> {
-        let res = make_get_request(&self.bearer_auth, LIST_BANKS_URL, Some(params));
-        return res;
+    pub fn list_banks(
+        &self,
+        params: ListBanksParams,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_get_request(&self.http, LIST_BANKS_PATH, Some(params))
+    }
+
+    /// Iterate over every supported bank, following the `meta.next` cursor as further pages are
+    /// needed. Start from `params.previous` alone to walk back through `meta.previous` instead.
+    pub fn list_banks_iter<'a>(
+        &self,
+        mut params: ListBanksParams<'a>,
+    ) -> Paginator<'a, JSON, ListBanksParams<'a>> {
+        params.use_cursor = true;
+        let http = self.http.clone();
+        Paginator::cursor(params, move |params| {
+            make_get_request(&http, LIST_BANKS_PATH, Some(params))
+        })
     }
 
     // TODO: link with dedicated nuban
     /// Get a list of all providers for [][Dedicated NUBAN]
-    pub fn list_providers(&self, params: ListProvidersParams) -> Result<Response, String> {
-        let res = make_get_request(&self.bearer_auth, LIST_BANKS_URL, Some(params));
-        return res;
+    pub fn list_providers(
+        &self,
+        params: ListProvidersParams,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_get_request(&self.http, LIST_BANKS_PATH, Some(params))
     }
 
     /// Gets a list of Countries that Paystack currently supports
-    pub fn list_or_search_countries(&self) -> Result<Response, String> {
-        let res = make_get_request(&self.bearer_auth, LIST_COUNTRIES_URL, None::<String>);
-        return res;
+    pub fn list_or_search_countries(&self) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_get_request(&self.http, LIST_COUNTRIES_PATH, None::<String>)
     }
 
     /// Get a list of states for a country for address verification.
-    pub fn list_states(&self, params: ListStatesParams) -> Result<Response, String> {
-        let res = make_get_request(&self.bearer_auth, LIST_STATES_URL, Some(params));
-        return res;
+    pub fn list_states(
+        &self,
+        params: ListStatesParams,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_get_request(&self.http, LIST_STATES_PATH, Some(params))
     }
 }
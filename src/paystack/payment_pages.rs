@@ -1,14 +1,24 @@
+use crate::money::Money;
+#[cfg(feature = "blocking")]
+use crate::{
+    error::PaystackError,
+    pagination::Paginator,
+    response::PaystackResponse,
+    utils::{make_get_request, make_request, HttpClient},
+};
 use chrono::{DateTime, Local};
-use reqwest::blocking::Response;
+#[cfg(feature = "blocking")]
+use reqwest::Method;
 use serde::Serialize;
 use serde_json::Value as JSON;
+#[cfg(feature = "blocking")]
+use std::sync::Arc;
 
-use crate::utils::{make_get_request, make_request, REQUEST};
-
-#[derive(Debug, Default)]
+#[cfg(feature = "blocking")]
+#[derive(Debug, Clone)]
 /// The Payment Pages API provides a quick and secure way to collect payment for products.
 pub struct PaymentPages {
-    pub(crate) bearer_auth: String,
+    pub(crate) http: Arc<HttpClient>,
 }
 
 #[derive(Debug, Serialize)]
@@ -17,8 +27,9 @@ pub struct CreatePaymentPagesBody<'a> {
     pub name: &'a str,
     /// A description for this page
     pub description: Option<&'a str>,
-    /// Amount should be in kobo if currency is NGN, pesewas, if currency is GHS, and cents, if currency is ZAR
-    pub amount: Option<i128>,
+    /// Amount to collect on the page, sent in minor units without its currency, so it must be in
+    /// the integration's default currency
+    pub amount: Option<Money>,
     /// URL slug you would like to be associated with this page. Page will be accessible at https://paystack.com/pay/[slug]
     pub slug: Option<String>,
     /// Extra data to configure the payment page including subaccount, logo image, transaction charge
@@ -29,7 +40,7 @@ pub struct CreatePaymentPagesBody<'a> {
     pub custom_fields: Option<Vec<JSON>>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct ListPagesParams {
     /// Specify how many records you want to retrieve per page. If not specify we use a default value of 50.
     #[serde(rename = "perPage")]
@@ -49,7 +60,8 @@ pub struct UpdatePageBody<'a> {
     /// A description for this page
     pub description: Option<&'a str>,
     /// Default amount you want to accept using this page. If none is set, customer is free to provide any amount of their choice. The latter scenario is useful for accepting donations
-    pub amount: Option<i128>,
+    /// Sent in minor units without its currency, like the amount the page was created with.
+    pub amount: Option<Money>,
     /// Set to false to deactivate page url
     pub active: Option<bool>,
 }
@@ -60,51 +72,69 @@ pub struct AddProductsBody {
     pub product: Vec<i128>,
 }
 
-const PAYMENT_PAGES_URL: &str = "https://api.paystack.co/page";
+pub(crate) const PAYMENT_PAGES_PATH: &str = "/page";
+#[cfg(feature = "blocking")]
 impl PaymentPages {
     /// Create a payment page on your integration
-    pub fn create_pages(&self, body: CreatePaymentPagesBody) -> Result<Response, String> {
-        let res = make_request(
-            &self.bearer_auth,
-            PAYMENT_PAGES_URL,
-            Some(body),
-            REQUEST::POST,
-        );
-        return res;
+    pub fn create_pages(
+        &self,
+        body: CreatePaymentPagesBody,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_request(&self.http, PAYMENT_PAGES_PATH, Some(body), Method::POST)
     }
     /// List payment pages available on your integration.
-    pub fn list_pages(&self, params: Option<ListPagesParams>) -> Result<Response, String> {
-        let res = make_get_request(&self.bearer_auth, PAYMENT_PAGES_URL, params);
-        return res;
+    pub fn list_pages(
+        &self,
+        params: Option<ListPagesParams>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_get_request(&self.http, PAYMENT_PAGES_PATH, params)
+    }
+
+    /// Iterate over every payment page, fetching further pages as they are needed
+    pub fn list_pages_iter(
+        &self,
+        params: ListPagesParams,
+    ) -> Paginator<'static, JSON, ListPagesParams> {
+        let http = self.http.clone();
+        Paginator::new(params, move |params| {
+            make_get_request(&http, PAYMENT_PAGES_PATH, Some(params))
+        })
     }
 
     /// Get details of a payment page on your integration.
     /// id_or_slug: The page ID or slug you want to fetch.
-    pub fn fetch_page(&self, id_or_slug: &str) -> Result<Response, String> {
-        let url = format!("{}/{}", PAYMENT_PAGES_URL, id_or_slug);
-        let res = make_get_request(&self.bearer_auth, &url, None::<String>);
-        return res;
+    pub fn fetch_page(&self, id_or_slug: &str) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", PAYMENT_PAGES_PATH, id_or_slug);
+        make_get_request(&self.http, &path, None::<String>)
     }
 
     /// Update a payment page details on your integration
-    pub fn update_page(&self, id_or_slug: &str, body: UpdatePageBody) -> Result<Response, String> {
-        let url = format!("{}/{}", PAYMENT_PAGES_URL, id_or_slug);
-        let res = make_request(&self.bearer_auth, &url, Some(body), REQUEST::POST);
-        return res;
+    pub fn update_page(
+        &self,
+        id_or_slug: &str,
+        body: UpdatePageBody,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", PAYMENT_PAGES_PATH, id_or_slug);
+        make_request(&self.http, &path, Some(body), Method::POST)
     }
 
     /// Check the availability of a slug for a payment page.
     /// slug: URL slug to be confirmed
-    pub fn check_slug_availability(&self, slug: &str) -> Result<Response, String> {
-        let url = format!("{}/check_slug_availability/{}", PAYMENT_PAGES_URL, slug);
-        let res = make_get_request(&self.bearer_auth, &url, None::<String>);
-        return res;
+    pub fn check_slug_availability(
+        &self,
+        slug: &str,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/check_slug_availability/{}", PAYMENT_PAGES_PATH, slug);
+        make_get_request(&self.http, &path, None::<String>)
     }
 
     /// Add products to a payment page
-    pub fn add_products(&self, id: i128, body: AddProductsBody) -> Result<Response, String> {
-        let url = format!("{}/{}/product", PAYMENT_PAGES_URL, id);
-        let res = make_request(&self.bearer_auth, &url, Some(body), REQUEST::POST);
-        return res;
+    pub fn add_products(
+        &self,
+        id: i128,
+        body: AddProductsBody,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}/product", PAYMENT_PAGES_PATH, id);
+        make_request(&self.http, &path, Some(body), Method::POST)
     }
 }
@@ -1,13 +1,23 @@
-use crate::prelude::Currency;
-use crate::utils::*;
-use reqwest::blocking::Response;
+use crate::money::{amount_with_currency, Money};
+#[cfg(feature = "blocking")]
+use crate::{
+    error::PaystackError,
+    pagination::Paginator,
+    response::PaystackResponse,
+    utils::{make_get_request, make_request, HttpClient},
+};
+#[cfg(feature = "blocking")]
+use reqwest::Method;
 use serde::Serialize;
+#[cfg(feature = "blocking")]
+use serde_json::Value as JSON;
+#[cfg(feature = "blocking")]
+use std::sync::Arc;
 
-use crate::utils::make_request;
-
-#[derive(Debug, Default)]
+#[cfg(feature = "blocking")]
+#[derive(Debug, Clone)]
 pub struct Plans {
-    pub(crate) bearer_auth: String,
+    pub(crate) http: Arc<HttpClient>,
 }
 
 #[derive(Debug, Serialize)]
@@ -24,8 +34,9 @@ pub enum Interval {
 pub struct CreatePlan {
     /// Name of plan
     pub name: String,
-    /// Amount should be in kobo if currency is NGN, pesewas, if currency is GHS, and cents, if currency is ZAR
-    pub amount: i128,
+    /// Amount and currency of the plan, sent as `amount` in minor units and `currency`
+    #[serde(flatten, serialize_with = "amount_with_currency")]
+    pub amount: Money,
     /// Interval in words. Valid intervals are: daily, weekly, monthly,biannually, annually.
     pub interval: Interval,
     /// A description for this plan
@@ -34,27 +45,30 @@ pub struct CreatePlan {
     pub send_invoices: Option<bool>,
     /// Set to false if you don't want text messages to be sent to your customers
     pub send_sms: Option<String>, // TODO: change this to bool and see if it'll work.
-    /// Currency in which amount is set. Allowed values are NGN, GHS, ZAR or USD
-    pub currency: Option<Currency>,
     /// Number of invoices to raise during subscription to this plan. Can be overridden by specifying an invoice_limit while subscribing.
     pub invoice_limit: Option<i128>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Default, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ListPlansParams {
-    per_page: Option<i128>,
-    page: Option<i128>,
-    interval: Option<i128>,
-    amount: Option<Currency>,
+    /// Specify how many records you want to retrieve per page. If not specify we use a default value of 50.
+    pub per_page: Option<i128>,
+    /// Specify exactly what page you want to retrieve. If not specify we use a default value of 1.
+    pub page: Option<i128>,
+    /// Filter list by plans with specified interval
+    pub interval: Option<i128>,
+    /// Filter list by plans with specified amount, sent in minor units without its currency
+    pub amount: Option<Money>,
 }
 
 #[derive(Debug, Serialize)]
 pub struct UpdatePlanBody {
     /// Name of plan
     pub name: String,
-    /// Amount should be in kobo if currency is NGN, pesewas, if currency is GHS, and cents, if currency is ZAR
-    pub amount: i128,
+    /// Amount and currency of the plan, sent as `amount` in minor units and `currency`
+    #[serde(flatten, serialize_with = "amount_with_currency")]
+    pub amount: Money,
     /// Interval in words. Valid intervals are: daily, weekly, monthly,biannually, annually.
     pub interval: Interval,
     /// A description for this plan
@@ -63,32 +77,42 @@ pub struct UpdatePlanBody {
     pub send_invoices: Option<bool>,
     /// Set to false if you don't want text messages to be sent to your customers
     pub send_sms: Option<String>, // TODO: change this to bool and see if it'll work.
-    /// Currency in which amount is set. Allowed values are NGN, GHS, ZAR or USD
-    pub currency: Option<Currency>,
     /// Number of invoices to raise during subscription to this plan. Can be overridden by specifying an invoice_limit while subscribing.
     pub invoice_limit: Option<i128>,
 }
-const PLANS_URL: &str = "https://api.paystack.co/plan";
+pub(crate) const PLANS_PATH: &str = "/plan";
 /// The Plans API allows you create and manage installment payment options on your integration
+#[cfg(feature = "blocking")]
 impl Plans {
     /// Create a plan on your integration
-    pub fn create_plan(&self, body: CreatePlan) -> Result<Response, String> {
-        let res = make_request(&self.bearer_auth, PLANS_URL, Some(body), REQUEST::POST);
-        return res;
+    pub fn create_plan(&self, body: CreatePlan) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_request(&self.http, PLANS_PATH, Some(body), Method::POST)
     }
 
     /// List plans available on your integration.
-    pub fn list_plans(&self, params: Option<ListPlansParams>) -> Result<Response, String> {
-        let res = make_get_request(&self.bearer_auth, PLANS_URL, params);
-        return res;
+    pub fn list_plans(
+        &self,
+        params: Option<ListPlansParams>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_get_request(&self.http, PLANS_PATH, params)
+    }
+
+    /// Iterate over every plan, fetching further pages as they are needed
+    pub fn list_plans_iter(
+        &self,
+        params: ListPlansParams,
+    ) -> Paginator<'static, JSON, ListPlansParams> {
+        let http = self.http.clone();
+        Paginator::new(params, move |params| {
+            make_get_request(&http, PLANS_PATH, Some(params))
+        })
     }
 
     /// Get details of a plan on your integration.
     /// id_or_code: The plan ID or code you want to fetch
-    pub fn fetch_plan(&self, id_or_code: String) -> Result<Response, String> {
-        let url = format!("{}/{}", PLANS_URL, id_or_code);
-        let res = make_get_request(&self.bearer_auth, &url, None::<String>);
-        return res;
+    pub fn fetch_plan(&self, id_or_code: String) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", PLANS_PATH, id_or_code);
+        make_get_request(&self.http, &path, None::<String>)
     }
 
     /// Update a plan details on your integration
@@ -96,9 +120,8 @@ impl Plans {
         &self,
         id_or_code: String,
         body: UpdatePlanBody,
-    ) -> Result<Response, String> {
-        let url = format!("{}/{}", PLANS_URL, id_or_code);
-        let res = make_request(&self.bearer_auth, &url, Some(body), REQUEST::PUT);
-        return res;
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", PLANS_PATH, id_or_code);
+        make_request(&self.http, &path, Some(body), Method::PUT)
     }
 }
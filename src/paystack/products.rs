@@ -1,17 +1,26 @@
-use chrono::{DateTime, Utc};
-use reqwest::blocking::Response;
-use serde::Serialize;
-
+use crate::money::{price_with_currency, Money};
+#[cfg(feature = "blocking")]
 use crate::{
-    prelude::Currency,
-    utils::{make_get_request, make_request, REQUEST},
+    error::PaystackError,
+    pagination::Paginator,
+    response::PaystackResponse,
+    utils::{make_get_request, make_request, HttpClient},
 };
+use chrono::{DateTime, Utc};
+#[cfg(feature = "blocking")]
+use reqwest::Method;
+use serde::Serialize;
+#[cfg(feature = "blocking")]
+use serde_json::Value as JSON;
+#[cfg(feature = "blocking")]
+use std::sync::Arc;
 
-const PRODUCT_URL: &str = "https://api.paystack.co/product";
-#[derive(Debug, Default)]
+pub(crate) const PRODUCT_PATH: &str = "/product";
+#[cfg(feature = "blocking")]
+#[derive(Debug, Clone)]
 /// The Products API allows you create and manage inventories on your integration
 pub struct Products {
-    pub(crate) bearer_auth: String,
+    pub(crate) http: Arc<HttpClient>,
 }
 
 #[derive(Debug, Serialize)]
@@ -20,17 +29,16 @@ pub struct CreateProductBody {
     pub name: String,
     /// A description for this product
     pub description: String,
-    /// Price should be in kobo if currency is NGN, pesewas, if currency is GHS, and cents, if currency is ZAR
-    pub price: i128,
-    /// Currency in which price is set. Allowed values are: NGN, GHS, ZAR or USD
-    pub currency: Currency,
+    /// Price and currency of the product, sent as `price` in minor units and `currency`
+    #[serde(flatten, serialize_with = "price_with_currency")]
+    pub price: Money,
     /// Set to true if the product has limited stock. Leave as false if the product has unlimited stock
     pub limited: Option<bool>,
     /// Number of products in stock. Use if limited is true
     pub quantity: Option<i128>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Default, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ListProductsParams {
     /// Specify how many records you want to retrieve per page. If not specify we use a default value of 50.
@@ -49,35 +57,49 @@ pub struct UpdateProductsBody {
     pub name: String,
     /// A description for this product
     pub description: String,
-    /// Price should be in kobo if currency is NGN, pesewas, if currency is GHS, and cents, if currency is ZAR
-    pub price: i128,
-    /// Currency in which price is set. Allowed values are: NGN, GHS, ZAR or USD
-    pub currency: Currency,
+    /// Price and currency of the product, sent as `price` in minor units and `currency`
+    #[serde(flatten, serialize_with = "price_with_currency")]
+    pub price: Money,
     /// Set to true if the product has limited stock. Leave as false if the product has unlimited stock
     pub limited: Option<bool>,
     /// Number of products in stock. Use if limited is true
     pub quantity: Option<i128>,
 }
 
+#[cfg(feature = "blocking")]
 impl Products {
     /// Create a product on your integration
-    pub fn create_products(&self, body: CreateProductBody) -> Result<Response, String> {
-        let res = make_request(&self.bearer_auth, PRODUCT_URL, Some(body), REQUEST::POST);
-        return res;
+    pub fn create_products(
+        &self,
+        body: CreateProductBody,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_request(&self.http, PRODUCT_PATH, Some(body), Method::POST)
     }
 
     /// List products available on your integration.
-    pub fn list_products(&self, params: Option<ListProductsParams>) -> Result<Response, String> {
-        let res = make_get_request(&self.bearer_auth, PRODUCT_URL, params);
-        return res;
+    pub fn list_products(
+        &self,
+        params: Option<ListProductsParams>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_get_request(&self.http, PRODUCT_PATH, params)
+    }
+
+    /// Iterate over every product, fetching further pages as they are needed
+    pub fn list_products_iter(
+        &self,
+        params: ListProductsParams,
+    ) -> Paginator<'static, JSON, ListProductsParams> {
+        let http = self.http.clone();
+        Paginator::new(params, move |params| {
+            make_get_request(&http, PRODUCT_PATH, Some(params))
+        })
     }
 
     /// Get details of a product on your integration.
     /// id: The product ID you want to fetch
-    pub fn fetch_products(&self, id: String) -> Result<Response, String> {
-        let url = format!("{}/{}", PRODUCT_URL.to_owned(), id);
-        let res = make_get_request(&self.bearer_auth, &url, None::<String>);
-        return res;
+    pub fn fetch_products(&self, id: String) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", PRODUCT_PATH, id);
+        make_get_request(&self.http, &path, None::<String>)
     }
 
     /// Update a product details on your integration.
@@ -85,9 +107,8 @@ impl Products {
         &self,
         id: String,
         body: UpdateProductsBody,
-    ) -> Result<Response, String> {
-        let url = format!("{}/{}", PRODUCT_URL.to_owned(), id);
-        let res = make_request(&self.bearer_auth, &url, Some(body), REQUEST::PUT);
-        return res;
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", PRODUCT_PATH, id);
+        make_request(&self.http, &path, Some(body), Method::PUT)
     }
 }
@@ -1,12 +1,28 @@
-use crate::{prelude::Currency, utils::*};
+use crate::error::PaystackError;
+use crate::money::{optional_amount_with_currency, Money};
+use crate::paystack::transactions::TransactionCustomer;
+use crate::prelude::Currency;
+use crate::response::{lenient_i64, lenient_u64, timestamp};
+use crate::validation;
+#[cfg(feature = "blocking")]
+use crate::{
+    pagination::Paginator,
+    response::PaystackResponse,
+    utils::{make_get_request, make_request, HttpClient},
+};
 use chrono::{prelude::DateTime, Utc};
-use reqwest::blocking::Response;
-use serde::Serialize;
+#[cfg(feature = "blocking")]
+use reqwest::Method;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value as JSON;
+#[cfg(feature = "blocking")]
+use std::sync::Arc;
 
+#[cfg(feature = "blocking")]
 /// The Refunds API allows you create and manage transaction refunds
-#[derive(Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct Refunds {
-    pub(crate) bearer_auth: String,
+    pub(crate) http: Arc<HttpClient>,
 }
 
 #[derive(Debug, Serialize)]
@@ -14,18 +30,32 @@ pub struct CreateRefundBody<'a> {
     /// Transaction reference or id
     pub transaction: &'a str,
     /// Amount is optional(defaults to original transaction amount) and cannot be more than the original transaction amount.
-    pub amount: Option<&'a str>,
-    /// Three-letter ISO currency.
-    pub currency: Option<Currency>,
+    /// Sent as `amount` in minor units and `currency`.
+    #[serde(flatten, serialize_with = "optional_amount_with_currency")]
+    pub amount: Option<Money>,
     /// Customer reason
     pub customer_note: Option<&'a str>,
     /// Merchant reason
     pub merchant_note: Option<&'a str>,
 }
 
-#[derive(Debug, Serialize)]
+impl CreateRefundBody<'_> {
+    /// Check the transaction and amount before they are sent
+    pub fn validate(&self) -> Result<(), PaystackError> {
+        if self.transaction.is_empty() {
+            return Err(PaystackError::validation("transaction is empty"));
+        }
+        if let Some(amount) = &self.amount {
+            validation::amount("amount", amount)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct ListRefundsParams<'a> {
     /// Specify how many records you want to retrieve per page. If not specify we use a default value of 50.
+    #[serde(rename = "perPage")]
     pub per_page: Option<i64>,
     /// Specify exactly what page you want to retrieve. If not specify we use a default value of 1.
     pub page: Option<i64>,
@@ -39,24 +69,153 @@ pub struct ListRefundsParams<'a> {
     pub currency: Option<Currency>,
 }
 
-const REFUND_URL: &str = "https://api.paystack.co/refund";
+/// A refund, as sent in the `refund.*` webhooks. The webhooks name the transaction and the
+/// refund by their references, while the Refunds API sends the transaction itself.
+/// ```rust
+/// use paystack_rs::prelude::{Currency, Event};
+///
+/// // The `refund.processed` example from Paystack's documentation
+/// let event: Event = serde_json::from_str(
+///     r#"{
+///         "event": "refund.processed",
+///         "data": {
+///             "status": "processed",
+///             "transaction_reference": "tvunjbbd_412_829_1",
+///             "refund_reference": "132013318360",
+///             "amount": "10000",
+///             "currency": "NGN",
+///             "processor": "mpgs_zen",
+///             "customer": {
+///                 "first_name": "Damilola",
+///                 "last_name": "Odujoko",
+///                 "email": "damilola@example.com"
+///             },
+///             "integration": 412829,
+///             "domain": "live"
+///         }
+///     }"#,
+/// )
+/// .unwrap();
+/// match event {
+///     Event::RefundProcessed(refund) => {
+///         assert_eq!(refund.amount, 10_000);
+///         assert_eq!(refund.currency, Currency::NGN);
+///         assert_eq!(refund.refund_reference.as_deref(), Some("132013318360"));
+///     }
+///     other => panic!("unexpected {}", other.name()),
+/// }
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RefundData {
+    /// Paystack's ID for the refund. Webhooks send it as a string.
+    #[serde(deserialize_with = "lenient_u64", serialize_with = "string_id")]
+    pub id: Option<u64>,
+    /// `test` or `live`
+    pub domain: Option<String>,
+    /// Paystack's ID for the integration the refund was made on
+    pub integration: Option<i64>,
+    /// e.g. `pending`, `processing`, `processed` or `failed`
+    pub status: String,
+    /// Amount refunded, in the minor unit of `currency`. Webhooks send it as a string.
+    #[serde(deserialize_with = "lenient_amount", serialize_with = "string_amount")]
+    pub amount: i64,
+    /// Currency of the refunded transaction
+    pub currency: Currency,
+    /// Reference of the refunded transaction
+    pub transaction_reference: Option<String>,
+    /// Reference the processor gave the refund
+    pub refund_reference: Option<String>,
+    /// The refunded transaction, or its ID
+    pub transaction: Option<JSON>,
+    /// Processor that handled the refund, e.g. `mpgs_zen`
+    pub processor: Option<String>,
+    /// Channel of the refunded transaction
+    pub channel: Option<String>,
+    /// Amount already taken from the integration's balance, in the minor unit of `currency`
+    #[serde(deserialize_with = "lenient_i64")]
+    pub deducted_amount: Option<i64>,
+    /// Whether the whole amount has been taken from the integration's balance
+    pub fully_deducted: Option<bool>,
+    /// Reason given to the customer
+    pub customer_note: Option<String>,
+    /// Reason kept for the merchant
+    pub merchant_note: Option<String>,
+    /// Email of whoever asked for the refund
+    pub refunded_by: Option<String>,
+    /// The customer being refunded
+    pub customer: Option<TransactionCustomer>,
+    /// When the refund was processed
+    #[serde(serialize_with = "timestamp")]
+    pub refunded_at: Option<DateTime<Utc>>,
+    /// When the customer should expect the money
+    #[serde(serialize_with = "timestamp")]
+    pub expected_at: Option<DateTime<Utc>>,
+    /// When the refund was asked for
+    #[serde(alias = "createdAt")]
+    #[serde(serialize_with = "timestamp")]
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+/// Refund webhooks carry the ID as a string
+fn string_id<S: Serializer>(id: &Option<u64>, serializer: S) -> Result<S::Ok, S::Error> {
+    match id {
+        Some(id) => serializer.serialize_str(&id.to_string()),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn lenient_amount<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i64, D::Error> {
+    lenient_i64(deserializer).map(Option::unwrap_or_default)
+}
+
+/// Refund webhooks carry the amount as a string
+fn string_amount<S: Serializer>(amount: &i64, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&amount.to_string())
+}
+
+pub(crate) const REFUND_PATH: &str = "/refund";
+#[cfg(feature = "blocking")]
 impl Refunds {
     /// Initiate a refund on your integration
-    pub fn initiate_refund(&self, body: CreateRefundBody) -> Result<Response, String> {
-        let res = make_request(&self.bearer_auth, REFUND_URL, Some(body), REQUEST::POST);
-        return res;
+    pub fn initiate_refund(
+        &self,
+        body: CreateRefundBody,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        body.validate()?;
+        make_request(&self.http, REFUND_PATH, Some(body), Method::POST)
     }
 
     /// List refunds available on your integration.
-    pub fn list_refunds(&self, params: Option<ListRefundsParams>) -> Result<Response, String> {
-        let res = make_get_request(&self.bearer_auth, REFUND_URL, params);
-        return res;
+    pub fn list_refunds(
+        &self,
+        params: Option<ListRefundsParams>,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_get_request(&self.http, REFUND_PATH, params)
+    }
+
+    /// Iterate over every refund, fetching further pages as they are needed
+    pub fn list_refunds_iter<'a>(
+        &self,
+        params: ListRefundsParams<'a>,
+    ) -> Paginator<'a, JSON, ListRefundsParams<'a>> {
+        let http = self.http.clone();
+        Paginator::new(params, move |params| {
+            make_get_request(&http, REFUND_PATH, Some(params))
+        })
     }
     /// Get details of a refund on your integration.
     /// takes a parameter reference. A transaction reference for the refund you want to fetch
-    pub fn fetch_refund(&self, reference: &str) -> Result<Response, String> {
-        let url = format!("{}/{}", REFUND_URL, reference);
-        let res = make_get_request(&self.bearer_auth, &url, None::<String>);
-        return res;
+    /// ```rust
+    /// use paystack_rs::prelude::{Paystack, PaystackError};
+    ///
+    /// let paystack = Paystack::new("sk_test_xxxx".to_string());
+    /// let err = paystack.refund.fetch_refund("T685312322670591?perPage=1").unwrap_err();
+    /// assert!(matches!(err, PaystackError::Validation(_)));
+    /// ```
+    pub fn fetch_refund(&self, reference: &str) -> Result<PaystackResponse<JSON>, PaystackError> {
+        validation::lookup("reference", reference)?;
+        let path = format!("{}/{}", REFUND_PATH, reference);
+        make_get_request(&self.http, &path, None::<String>)
     }
 }
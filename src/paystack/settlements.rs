@@ -1,8 +1,18 @@
-use crate::utils::make_get_request;
+#[cfg(feature = "blocking")]
+use crate::{
+    error::PaystackError,
+    pagination::Paginator,
+    response::PaystackResponse,
+    utils::{make_get_request, HttpClient},
+};
 use chrono::{DateTime, Local};
-use reqwest::blocking::Response;
 use serde::Serialize;
-#[derive(Debug, Serialize)]
+#[cfg(feature = "blocking")]
+use serde_json::Value as JSON;
+#[cfg(feature = "blocking")]
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct FetchSettlementsBody<'a> {
     /// Specify how many records you want to retrieve per page. If not specify we use a default value of 50.
     #[serde(rename = "perPage")]
@@ -17,7 +27,7 @@ pub struct FetchSettlementsBody<'a> {
     pub to: Option<DateTime<Local>>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct FetchSettleTxBody<'a> {
     /// Specify how many records you want to retrieve per page. If not specify we use a default value of 50.
     #[serde(rename = "perPage")]
@@ -31,21 +41,33 @@ pub struct FetchSettleTxBody<'a> {
     /// A timestamp at which to stop listing product e.g. 2016-09-24T00:00:05.000Z, 2016-09-21
     pub to: Option<DateTime<Local>>,
 }
+#[cfg(feature = "blocking")]
 /// The Settlements API allows you gain insights into payouts made by Paystack to your bank account
-#[derive(Debug, Default)]
+#[derive(Debug, Clone)]
 pub struct Settlements {
-    pub(crate) bearer_auth: String,
+    pub(crate) http: Arc<HttpClient>,
 }
 
-const SETTLEMENTS_URL: &str = "https://api.paystack.co/settlement";
+pub(crate) const SETTLEMENTS_PATH: &str = "/settlement";
+#[cfg(feature = "blocking")]
 impl Settlements {
     /// Fetch settlements made to your settlement accounts.
     pub fn fetch_settlements(
         &self,
         params: Option<FetchSettlementsBody>,
-    ) -> Result<Response, String> {
-        let res = make_get_request(&self.bearer_auth, SETTLEMENTS_URL, Some(params));
-        return res;
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_get_request(&self.http, SETTLEMENTS_PATH, Some(params))
+    }
+
+    /// Iterate over every settlement, fetching further pages as they are needed
+    pub fn fetch_settlements_iter<'a>(
+        &self,
+        params: FetchSettlementsBody<'a>,
+    ) -> Paginator<'a, JSON, FetchSettlementsBody<'a>> {
+        let http = self.http.clone();
+        Paginator::new(params, move |params| {
+            make_get_request(&http, SETTLEMENTS_PATH, Some(params))
+        })
     }
 
     /// Get the transactions that make up a particular settlement
@@ -53,9 +75,21 @@ impl Settlements {
         &self,
         id: &str,
         params: Option<FetchSettleTxBody>,
-    ) -> Result<Response, String> {
-        let url = format!("{}/{}/transactions", SETTLEMENTS_URL, id);
-        let res = make_get_request(&self.bearer_auth, &url, params);
-        return res;
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}/transactions", SETTLEMENTS_PATH, id);
+        make_get_request(&self.http, &path, params)
+    }
+
+    /// Iterate over every transaction of a settlement, fetching further pages as they are needed
+    pub fn fetch_settlement_transactions_iter<'a>(
+        &self,
+        id: &str,
+        params: FetchSettleTxBody<'a>,
+    ) -> Paginator<'a, JSON, FetchSettleTxBody<'a>> {
+        let path = format!("{}/{}/transactions", SETTLEMENTS_PATH, id);
+        let http = self.http.clone();
+        Paginator::new(params, move |params| {
+            make_get_request(&http, &path, Some(params))
+        })
     }
 }
@@ -1,16 +1,27 @@
-use crate::utils::*;
+#[cfg(feature = "blocking")]
+use crate::{
+    error::PaystackError,
+    pagination::Paginator,
+    response::PaystackResponse,
+    utils::{make_get_request, make_request, HttpClient},
+};
 use chrono::{DateTime, Utc};
-use reqwest::blocking::Response;
+#[cfg(feature = "blocking")]
+use reqwest::Method;
 use serde::Serialize;
 use serde_json::Value;
+#[cfg(feature = "blocking")]
 use std::fmt::Debug;
+#[cfg(feature = "blocking")]
+use std::sync::Arc;
 
-const SUBACCOUNT_URL: &str = "https://api.paystack.co/subaccount";
+pub(crate) const SUBACCOUNT_PATH: &str = "/subaccount";
 
+#[cfg(feature = "blocking")]
 /// The Subaccounts API allows you create and manage subaccounts on your integration. Subaccounts can be used to split payment between two accounts (your main account and a sub account)
-#[derive(Debug, Default)]
+#[derive(Debug, Clone)]
 pub struct Subaccount {
-    pub(crate) bearer_auth: String,
+    pub(crate) http: Arc<HttpClient>,
 }
 
 #[derive(Debug, Serialize)]
@@ -35,9 +46,10 @@ pub struct CreateSubaccountBody<'a> {
     pub metadata: Option<Value>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct ListSubaccountParams {
     /// Specify how many records you want to retrieve per page. If not specify we use a default value of 50.
+    #[serde(rename = "perPage")]
     pub per_page: Option<i64>,
     /// Specify exactly what page you want to retrieve. If not specify we use a default value of 1.
     pub page: Option<i64>,
@@ -70,27 +82,42 @@ pub struct UpdateSubaccountBody<'a> {
 }
 
 /// The Subaccounts API allows you create and manage subaccounts on your integration. Subaccounts can be used to split payment between two accounts (your main account and a sub account)
+#[cfg(feature = "blocking")]
 impl Subaccount {
     /// Create a subacount on your integration
-    pub fn create_subaccount(&self, body: CreateSubaccountBody) -> Result<Response, String> {
-        let res = make_request(&self.bearer_auth, SUBACCOUNT_URL, Some(body), REQUEST::POST);
-        return res;
+    pub fn create_subaccount(
+        &self,
+        body: CreateSubaccountBody,
+    ) -> Result<PaystackResponse<Value>, PaystackError> {
+        make_request(&self.http, SUBACCOUNT_PATH, Some(body), Method::POST)
     }
 
     /// List subaccounts available on your integration.
-    pub fn list_subaccounts<T>(&self, params: Option<T>) -> Result<Response, String>
+    pub fn list_subaccounts<T>(
+        &self,
+        params: Option<T>,
+    ) -> Result<PaystackResponse<Value>, PaystackError>
     where
         T: Debug + Serialize,
     {
-        let res = make_get_request(&self.bearer_auth, SUBACCOUNT_URL, params);
-        return res;
+        make_get_request(&self.http, SUBACCOUNT_PATH, params)
+    }
+
+    /// Iterate over every subaccount, fetching further pages as they are needed
+    pub fn list_subaccounts_iter(
+        &self,
+        params: ListSubaccountParams,
+    ) -> Paginator<'static, Value, ListSubaccountParams> {
+        let http = self.http.clone();
+        Paginator::new(params, move |params| {
+            make_get_request(&http, SUBACCOUNT_PATH, Some(params))
+        })
     }
 
     /// Get details of a subaccount on your integration.
-    pub fn fetch_subaccount(&self, id: &str) -> Result<Response, String> {
-        let url = format!("{}/{}", SUBACCOUNT_URL, id);
-        let res = make_get_request(&self.bearer_auth, &url, None::<String>);
-        return res;
+    pub fn fetch_subaccount(&self, id: &str) -> Result<PaystackResponse<Value>, PaystackError> {
+        let path = format!("{}/{}", SUBACCOUNT_PATH, id);
+        make_get_request(&self.http, &path, None::<String>)
     }
 
     /// Update a subaccount details on your integration
@@ -98,9 +125,8 @@ impl Subaccount {
         &self,
         id: &str,
         body: UpdateSubaccountBody,
-    ) -> Result<Response, String> {
-        let url = format!("{}/{}", SUBACCOUNT_URL, id);
-        let res = make_request(&self.bearer_auth, &url, Some(body), REQUEST::PUT);
-        return res;
+    ) -> Result<PaystackResponse<Value>, PaystackError> {
+        let path = format!("{}/{}", SUBACCOUNT_PATH, id);
+        make_request(&self.http, &path, Some(body), Method::PUT)
     }
 }
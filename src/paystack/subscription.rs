@@ -1,12 +1,26 @@
-use crate::utils::{make_get_request, make_request, REQUEST};
+use crate::currency::Currency;
+use crate::paystack::transactions::{Authorization, TransactionCustomer, TransactionData};
+use crate::response::timestamp;
+#[cfg(feature = "blocking")]
+use crate::{
+    error::PaystackError,
+    pagination::Paginator,
+    response::PaystackResponse,
+    utils::{make_get_request, make_request, HttpClient},
+};
 use chrono::{DateTime, Utc};
-use reqwest::blocking::Response;
-use serde::Serialize;
+#[cfg(feature = "blocking")]
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JSON;
+#[cfg(feature = "blocking")]
+use std::sync::Arc;
 
+#[cfg(feature = "blocking")]
 /// The Subscriptions API allows you create and manage recurring payment on your integration
-#[derive(Debug, Default)]
+#[derive(Debug, Clone)]
 pub struct Subscription {
-    pub(crate) bearer_auth: String,
+    pub(crate) http: Arc<HttpClient>,
 }
 
 #[derive(Debug, Serialize)]
@@ -21,7 +35,7 @@ pub struct CreateSubscriptionBody {
     pub start_date: Option<DateTime<Utc>>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ListSubscriptionParams {
     /// Specify how many records you want to retrieve per page. If not specify we use a default value of 50.
@@ -50,47 +64,136 @@ pub struct DisableSubscriptionBody {
     pub token: String,
 }
 
-const SUBSCRIPTION_URL: &str = "https://api.paystack.co/subscription";
+/// A subscription, as sent in the `subscription.*` webhooks and inside invoices
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SubscriptionData {
+    /// Paystack's ID for the subscription
+    pub id: Option<i64>,
+    /// `test` or `live`
+    pub domain: Option<String>,
+    /// e.g. `active`, `non-renewing`, `attention`, `completed` or `cancelled`
+    pub status: String,
+    /// The subscription's code, e.g. `SUB_vsyqdmlzble3uii`
+    pub subscription_code: String,
+    /// Token the customer can cancel the subscription with, along with its code
+    pub email_token: Option<String>,
+    /// Amount charged on each payment, in the minor unit of the plan's currency
+    pub amount: i64,
+    /// When payments are due, as a cron expression, e.g. `0 0 28 * *`
+    pub cron_expression: Option<String>,
+    /// When the next payment is due, if any
+    #[serde(serialize_with = "timestamp")]
+    pub next_payment_date: Option<DateTime<Utc>>,
+    /// Code of the invoice waiting to be paid, if any
+    pub open_invoice: Option<String>,
+    /// The plan subscribed to
+    pub plan: Option<JSON>,
+    /// The card or account charged on each payment
+    pub authorization: Option<Authorization>,
+    /// The subscriber
+    pub customer: Option<TransactionCustomer>,
+    /// When the customer subscribed
+    #[serde(serialize_with = "timestamp")]
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+/// An invoice raised for a subscription payment, as sent in the `invoice.create`,
+/// `invoice.update` and `invoice.payment_failed` webhooks
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SubscriptionInvoice {
+    /// `test` or `live`
+    pub domain: Option<String>,
+    /// The invoice's code, e.g. `INV_thy2vkmirn2urwv`
+    pub invoice_code: String,
+    /// Amount due, in the minor unit of `currency`
+    pub amount: i64,
+    /// Currency of the amount, when Paystack includes it
+    pub currency: Option<Currency>,
+    /// e.g. `pending`, `success` or `failed`
+    pub status: String,
+    /// Whether the invoice has been paid
+    pub paid: bool,
+    /// When the invoice was paid
+    #[serde(serialize_with = "timestamp")]
+    pub paid_at: Option<DateTime<Utc>>,
+    /// Start of the period the invoice covers
+    #[serde(serialize_with = "timestamp")]
+    pub period_start: Option<DateTime<Utc>>,
+    /// End of the period the invoice covers
+    #[serde(serialize_with = "timestamp")]
+    pub period_end: Option<DateTime<Utc>>,
+    /// Description of the invoice, if any
+    pub description: Option<String>,
+    /// The card or account the invoice is charged to
+    pub authorization: Option<Authorization>,
+    /// The subscription the invoice belongs to
+    pub subscription: Option<SubscriptionData>,
+    /// The subscriber
+    pub customer: Option<TransactionCustomer>,
+    /// The payment attempt, once there is one
+    pub transaction: Option<TransactionData>,
+    /// When the invoice was raised
+    #[serde(serialize_with = "timestamp")]
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+pub(crate) const SUBSCRIPTION_PATH: &str = "/subscription";
 /// The Subscriptions API allows you create and manage recurring payment on your integration
+#[cfg(feature = "blocking")]
 impl Subscription {
     /// Create a subscription on your integration
     /// 💡 Email Token We create an email token on each subscription to allow customers cancel their subscriptions from within the invoices sent to their mailboxes. Since they are not authorized, the email tokens are what we use to authenticate the requests over the API.
-    pub fn create_subscription(&self, body: CreateSubscriptionBody) -> Result<Response, String> {
-        let res = make_request(
-            &self.bearer_auth,
-            SUBSCRIPTION_URL,
-            Some(body),
-            REQUEST::POST,
-        );
-        return res;
+    pub fn create_subscription(
+        &self,
+        body: CreateSubscriptionBody,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_request(&self.http, SUBSCRIPTION_PATH, Some(body), Method::POST)
     }
 
     /// List subscriptions available on your integration.
     pub fn list_subscription(
         &self,
         params: Option<ListSubscriptionParams>,
-    ) -> Result<Response, String> {
-        let res = make_get_request(&self.bearer_auth, SUBSCRIPTION_URL, params);
-        return res;
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_get_request(&self.http, SUBSCRIPTION_PATH, params)
+    }
+
+    /// Iterate over every subscription, fetching further pages as they are needed
+    pub fn list_subscription_iter(
+        &self,
+        params: ListSubscriptionParams,
+    ) -> Paginator<'static, JSON, ListSubscriptionParams> {
+        let http = self.http.clone();
+        Paginator::new(params, move |params| {
+            make_get_request(&http, SUBSCRIPTION_PATH, Some(params))
+        })
     }
     /// Get details of a subscription on your integration.
     /// id_or_code: The subscription ID or code you want to fetch
-    pub fn fetch_subscription(&self, id_or_code: String) -> Result<Response, String> {
-        let url = format!("{}/{}", SUBSCRIPTION_URL.to_owned(), id_or_code);
-        let res = make_get_request(&self.bearer_auth, &url, None::<String>);
-        return res;
+    pub fn fetch_subscription(
+        &self,
+        id_or_code: String,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", SUBSCRIPTION_PATH, id_or_code);
+        make_get_request(&self.http, &path, None::<String>)
     }
     /// Enable a subscription on your integration
-    pub fn enable_subscription(&self, body: EnableSubscriptionBody) -> Result<Response, String> {
-        let url = format!("{}/enable", SUBSCRIPTION_URL.to_owned());
-        let res = make_request(&self.bearer_auth, &url, Some(body), REQUEST::POST);
-        return res;
+    pub fn enable_subscription(
+        &self,
+        body: EnableSubscriptionBody,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/enable", SUBSCRIPTION_PATH);
+        make_request(&self.http, &path, Some(body), Method::POST)
     }
 
     /// Disable a subscription on your integration
-    pub fn disable_subscription(&self, body: DisableSubscriptionBody) -> Result<Response, String> {
-        let url = format!("{}/disable", SUBSCRIPTION_URL.to_owned());
-        let res = make_request(&self.bearer_auth, &url, Some(body), REQUEST::POST);
-        return res;
+    pub fn disable_subscription(
+        &self,
+        body: DisableSubscriptionBody,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/disable", SUBSCRIPTION_PATH);
+        make_request(&self.http, &path, Some(body), Method::POST)
     }
 }
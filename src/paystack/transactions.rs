@@ -1,25 +1,32 @@
-use crate::utils::*;
+use crate::builder::{Missing, Set};
+use crate::currency::Currency;
+use crate::error::PaystackError;
+use crate::money::{amount_with_currency, Money};
+use crate::response::timestamp;
+use crate::validation;
+#[cfg(feature = "blocking")]
+use crate::{
+    pagination::Paginator,
+    response::PaystackResponse,
+    utils::{make_get_request, make_idempotent_request, make_request, HttpClient},
+};
 use chrono::{prelude::DateTime, Utc};
-use reqwest::blocking::Response;
-use serde::Serialize;
+#[cfg(feature = "blocking")]
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::fmt::Debug;
+use std::marker::PhantomData;
+#[cfg(feature = "blocking")]
+use std::sync::Arc;
 
-#[derive(Default)]
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
 /// The Transactions API allows you create and manage payments on your integration
 pub struct Transaction {
-    pub(crate) bearer_auth: String,
+    pub(crate) http: Arc<HttpClient>,
 }
 
-#[derive(Debug, Serialize)]
-pub enum Currency {
-    NGN,
-    GHS,
-    USD,
-    ZAR,
-}
-
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Status {
     FAILED,
@@ -27,22 +34,13 @@ pub enum Status {
     ABANDONED,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Default, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum ChargesBearer {
+    #[default]
     Account,
     Subaccount,
 }
-impl Default for ChargesBearer {
-    fn default() -> Self {
-        ChargesBearer::Account
-    }
-}
-impl Default for Currency {
-    fn default() -> Self {
-        Currency::NGN
-    }
-}
 
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "snake_case")]
@@ -55,20 +53,25 @@ pub enum Channels {
     BankTransfer,
 }
 
-const CHARGE_AUTHORIZATION_URL: &str = "https://api.paystack.co/transaction/charge_authorization";
-const INITIALIZE_TRANSACTION_URL: &str = "https://api.paystack.co/transaction/initialize";
-const PAYSTACK_BASE_URL: &str = "https://api.paystack.co";
-const TRANSACTION_URL: &str = "https://api.paystack.co/transaction";
+pub(crate) const CHARGE_AUTHORIZATION_PATH: &str = "/transaction/charge_authorization";
+pub(crate) const CHECK_AUTHORIZATION_PATH: &str = "/transaction/check_authorization";
+pub(crate) const INITIALIZE_TRANSACTION_PATH: &str = "/transaction/initialize";
+pub(crate) const TRANSACTION_PATH: &str = "/transaction";
 /// struct passed to initiatialize a transaction.
 #[derive(Debug, Default, Serialize)]
 pub struct InitializeTransactionBody {
     /// Customer's email address
     pub email: String,
-    /// Amount should be in kobo if currency is NGN, pesewas, if currency is GHS, and cents, if currency is ZAR
-    pub amount: i128, // tbh, not sure what integer type i should use here. but pretty sure you cannot go wrong with i128
-    /// The transaction currency (NGN, GHS, ZAR or USD). Defaults to your integration currency.
-    pub currency: Option<Currency>,
+    /// Amount and currency of the transaction, sent as `amount` in minor units and `currency`.
+    /// Left at zero, and then out of the request, when the transaction is priced with a `plan`.
+    #[serde(
+        flatten,
+        serialize_with = "amount_with_currency",
+        skip_serializing_if = "is_unpriced"
+    )]
+    pub amount: Money,
     /// Unique transaction reference. Only -, ., = and alphanumeric characters allowed.
+    /// Setting it lets the client safely retry the request if Paystack fails to respond.
     pub reference: Option<String>,
     /// Fully qualified url, e.g. https://example.com/ . Use this to override the callback url provided on the dashboard for this transaction
     pub callback_url: Option<String>,
@@ -85,13 +88,208 @@ pub struct InitializeTransactionBody {
     /// The code for the subaccount that owns the payment. e.g. ACCT_8f4s1eq7ml6rlzj
     pub subaccount: Option<String>,
     /// A flat fee to charge the subaccount for this transaction (). This overrides the split percentage set when the subaccount was created. Ideally, you will need to use this if you are splitting in flat rates (since subaccount creation only allows for percentage split). e.g. 7000 for a 70 naira flat fee.
-    pub transaction_charge: Option<i128>,
+    /// Sent in minor units without a currency, so it must be in the currency of `amount`.
+    pub transaction_charge: Option<Money>,
     /// Who bears Paystack charges? account or subaccount (defaults to account).
     pub bearer: Option<ChargesBearer>,
 }
 
+impl InitializeTransactionBody {
+    /// Start building the body of a transaction paid by the customer at `email`
+    pub fn builder(email: impl Into<String>) -> InitializeTransactionBuilder<Missing> {
+        InitializeTransactionBuilder {
+            body: InitializeTransactionBody {
+                email: email.into(),
+                ..Default::default()
+            },
+            price: PhantomData,
+        }
+    }
+
+    /// Check the email, amount, reference and metadata before they are sent. Also run by the
+    /// builder, so a built body never fails this check.
+    pub fn validate(&self) -> Result<(), PaystackError> {
+        validation::email("email", &self.email)?;
+        match &self.plan {
+            None => validation::amount("amount", &self.amount)?,
+            Some(_) if !is_unpriced(&self.amount) => {
+                return Err(PaystackError::validation(
+                    "a plan sets the amount, so amount must be left unset",
+                ))
+            }
+            Some(_) => {}
+        }
+        if let Some(reference) = &self.reference {
+            validation::reference("reference", reference)?;
+        }
+        if let Some(metadata) = &self.metadata {
+            validation::metadata("metadata", metadata)?;
+        }
+        if let Some(charge) = &self.transaction_charge {
+            validation::amount("transaction_charge", charge)?;
+            if self.plan.is_none() {
+                validation::same_currency("transaction_charge", charge, &self.amount)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Builds an [`InitializeTransactionBody`]. The transaction is priced either with an amount or
+/// with a plan, exactly once, before it can be built; `build` then checks the fields that only
+/// make sense together.
+/// ```rust
+/// use paystack_rs::prelude::{Currency, InitializeTransactionBody, Money, PaystackError};
+///
+/// let body = InitializeTransactionBody::builder("customer@email.com")
+///     .amount(Money::from_minor(500_000, Currency::NGN))
+///     .reference("order-1042")
+///     .subaccount("ACCT_8f4s1eq7ml6rlzj")
+///     .transaction_charge(Money::from_minor(7_000, Currency::NGN))
+///     .build()
+///     .unwrap();
+/// assert_eq!(body.amount.minor(), 500_000);
+///
+/// // A flat fee is only charged to a subaccount
+/// let err = InitializeTransactionBody::builder("customer@email.com")
+///     .amount(Money::from_minor(500_000, Currency::NGN))
+///     .transaction_charge(Money::from_minor(7_000, Currency::NGN))
+///     .build()
+///     .unwrap_err();
+/// assert!(matches!(err, PaystackError::Validation(_)));
+/// ```
+#[derive(Debug)]
+pub struct InitializeTransactionBuilder<P> {
+    body: InitializeTransactionBody,
+    price: PhantomData<P>,
+}
+
+impl InitializeTransactionBuilder<Missing> {
+    /// Charge `amount` for the transaction
+    pub fn amount(mut self, amount: Money) -> InitializeTransactionBuilder<Set> {
+        self.body.amount = amount;
+        InitializeTransactionBuilder {
+            body: self.body,
+            price: PhantomData,
+        }
+    }
+
+    /// Subscribe the customer to the plan with code `plan`. Paystack charges the plan's own
+    /// amount in its currency, so neither is sent.
+    /// ```rust
+    /// use paystack_rs::prelude::InitializeTransactionBody;
+    ///
+    /// let body = InitializeTransactionBody::builder("customer@email.com")
+    ///     .plan("PLN_gx2wn530m0i3w3m")
+    ///     .invoice_limit(12)
+    ///     .build()
+    ///     .unwrap();
+    /// let json = serde_json::to_value(&body).unwrap();
+    /// assert_eq!(json["plan"], "PLN_gx2wn530m0i3w3m");
+    /// assert!(json.get("amount").is_none() && json.get("currency").is_none());
+    /// ```
+    pub fn plan(mut self, plan: impl Into<String>) -> InitializeTransactionBuilder<Set> {
+        self.body.plan = Some(plan.into());
+        InitializeTransactionBuilder {
+            body: self.body,
+            price: PhantomData,
+        }
+    }
+}
+
+impl<P> InitializeTransactionBuilder<P> {
+    /// Unique reference of the transaction, which also lets the client retry the request safely
+    pub fn reference(mut self, reference: impl Into<String>) -> InitializeTransactionBuilder<P> {
+        self.body.reference = Some(reference.into());
+        self
+    }
+
+    /// Where to send the customer after paying, instead of the dashboard's callback url
+    pub fn callback_url(mut self, url: impl Into<String>) -> InitializeTransactionBuilder<P> {
+        self.body.callback_url = Some(url.into());
+        self
+    }
+
+    /// Number of times to charge the customer during their subscription. Requires a plan.
+    pub fn invoice_limit(mut self, limit: i64) -> InitializeTransactionBuilder<P> {
+        self.body.invoice_limit = Some(limit);
+        self
+    }
+
+    /// Custom data to attach to the transaction
+    pub fn metadata(mut self, metadata: &Value) -> InitializeTransactionBuilder<P> {
+        self.body.metadata = Some(metadata.to_string());
+        self
+    }
+
+    /// The channels offered to the customer on the checkout page
+    pub fn channels(mut self, channels: Vec<Channels>) -> InitializeTransactionBuilder<P> {
+        self.body.channels = Some(channels);
+        self
+    }
+
+    /// Settle the transaction according to the split with code `split_code`
+    pub fn split_code(mut self, split_code: impl Into<String>) -> InitializeTransactionBuilder<P> {
+        self.body.split_code = Some(split_code.into());
+        self
+    }
+
+    /// Settle the transaction to the subaccount with code `subaccount`
+    pub fn subaccount(mut self, subaccount: impl Into<String>) -> InitializeTransactionBuilder<P> {
+        self.body.subaccount = Some(subaccount.into());
+        self
+    }
+
+    /// Flat fee to charge the subaccount, overriding the percentage it was created with.
+    /// Requires a subaccount.
+    pub fn transaction_charge(mut self, charge: Money) -> InitializeTransactionBuilder<P> {
+        self.body.transaction_charge = Some(charge);
+        self
+    }
+
+    /// Who bears Paystack's charges. Requires a subaccount.
+    pub fn bearer(mut self, bearer: ChargesBearer) -> InitializeTransactionBuilder<P> {
+        self.body.bearer = Some(bearer);
+        self
+    }
+}
+
+impl InitializeTransactionBuilder<Set> {
+    /// Check the fields that depend on each other and return the body
+    pub fn build(self) -> Result<InitializeTransactionBody, PaystackError> {
+        let body = self.body;
+        if body.invoice_limit.is_some() && body.plan.is_none() {
+            return Err(PaystackError::validation(
+                "invoice_limit only applies to a plan",
+            ));
+        }
+        if body.split_code.is_some() && body.subaccount.is_some() {
+            return Err(PaystackError::validation(
+                "split_code and subaccount cannot both settle the transaction",
+            ));
+        }
+        if body.subaccount.is_none() {
+            if body.transaction_charge.is_some() {
+                return Err(PaystackError::validation(
+                    "transaction_charge requires a subaccount",
+                ));
+            }
+            if body.bearer.is_some() {
+                return Err(PaystackError::validation("bearer requires a subaccount"));
+            }
+        }
+        body.validate()?;
+        Ok(body)
+    }
+}
+
+/// A transaction priced with a plan leaves its amount at zero
+fn is_unpriced(amount: &Money) -> bool {
+    amount.minor() == 0
+}
+
 /// struct ListTransactionsQuery
-#[derive(Debug, Default, Serialize)]
+#[derive(Debug, Clone, Default, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ListTransactionsParams {
     /// Specify how many records you want to retrieve per page. If not specify we use a default value of 50.
@@ -106,36 +304,56 @@ pub struct ListTransactionsParams {
     pub from: Option<DateTime<Utc>>,
     /// A timestamp at which to stop listing transaction e.g. 2016-09-24T00:00:05.000Z, 2016-09-21
     pub to: Option<DateTime<Utc>>,
-    /// Filter transactions by amount. Specify the amount (in kobo if currency is NGN, pesewas, if currency is GHS, and cents, if currency is ZAR)
-    pub amount: Option<i128>,
+    /// Filter transactions by amount, sent in minor units without its currency
+    pub amount: Option<Money>,
 }
 
 #[derive(Serialize, Default, Debug)]
 pub struct ChargeAuthorizationBody {
-    /// Amount should be in kobo if currency is NGN, pesewas, if currency is GHS, and cents, if currency is ZAR
-    pub amount: String,
+    /// Amount and currency to charge, sent as `amount` in minor units and `currency`
+    #[serde(flatten, serialize_with = "amount_with_currency")]
+    pub amount: Money,
     /// Customer's email address
     pub email: String,
     /// Valid authorization code to charge
     pub authorization_code: String,
     /// Unique transaction reference. Only -, ., = and alphanumeric characters allowed.
+    /// Setting it lets the client safely retry the request if Paystack fails to respond.
     pub reference: Option<String>,
-    /// Currency in which amount should be charged. Allowed values are: NGN, GHS, ZAR or USD
-    pub currency: Option<Currency>,
     /// Stringified JSON object. Add a custom_fields attribute which has an array of objects if you would like the fields to be added to your transaction when displayed on the dashboard. Sample: {"custom_fields":[{"display_name":"Cart ID","variable_name": "cart_id","value": "8393"}]}
     pub metadata: Option<Value>,
     /// Send us 'card' or 'bank' or 'card','bank' as an array to specify what options to show the user paying
     pub channels: Option<Vec<String>>,
     /// The code for the subaccount that owns the payment. e.g. ACCT_8f4s1eq7ml6rlzj
     pub subaccount: Option<String>,
-    /// A flat fee to charge the subaccount for this transaction (in kobo if currency is NGN, pesewas, if currency is GHS, and cents, if currency is ZAR). This overrides the split percentage set when the subaccount was created. Ideally, you will need to use this if you are splitting in flat rates (since subaccount creation only allows for percentage split). e.g. 7000 for a 70 naira
-    pub transaction_charge: Option<i128>,
+    /// A flat fee to charge the subaccount for this transaction. This overrides the split percentage set when the subaccount was created. Ideally, you will need to use this if you are splitting in flat rates (since subaccount creation only allows for percentage split). e.g. 7000 for a 70 naira
+    /// Sent in minor units without a currency, so it must be in the currency of `amount`.
+    pub transaction_charge: Option<Money>,
     /// Who bears Paystack charges? account or subaccount (defaults to account).
     pub bearer: Option<ChargesBearer>,
     /// If you are making a scheduled charge call, it is a good idea to queue them so the processing system does not get overloaded causing transaction processing errors. Send queue:true to take advantage of our queued charging.
     pub queue: Option<bool>,
 }
 
+impl ChargeAuthorizationBody {
+    /// Check the email, amount, reference and metadata before they are sent
+    pub fn validate(&self) -> Result<(), PaystackError> {
+        validation::email("email", &self.email)?;
+        validation::amount("amount", &self.amount)?;
+        if let Some(reference) = &self.reference {
+            validation::reference("reference", reference)?;
+        }
+        if let Some(metadata) = &self.metadata {
+            validation::metadata("metadata", &metadata.to_string())?;
+        }
+        if let Some(charge) = &self.transaction_charge {
+            validation::amount("transaction_charge", charge)?;
+            validation::same_currency("transaction_charge", charge, &self.amount)?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Default, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionsTotal {
@@ -155,30 +373,60 @@ pub struct TransactionsTotal {
 
 #[derive(Debug, Default, Serialize)]
 pub struct CheckAuthorizationBody {
-    /// Amount should be in kobo if currency is NGN, pesewas, if currency is GHS, and cents, if currency is ZAR
-    pub amount: String,
+    /// Amount and currency to check for, sent as `amount` in minor units and `currency`
+    #[serde(flatten, serialize_with = "amount_with_currency")]
+    pub amount: Money,
     /// Customer's email address
     pub email: String,
     /// Valid authorization code to charge
     pub authorization_code: String,
-    /// Currency in which amount should be charged. Allowed values are: NGN, GHS, ZAR or USD
-    pub currency: Option<Currency>,
+}
+
+impl CheckAuthorizationBody {
+    /// Check the email and amount before they are sent
+    pub fn validate(&self) -> Result<(), PaystackError> {
+        validation::email("email", &self.email)?;
+        validation::amount("amount", &self.amount)
+    }
 }
 
 #[derive(Debug, Default, Serialize)]
 pub struct PartialDebitBody {
-    /// Amount should be in kobo if currency is NGN, pesewas, if currency is GHS, and cents, if currency is ZAR
-    pub amount: String,
+    /// Amount and currency to debit, sent as `amount` in minor units and `currency`
+    #[serde(flatten, serialize_with = "amount_with_currency")]
+    pub amount: Money,
     /// Customer's email address (attached to the authorization code)
     pub email: String,
     /// Authorization Code
     pub authorization_code: String,
-    /// Specify the currency you want to debit. Allowed values are NGN, GHS, ZAR or USD.
-    pub currency: Currency,
     /// Unique transaction reference. Only -, ., = and alphanumeric characters allowed.
+    /// Setting it lets the client safely retry the request if Paystack fails to respond.
     pub reference: Option<String>,
-    /// Minimum amount to charge
-    pub at_least: Option<String>,
+    /// Minimum amount to charge, sent in minor units without a currency, so it must be in the
+    /// currency of `amount`
+    pub at_least: Option<Money>,
+}
+
+impl PartialDebitBody {
+    /// Check the email, amounts and reference before they are sent
+    pub fn validate(&self) -> Result<(), PaystackError> {
+        validation::email("email", &self.email)?;
+        validation::amount("amount", &self.amount)?;
+        if let Some(reference) = &self.reference {
+            validation::reference("reference", reference)?;
+        }
+        if let Some(at_least) = &self.at_least {
+            validation::amount("at_least", at_least)?;
+            validation::same_currency("at_least", at_least, &self.amount)?;
+            if at_least.minor() > self.amount.minor() {
+                return Err(PaystackError::validation(format!(
+                    "at_least {} is more than the amount {}",
+                    at_least, self.amount
+                )));
+            }
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -199,8 +447,9 @@ pub struct ExportTransactionsBody {
     pub status: Option<Status>,
     /// Specify the transaction currency to export. Allowed values are: in kobo if currency is NGN, pesewas, if currency is GHS, and cents, if currency is ZAR
     pub currency: Option<Currency>,
-    /// Filter transactions by amount. Specify the amount, in kobo if currency is NGN, pesewas, if currency is GHS, and cents, if currency is ZAR
-    pub amount: Option<String>,
+    /// Filter transactions by amount, sent in minor units without its currency, so it should be
+    /// in `currency`
+    pub amount: Option<Money>,
     /// Set to true to export only settled transactions. false for pending transactions. Leave undefined to export all transactions
     pub settled: Option<bool>,
     /// An ID for the settlement whose transactions we should export
@@ -209,18 +458,225 @@ pub struct ExportTransactionsBody {
     pub payment_page: Option<i64>,
 }
 
+/// `data` of a successful `initialize_transaction`
+#[derive(Debug, Clone, Deserialize)]
+pub struct InitializeTransactionData {
+    /// URL to redirect the customer to so they can pay
+    pub authorization_url: String,
+    /// Code to resume the payment with Paystack's Popup JS
+    pub access_code: String,
+    /// Reference of the new transaction, generated by Paystack unless one was given
+    pub reference: String,
+}
+
+/// A transaction as returned by the verify, fetch and list endpoints, and as the result of
+/// charging an authorization or a card. Fields a particular endpoint leaves out are left empty,
+/// e.g. a charge still waiting for a PIN only carries `reference`, `status` and `display_text`.
+/// ```rust
+/// use paystack_rs::prelude::TransactionData;
+///
+/// let data: TransactionData = serde_json::from_str(
+///     r#"{
+///         "id": 4099260516, "domain": "test", "status": "success", "reference": "re4lyvq3s3",
+///         "amount": 40333, "currency": "NGN", "channel": "card", "gateway_response": "Successful",
+///         "paid_at": "2024-08-22T09:15:02.000Z", "fees": 10283, "metadata": "",
+///         "authorization": {"authorization_code": "AUTH_uh8bcl3zbn", "last4": "4081", "reusable": true},
+///         "customer": {"id": 181873746, "email": "demo@test.com", "customer_code": "CUS_1rkzaqsv4rrhqo6"},
+///         "log": {"time_spent": 4, "history": [{"type": "success", "message": "Successfully paid", "time": 4}]},
+///         "split": {}
+///     }"#,
+/// )
+/// .unwrap();
+/// assert!(data.authorization.unwrap().reusable);
+/// assert_eq!(data.customer.unwrap().customer_code, "CUS_1rkzaqsv4rrhqo6");
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TransactionData {
+    /// Paystack's ID for the transaction
+    pub id: i64,
+    /// `test` or `live`
+    pub domain: String,
+    /// e.g. `success`, `failed`, `abandoned`, `ongoing`, `reversed`, or a charge step such as `send_pin`
+    pub status: String,
+    /// Unique transaction reference
+    pub reference: String,
+    /// Amount in the minor unit of `currency`, e.g. kobo
+    pub amount: i64,
+    /// The amount originally asked for, before fees passed on to the customer
+    pub requested_amount: Option<i64>,
+    /// Three letter currency code, e.g. `NGN`
+    pub currency: Currency,
+    /// e.g. `card`, `bank`, `ussd`, `qr`, `mobile_money`, `bank_transfer`
+    pub channel: String,
+    /// Message shown for the last charge attempt, if any
+    pub message: Option<String>,
+    /// What the processor said about the charge, e.g. `Successful` or `Declined`
+    pub gateway_response: Option<String>,
+    /// Instruction to show the customer while a charge waits on them, e.g. `Please enter your PIN`
+    pub display_text: Option<String>,
+    /// Fees Paystack deducted, in the minor unit of `currency`
+    pub fees: Option<i64>,
+    /// How the fees were shared between the integration and its subaccounts
+    pub fees_split: Option<Value>,
+    /// When the transaction was created
+    #[serde(serialize_with = "timestamp")]
+    pub created_at: Option<DateTime<Utc>>,
+    /// When the transaction was created, as sent by the charge endpoints
+    #[serde(serialize_with = "timestamp")]
+    pub transaction_date: Option<DateTime<Utc>>,
+    /// When the customer paid
+    #[serde(serialize_with = "timestamp")]
+    pub paid_at: Option<DateTime<Utc>>,
+    /// IP address the customer paid from
+    pub ip_address: Option<String>,
+    /// Whatever metadata was sent when the transaction was initialized
+    pub metadata: Option<Value>,
+    /// Record of the customer's attempts on the checkout
+    pub log: Option<TransactionLog>,
+    /// The card or account that was charged
+    pub authorization: Option<Authorization>,
+    /// The customer who paid
+    pub customer: Option<TransactionCustomer>,
+    /// The plan the transaction paid for, if any
+    pub plan: Option<Value>,
+    /// The split the payment was shared by, if any
+    pub split: Option<TransactionSplitData>,
+    /// The subaccount that owns the payment, if any
+    pub subaccount: Option<Value>,
+    /// ID of the Commerce order the transaction paid for, if any
+    pub order_id: Option<i64>,
+}
+
+/// A reusable card or account a customer paid with. Charge it again with its `authorization_code`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Authorization {
+    /// Code to charge this authorization with, e.g. `AUTH_8dfhjjdt`
+    pub authorization_code: String,
+    /// First six digits of the card
+    pub bin: Option<String>,
+    /// Last four digits of the card
+    pub last4: Option<String>,
+    /// Expiry month of the card, e.g. `12`
+    pub exp_month: Option<String>,
+    /// Expiry year of the card, e.g. `2030`
+    pub exp_year: Option<String>,
+    /// e.g. `card` or `bank`
+    pub channel: Option<String>,
+    /// e.g. `visa` or `mastercard debit`
+    pub card_type: Option<String>,
+    /// Issuing bank
+    pub bank: Option<String>,
+    /// Two letter code of the issuing country, e.g. `NG`
+    pub country_code: Option<String>,
+    /// e.g. `visa`
+    pub brand: Option<String>,
+    /// Whether the authorization can be charged again with `charge_authorization`
+    pub reusable: bool,
+    /// Identifies the card across authorizations, to spot customers reusing the same card
+    pub signature: Option<String>,
+    /// Name on the account, for bank authorizations
+    pub account_name: Option<String>,
+}
+
+/// The customer section of a transaction
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TransactionCustomer {
+    /// Paystack's ID for the customer
+    pub id: i64,
+    /// The customer's code, e.g. `CUS_xnxdt6s1zg1f4nx`
+    pub customer_code: String,
+    /// The customer's email address
+    pub email: String,
+    /// The customer's first name
+    pub first_name: Option<String>,
+    /// The customer's last name
+    pub last_name: Option<String>,
+    /// The customer's phone number
+    pub phone: Option<String>,
+    /// Metadata stored on the customer
+    pub metadata: Option<Value>,
+    /// `default`, `allow` or `deny`
+    pub risk_action: Option<String>,
+    /// The phone number in international format, e.g. `+2348012345678`
+    pub international_format_phone: Option<String>,
+}
+
+/// What happened on the checkout while the customer paid. Also the `data` of `view_transaction_timeline`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TransactionLog {
+    /// Unix timestamp of when the checkout was opened
+    pub start_time: Option<i64>,
+    /// Seconds the customer spent on the checkout
+    pub time_spent: Option<i64>,
+    /// Number of payment attempts
+    pub attempts: Option<i64>,
+    /// Number of failed attempts
+    pub errors: Option<i64>,
+    /// Whether the customer ended up paying
+    pub success: Option<bool>,
+    /// Whether the checkout was opened on a mobile device
+    pub mobile: Option<bool>,
+    /// How the customer was authenticated, e.g. `pin`
+    pub authentication: Option<String>,
+    /// Fields the customer filled in
+    pub input: Vec<Value>,
+    /// Every step of the checkout, in order
+    pub history: Vec<TransactionLogEntry>,
+}
+
+/// One step of a [`TransactionLog`]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TransactionLogEntry {
+    /// e.g. `action`, `auth`, `success` or `error`
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// What happened, e.g. `Attempted to pay with card`
+    pub message: String,
+    /// Seconds since the checkout was opened
+    pub time: i64,
+}
+
+/// The split section of a transaction that was shared with subaccounts
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TransactionSplitData {
+    /// Paystack's ID for the split
+    pub id: Option<i64>,
+    /// Name of the split
+    pub name: Option<String>,
+    /// The split's code, e.g. `SPL_98WF13Eb3w`
+    pub split_code: Option<String>,
+    /// `percentage` or `flat`
+    #[serde(rename = "type")]
+    pub split_type: Option<String>,
+    /// Who bears Paystack's fees, e.g. `account`, `subaccount` or `all-proportional`
+    pub bearer_type: Option<String>,
+    /// The subaccount that bears the fees, when `bearer_type` is `subaccount`
+    pub bearer_subaccount: Option<String>,
+    /// Each subaccount and its share
+    pub subaccounts: Vec<Value>,
+    /// What each party ended up with
+    pub shares: Option<Value>,
+    /// How the shares were computed
+    pub formula: Option<Value>,
+}
+
+#[cfg(feature = "blocking")]
 impl Transaction {
     /// Initialize a transaction from your backend
-    /// ```rust
-    /// # use std::env;
+    /// ```no_run
     /// # use paystack_rs::prelude::Paystack;
-    /// use paystack_rs::prelude::InitializeTransactionBody;
+    /// use paystack_rs::prelude::{Currency, Money, InitializeTransactionBody};
     ///
-    /// # let key = env::var("PAYSTACK_SECRET_KEY").unwrap();
-    /// let paystack = Paystack::new(key);
+    /// let paystack = Paystack::new("sk_test_xxxx".to_string());
     /// let body = InitializeTransactionBody{
     ///     email: "randomemail@gmail.com".to_string(),
-    ///     amount: 10000,
+    ///     amount: Money::from_major(100, Currency::NGN).unwrap(),
     ///     ..Default::default()
     /// };
     /// paystack.transaction.initialize_transaction(body);
@@ -228,78 +684,93 @@ impl Transaction {
     pub fn initialize_transaction(
         &self,
         body: InitializeTransactionBody,
-    ) -> Result<Response, String> {
-        let res = make_request(
-            &self.bearer_auth,
-            INITIALIZE_TRANSACTION_URL,
+    ) -> Result<PaystackResponse<InitializeTransactionData>, PaystackError> {
+        body.validate()?;
+        let idempotent = body.reference.is_some();
+        make_idempotent_request(
+            &self.http,
+            INITIALIZE_TRANSACTION_PATH,
             Some(body),
-            REQUEST::POST,
-        );
-        return res;
+            Method::POST,
+            idempotent,
+        )
     }
 
     /// verify a transaction. it takes an argument reference which is the reference_id of a transaction you want to verify
-    /// ```rust
-    /// # use std::env;
+    /// ```no_run
     /// # use paystack_rs::prelude::Paystack;
     ///
-    /// # let key = env::var("PAYSTACK_SECRET_KEY").unwrap();
-    /// let paystack = Paystack::new(key);
+    /// let paystack = Paystack::new("sk_test_xxxx".to_string());
     /// paystack.transaction.verify_transaction("DG4uishudoq90LD".to_string());
     /// ```
-    pub fn verify_transaction(&self, reference: String) -> Result<Response, String> {
-        let full_url = format!(
-            "{}/transaction/verify/:{}",
-            PAYSTACK_BASE_URL,
-            reference.to_string()
-        );
-        let result = make_get_request(&self.bearer_auth, &full_url, None::<String>);
-        return result;
+    /// A reference that can't be part of the path is rejected before any request is made:
+    /// ```rust
+    /// # use paystack_rs::prelude::{Paystack, PaystackError};
+    /// # let paystack = Paystack::new("sk_test_xxxx".to_string());
+    /// let err = paystack.transaction.verify_transaction("../balance".to_string()).unwrap_err();
+    /// assert!(matches!(err, PaystackError::Validation(_)));
+    /// ```
+    pub fn verify_transaction(
+        &self,
+        reference: String,
+    ) -> Result<PaystackResponse<TransactionData>, PaystackError> {
+        validation::lookup("reference", &reference)?;
+        let path = format!("{}/verify/{}", TRANSACTION_PATH, reference);
+        make_get_request(&self.http, &path, None::<String>)
     }
 
     /// list_transactions lists all the transactions available
-    /// ```rust
-    /// # use std::env;
+    /// ```no_run
     /// # use paystack_rs::prelude::Paystack;
     /// use paystack_rs::prelude::ListTransactionsParams;
     ///
-    /// # let key = env::var("PAYSTACK_SECRET_KEY").unwrap();
-    /// let paystack = Paystack::new(key);
+    /// let paystack = Paystack::new("sk_test_xxxx".to_string());
     /// /// Retrieve 50 transactions per page
     /// let body = ListTransactionsParams{
     ///     per_page: Some(50),
     ///     ..Default::default()
     /// };
     /// paystack.transaction.list_transactions(body);
-    pub fn list_transactions(&self, body: ListTransactionsParams) -> Result<Response, String> {
-        let res = make_get_request(&self.bearer_auth, TRANSACTION_URL, Some(body));
-        return res;
+    pub fn list_transactions(
+        &self,
+        body: ListTransactionsParams,
+    ) -> Result<PaystackResponse<Vec<TransactionData>>, PaystackError> {
+        make_get_request(&self.http, TRANSACTION_PATH, Some(body))
     }
 
-    /// ```rust
-    /// # use std::env;
+    /// Iterate over every transaction, fetching further pages as they are needed
+    pub fn list_transactions_iter(
+        &self,
+        params: ListTransactionsParams,
+    ) -> Paginator<'static, TransactionData, ListTransactionsParams> {
+        let http = self.http.clone();
+        Paginator::new(params, move |params| {
+            make_get_request(&http, TRANSACTION_PATH, Some(params))
+        })
+    }
+
+    /// ```no_run
     /// # use paystack_rs::prelude::Paystack;
     ///
-    /// # let key = env::var("PAYSTACK_SECRET_KEY").unwrap();
-    /// let paystack = Paystack::new(key);
+    /// let paystack = Paystack::new("sk_test_xxxx".to_string());
     /// paystack.transaction.fetch_transaction(123412);
     /// ```
-    pub fn fetch_transaction(&self, transaction_id: i64) -> Result<Response, String> {
-        let url = format!("{}/{}", TRANSACTION_URL, transaction_id);
-        let res = make_get_request(&self.bearer_auth, &url, None::<String>);
-        return res;
+    pub fn fetch_transaction(
+        &self,
+        transaction_id: i64,
+    ) -> Result<PaystackResponse<TransactionData>, PaystackError> {
+        let path = format!("{}/{}", TRANSACTION_PATH, transaction_id);
+        make_get_request(&self.http, &path, None::<String>)
     }
 
     /// All authorizations marked as reusable can be charged with this endpoint whenever you need to receive payments.
-    /// ```rust
-    /// # use std::env;
+    /// ```no_run
     /// # use paystack_rs::prelude::Paystack;
-    /// use paystack_rs::prelude::ChargeAuthorizationBody;
+    /// use paystack_rs::prelude::{Currency, Money, ChargeAuthorizationBody};
     ///
-    /// # let key = env::var("PAYSTACK_SECRET_KEY").unwrap();
-    /// let paystack = Paystack::new(key);
+    /// let paystack = Paystack::new("sk_test_xxxx".to_string());
     /// let body = ChargeAuthorizationBody{
-    ///     amount: "5000".to_string(),
+    ///     amount: Money::from_major(50, Currency::NGN).unwrap(),
     ///     email: "randomemail@gmail.com".to_string(),
     ///     authorization_code: "2aeserqwdEAW".to_string(),
     ///     ..Default::default()
@@ -308,14 +779,16 @@ impl Transaction {
     pub fn charge_authorization(
         &self,
         params: ChargeAuthorizationBody,
-    ) -> Result<Response, String> {
-        let res = make_request(
-            &self.bearer_auth,
-            CHARGE_AUTHORIZATION_URL,
+    ) -> Result<PaystackResponse<TransactionData>, PaystackError> {
+        params.validate()?;
+        let idempotent = params.reference.is_some();
+        make_idempotent_request(
+            &self.http,
+            CHARGE_AUTHORIZATION_PATH,
             Some(params),
-            REQUEST::POST,
-        );
-        return res;
+            Method::POST,
+            idempotent,
+        )
     }
     /// All mastercard and visa authorizations can be checked with this endpoint to know if they have funds for the payment you seek.
     /// This endpoint should be used when you do not know the exact amount to charge a card when rendering a service. It should be used to check if a card has enough funds based on a maximum range value. It is well suited for:
@@ -325,93 +798,95 @@ impl Transaction {
     ///
     ///
     /// ⚠️ Warning You shouldn't use this endpoint to check a card for sufficient funds if you are going to charge the user immediately. This is because we hold funds when this endpoint is called which can lead to an insufficient funds error.
-    /// ```rust
-    /// # use std::env;
+    /// ```no_run
     /// # use paystack_rs::prelude::Paystack;
-    /// use paystack_rs::prelude::CheckAuthorizationBody;
+    /// use paystack_rs::prelude::{Currency, Money, CheckAuthorizationBody};
     ///
-    /// # let key = env::var("PAYSTACK_SECRET_KEY").unwrap();
-    /// let paystack = Paystack::new(key);
+    /// let paystack = Paystack::new("sk_test_xxxx".to_string());
     /// let body = CheckAuthorizationBody{
-    ///     amount: "5000".to_string(),
+    ///     amount: Money::from_major(50, Currency::NGN).unwrap(),
     ///     email: "randomemail@gmail.com".to_string(),
     ///     authorization_code: "2aeserqwdEAW".to_string(),
     ///     ..Default::default()
     /// };
     /// paystack.transaction.check_authorization(body);
-    pub fn check_authorization(&self, param: CheckAuthorizationBody) -> Result<Response, String> {
-        let full_url = CHARGE_AUTHORIZATION_URL;
-        let res = make_request(&self.bearer_auth, full_url, Some(param), REQUEST::POST);
-        return res;
+    pub fn check_authorization(
+        &self,
+        param: CheckAuthorizationBody,
+    ) -> Result<PaystackResponse<Value>, PaystackError> {
+        param.validate()?;
+        make_request(
+            &self.http,
+            CHECK_AUTHORIZATION_PATH,
+            Some(param),
+            Method::POST,
+        )
     }
 
-    /// ```rust
-    /// # use std::env;
+    /// ```no_run
     /// # use paystack_rs::prelude::Paystack;
     ///
-    /// # let key = env::var("PAYSTACK_SECRET_KEY").unwrap();
-    /// let paystack = Paystack::new(key);
+    /// let paystack = Paystack::new("sk_test_xxxx".to_string());
     /// paystack.transaction.view_transaction_timeline("DG4uishudoq90LD".to_string());
-    pub fn view_transaction_timeline(&self, id: String) -> Result<Response, String> {
-        let full_url = format!("{}/timeline/{}", TRANSACTION_URL, id).to_string();
-        let res = make_get_request(&self.bearer_auth, &full_url, None::<String>);
-        return res;
+    pub fn view_transaction_timeline(
+        &self,
+        id: String,
+    ) -> Result<PaystackResponse<TransactionLog>, PaystackError> {
+        validation::lookup("id", &id)?;
+        let path = format!("{}/timeline/{}", TRANSACTION_PATH, id);
+        make_get_request(&self.http, &path, None::<String>)
     }
 
     /// Total amount received on your account
-    /// ```rust
-    /// # use std::env;
+    /// ```no_run
     /// # use paystack_rs::prelude::Paystack;
     ///
-    /// # let key = env::var("PAYSTACK_SECRET_KEY").unwrap();
-    /// let paystack = Paystack::new(key);
+    /// let paystack = Paystack::new("sk_test_xxxx".to_string());
     /// /// Retrieve total transactions
     /// paystack.transaction.transactions_total(None);
     pub fn transactions_total(
         &self,
         params: Option<TransactionsTotal>,
-    ) -> Result<Response, String> {
-        let full_url = format!("{}/totals", TRANSACTION_URL);
-        let res = make_get_request(&self.bearer_auth, &full_url, params);
-        return res;
+    ) -> Result<PaystackResponse<Value>, PaystackError> {
+        let path = format!("{}/totals", TRANSACTION_PATH);
+        make_get_request(&self.http, &path, params)
     }
 
     /// Export transactions carried out on your integration.
-    /// ```rust
-    /// # use std::env;
+    /// ```no_run
     /// # use paystack_rs::prelude::Paystack;
     ///
-    /// # let key = env::var("PAYSTACK_SECRET_KEY").unwrap();
-    /// let paystack = Paystack::new(key);
+    /// let paystack = Paystack::new("sk_test_xxxx".to_string());
     /// /// Retrieve total transactions
     /// paystack.transaction.export_transactions(None);
     pub fn export_transactions(
         &self,
         params: Option<ExportTransactionsBody>,
-    ) -> Result<Response, String> {
-        let full_url = format!("{}/export", TRANSACTION_URL);
-        let res = make_get_request(&self.bearer_auth, &full_url, params);
-        return res;
+    ) -> Result<PaystackResponse<Value>, PaystackError> {
+        let path = format!("{}/export", TRANSACTION_PATH);
+        make_get_request(&self.http, &path, params)
     }
 
     /// Retrieve part of a payment from a customer
-    /// ```rust
-    /// # use std::env;
+    /// ```no_run
     /// # use paystack_rs::prelude::Paystack;
-    /// use paystack_rs::prelude::PartialDebitBody;
+    /// use paystack_rs::prelude::{Currency, Money, PartialDebitBody};
     ///
-    /// # let key = env::var("PAYSTACK_SECRET_KEY").unwrap();
-    /// let paystack = Paystack::new(key);
+    /// let paystack = Paystack::new("sk_test_xxxx".to_string());
     /// let body = PartialDebitBody{
-    ///     amount: "5000".to_string(),
+    ///     amount: Money::from_major(50, Currency::NGN).unwrap(),
     ///     email: "randomemail@gmail.com".to_string(),
     ///     authorization_code: "2aeserqwdEAW".to_string(),
     ///     ..Default::default()
     /// };
     /// paystack.transaction.partial_debit(body);
-    pub fn partial_debit(&self, body: PartialDebitBody) -> Result<Response, String> {
-        let full_url = format!("{}/partial_debit", TRANSACTION_URL);
-        let res = make_request(&self.bearer_auth, &full_url, Some(body), REQUEST::POST);
-        return res;
+    pub fn partial_debit(
+        &self,
+        body: PartialDebitBody,
+    ) -> Result<PaystackResponse<TransactionData>, PaystackError> {
+        body.validate()?;
+        let path = format!("{}/partial_debit", TRANSACTION_PATH);
+        let idempotent = body.reference.is_some();
+        make_idempotent_request(&self.http, &path, Some(body), Method::POST, idempotent)
     }
 }
@@ -1,15 +1,28 @@
-use std::fmt::Debug;
-
-use crate::{prelude::Currency, utils::*};
+use crate::builder::{Missing, Set};
+use crate::error::PaystackError;
+use crate::prelude::Currency;
+#[cfg(feature = "blocking")]
+use crate::{
+    pagination::Paginator,
+    response::PaystackResponse,
+    utils::{make_get_request, make_request, HttpClient},
+};
 use chrono::{DateTime, Utc};
-use reqwest::blocking::Response;
+#[cfg(feature = "blocking")]
+use reqwest::Method;
 use serde::Serialize;
+#[cfg(feature = "blocking")]
+use serde_json::Value as JSON;
+#[cfg(feature = "blocking")]
+use std::sync::Arc;
+use std::{collections::HashSet, marker::PhantomData};
 
-const SPLIT_PAYMENT_URL: &str = "https://api.paystack.co/split";
-#[derive(Default, Debug)]
+pub(crate) const SPLIT_PAYMENT_PATH: &str = "/split";
+#[cfg(feature = "blocking")]
+#[derive(Clone, Debug)]
 /// The Transaction Splits API enables merchants split the settlement for a transaction across their payout account, and one or more Subaccounts.
 pub struct TransactionSplit {
-    pub(crate) bearer_auth: String,
+    pub(crate) http: Arc<HttpClient>,
 }
 
 #[derive(Serialize, Debug)]
@@ -50,7 +63,138 @@ pub struct CreateSplitPaymentBody<'a> {
     pub bearer_subaccount: &'a str,
 }
 
-#[derive(Debug, Serialize)]
+impl<'a> CreateSplitPaymentBody<'a> {
+    /// Start building a split named `name`, sharing transactions in `currency` by `tx_type`.
+    /// Paystack's charges are borne by the main account unless told otherwise.
+    pub fn builder(
+        name: &'a str,
+        tx_type: TxType,
+        currency: Currency,
+    ) -> CreateSplitPaymentBuilder<'a, Missing> {
+        CreateSplitPaymentBuilder {
+            body: CreateSplitPaymentBody {
+                name,
+                tx_type,
+                currency,
+                subaccount: Vec::new(),
+                bearer_type: BearerType::Account,
+                bearer_subaccount: "",
+            },
+            subaccounts: PhantomData,
+        }
+    }
+}
+
+/// Builds a [`CreateSplitPaymentBody`]. A split needs at least one subaccount before it can be
+/// built, and `build` checks the shares and the subaccount bearing the charges.
+/// ```rust
+/// use paystack_rs::prelude::{CreateSplitPaymentBody, Currency, TxType};
+///
+/// let body = CreateSplitPaymentBody::builder("Halfsies", TxType::Percentage, Currency::NGN)
+///     .subaccount("ACCT_z3x6z3nbo14xsil", 20)
+///     .subaccount("ACCT_pwwualwty4nhq9d", 30)
+///     .bearer_subaccount("ACCT_z3x6z3nbo14xsil")
+///     .build()
+///     .unwrap();
+/// assert_eq!(body.subaccount.len(), 2);
+///
+/// // Percentages can't add up to more than the whole transaction
+/// let body = CreateSplitPaymentBody::builder("Greedy", TxType::Percentage, Currency::NGN)
+///     .subaccount("ACCT_z3x6z3nbo14xsil", 60)
+///     .subaccount("ACCT_pwwualwty4nhq9d", 60)
+///     .build();
+/// assert!(body.is_err());
+/// ```
+#[derive(Debug)]
+pub struct CreateSplitPaymentBuilder<'a, S> {
+    body: CreateSplitPaymentBody<'a>,
+    subaccounts: PhantomData<S>,
+}
+
+impl<'a, S> CreateSplitPaymentBuilder<'a, S> {
+    /// Give the subaccount with code `subaccount_code` a share of each transaction, as a
+    /// percentage or a flat amount in minor units depending on the split's type
+    pub fn subaccount(
+        mut self,
+        subaccount_code: &'a str,
+        share: i64,
+    ) -> CreateSplitPaymentBuilder<'a, Set> {
+        self.body.subaccount.push(SubaccountsBody {
+            subaccount_code,
+            share,
+        });
+        CreateSplitPaymentBuilder {
+            body: self.body,
+            subaccounts: PhantomData,
+        }
+    }
+
+    /// Who bears Paystack's charges. Use [`CreateSplitPaymentBuilder::bearer_subaccount`] to
+    /// have one of the subaccounts bear them.
+    pub fn bearer(mut self, bearer_type: BearerType) -> CreateSplitPaymentBuilder<'a, S> {
+        self.body.bearer_type = bearer_type;
+        self.body.bearer_subaccount = "";
+        self
+    }
+
+    /// Have the subaccount with code `subaccount_code`, one of the split's, bear Paystack's
+    /// charges
+    pub fn bearer_subaccount(
+        mut self,
+        subaccount_code: &'a str,
+    ) -> CreateSplitPaymentBuilder<'a, S> {
+        self.body.bearer_type = BearerType::Subaccount;
+        self.body.bearer_subaccount = subaccount_code;
+        self
+    }
+}
+
+impl<'a> CreateSplitPaymentBuilder<'a, Set> {
+    /// Check the shares and the bearer and return the body
+    pub fn build(self) -> Result<CreateSplitPaymentBody<'a>, PaystackError> {
+        let body = self.body;
+        let mut codes = HashSet::new();
+        for subaccount in &body.subaccount {
+            if subaccount.share <= 0 {
+                return Err(PaystackError::validation(format!(
+                    "the share of {} must be positive",
+                    subaccount.subaccount_code
+                )));
+            }
+            if !codes.insert(subaccount.subaccount_code) {
+                return Err(PaystackError::validation(format!(
+                    "{} is in the split more than once",
+                    subaccount.subaccount_code
+                )));
+            }
+        }
+        if let TxType::Percentage = body.tx_type {
+            let total: i64 = body.subaccount.iter().map(|s| s.share).sum();
+            if total > 100 {
+                return Err(PaystackError::validation(format!(
+                    "the subaccounts' shares add up to {}%",
+                    total
+                )));
+            }
+        }
+        if let BearerType::Subaccount = body.bearer_type {
+            if body.bearer_subaccount.is_empty() {
+                return Err(PaystackError::validation(
+                    "a subaccount bearer requires bearer_subaccount",
+                ));
+            }
+            if !codes.contains(body.bearer_subaccount) {
+                return Err(PaystackError::validation(format!(
+                    "the bearer {} is not one of the split's subaccounts",
+                    body.bearer_subaccount
+                )));
+            }
+        }
+        Ok(body)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ListOrSearchSplitsParams<'a> {
     /// The name of the split
@@ -95,39 +239,49 @@ pub struct RemoveSplitSubaccountBody<'a> {
     pub subaccount: &'a str,
 }
 
+#[cfg(feature = "blocking")]
 impl TransactionSplit {
     /// Create a split payment on your integration
-    pub fn create_split(&self, body: CreateSplitPaymentBody) -> Result<Response, String> {
-        let res = make_request(
-            &self.bearer_auth,
-            &SPLIT_PAYMENT_URL,
-            Some(body),
-            REQUEST::POST,
-        );
-        return res;
+    pub fn create_split(
+        &self,
+        body: CreateSplitPaymentBody,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_request(&self.http, SPLIT_PAYMENT_PATH, Some(body), Method::POST)
     }
 
     /// List/search for the transaction splits available on your integration.
     pub fn list_or_search_splits(
         &self,
         params: Option<ListOrSearchSplitsParams>,
-    ) -> Result<Response, String> {
-        let res = make_get_request(&self.bearer_auth, SPLIT_PAYMENT_URL, params);
-        return res;
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_get_request(&self.http, SPLIT_PAYMENT_PATH, params)
+    }
+
+    /// Iterate over every split, fetching further pages as they are needed
+    pub fn list_or_search_splits_iter<'a>(
+        &self,
+        params: ListOrSearchSplitsParams<'a>,
+    ) -> Paginator<'a, JSON, ListOrSearchSplitsParams<'a>> {
+        let http = self.http.clone();
+        Paginator::new(params, move |params| {
+            make_get_request(&http, SPLIT_PAYMENT_PATH, Some(params))
+        })
     }
 
     /// Get details of a split on your integration.
-    pub fn fetch_split(&self, id: &str) -> Result<Response, String> {
-        let url = format!("{}/{}", SPLIT_PAYMENT_URL, id);
-        let res = make_get_request(&self.bearer_auth, &url, None::<String>);
-        return res;
+    pub fn fetch_split(&self, id: &str) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", SPLIT_PAYMENT_PATH, id);
+        make_get_request(&self.http, &path, None::<String>)
     }
 
     /// Update a transaction split details on your integration
-    pub fn update_split(&self, id: &str, body: UpdateSplitBody) -> Result<Response, String> {
-        let url = format!("{}/{}", SPLIT_PAYMENT_URL, id);
-        let res = make_request(&self.bearer_auth, &url, Some(body), REQUEST::PUT);
-        return res;
+    pub fn update_split(
+        &self,
+        id: &str,
+        body: UpdateSplitBody,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", SPLIT_PAYMENT_PATH, id);
+        make_request(&self.http, &path, Some(body), Method::PUT)
     }
 
     /// Add a Subaccount to a Transaction Split, or update the share of an existing Subaccount in a Transaction Split
@@ -135,10 +289,9 @@ impl TransactionSplit {
         &self,
         id: &str,
         body: AddOrUpdateSplitSubaccountBody,
-    ) -> Result<Response, String> {
-        let url = format!("{}/{}/subaccount/add", SPLIT_PAYMENT_URL, id);
-        let res = make_request(&self.bearer_auth, &url, Some(body), REQUEST::POST);
-        return res;
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}/subaccount/add", SPLIT_PAYMENT_PATH, id);
+        make_request(&self.http, &path, Some(body), Method::POST)
     }
 
     /// Remove a subaccount from a transaction split
@@ -146,9 +299,8 @@ impl TransactionSplit {
         &self,
         id: &str,
         body: RemoveSplitSubaccountBody,
-    ) -> Result<Response, String> {
-        let url = format!("{}/{}/subaccount/remove", SPLIT_PAYMENT_URL, id);
-        let res = make_request(&self.bearer_auth, &url, Some(body), REQUEST::POST);
-        return res;
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}/subaccount/remove", SPLIT_PAYMENT_PATH, id);
+        make_request(&self.http, &path, Some(body), Method::POST)
     }
 }
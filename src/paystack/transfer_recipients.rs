@@ -1,23 +1,30 @@
+use crate::prelude::Currency;
+#[cfg(feature = "blocking")]
+use crate::{
+    error::PaystackError,
+    pagination::Paginator,
+    response::PaystackResponse,
+    utils::{make_get_request, make_request, HttpClient},
+};
 use chrono::{DateTime, Local};
-use reqwest::blocking::Response;
+#[cfg(feature = "blocking")]
+use reqwest::Method;
 use serde::Serialize;
 use serde_json::Value as JSON;
+#[cfg(feature = "blocking")]
+use std::sync::Arc;
 
-use crate::{
-    prelude::Currency,
-    utils::{make_get_request, make_request, REQUEST},
-};
-
-const TRANSFER_RECIPIENT_URL: &str = "https://api.paystack.co/transferrecipient";
+pub(crate) const TRANSFER_RECIPIENT_PATH: &str = "/transferrecipient";
+#[cfg(feature = "blocking")]
 /// The Transfer Recipients API allows you create and manage beneficiaries that you send money to
 ///  
 /// ```text
 ///  - 💡 Feature Availability
 /// This feature is only available to businesses in Nigeria and Ghana.
 /// ```
-#[derive(Debug, Default)]
+#[derive(Debug, Clone)]
 pub struct TransferRecipients {
-    pub(crate) bearer_auth: String,
+    pub(crate) http: Arc<HttpClient>,
 }
 
 #[derive(Debug, Serialize)]
@@ -49,7 +56,7 @@ pub struct BulkCreateTransferRecipient {
     pub batch: Vec<JSON>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct ListTransferRecipientsParams {
     /// Specify how many records you want to retrieve per page. If not specify we use a default value of 50.
     #[serde(rename = "perPage")]
@@ -71,46 +78,57 @@ pub struct UpdateTransferRecipient<'a> {
     /// A description for this plan
     pub description: &'a str,
 }
+#[cfg(feature = "blocking")]
 impl TransferRecipients {
     /// Creates a new recipient. A duplicate account number will lead to the retrieval of the existing record.
     pub fn create_transfer_recipient(
         &self,
         body: CreateTransferRecipientBody,
-    ) -> Result<Response, String> {
-        let res = make_request(
-            &self.bearer_auth,
-            TRANSFER_RECIPIENT_URL,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_request(
+            &self.http,
+            TRANSFER_RECIPIENT_PATH,
             Some(body),
-            REQUEST::POST,
-        );
-        return res;
+            Method::POST,
+        )
     }
 
     ///Create multiple transfer recipients in batches. A duplicate account number will lead to the retrieval of the existing record.
     pub fn bulk_create_transfer_recipient(
         &self,
         body: BulkCreateTransferRecipient,
-    ) -> Result<Response, String> {
-        let url = format!("{}/bulk", TRANSFER_RECIPIENT_URL);
-        let res = make_request(&self.bearer_auth, &url, Some(body), REQUEST::POST);
-        return res;
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/bulk", TRANSFER_RECIPIENT_PATH);
+        make_request(&self.http, &path, Some(body), Method::POST)
     }
 
     /// List transfer recipients available on your integration
     pub fn list_transfer_recipients(
         &self,
         params: ListTransferRecipientsParams,
-    ) -> Result<Response, String> {
-        let res = make_get_request(&self.bearer_auth, TRANSFER_RECIPIENT_URL, Some(params));
-        return res;
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_get_request(&self.http, TRANSFER_RECIPIENT_PATH, Some(params))
+    }
+
+    /// Iterate over every transfer recipient, fetching further pages as they are needed
+    pub fn list_transfer_recipients_iter(
+        &self,
+        params: ListTransferRecipientsParams,
+    ) -> Paginator<'static, JSON, ListTransferRecipientsParams> {
+        let http = self.http.clone();
+        Paginator::new(params, move |params| {
+            make_get_request(&http, TRANSFER_RECIPIENT_PATH, Some(params))
+        })
     }
 
     /// Fetch the details of a transfer recipient
     ///  - id_or_code: An ID or code for the recipient whose details you want to receive.
-    pub fn fetch_transfer_recipient(&self, id_or_code: &str) -> Result<Response, String> {
-        let url = format!("{}/{}", TRANSFER_RECIPIENT_URL, id_or_code);
-        let res = make_get_request(&self.bearer_auth, &url, None::<String>);
-        return res;
+    pub fn fetch_transfer_recipient(
+        &self,
+        id_or_code: &str,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", TRANSFER_RECIPIENT_PATH, id_or_code);
+        make_get_request(&self.http, &path, None::<String>)
     }
 
     /// Update an existing recipient. An duplicate account number will lead to the retrieval of the existing record.
@@ -118,16 +136,17 @@ impl TransferRecipients {
         &self,
         body: UpdateTransferRecipient,
         id_or_code: &str,
-    ) -> Result<Response, String> {
-        let url = format!("{}/{}", TRANSFER_RECIPIENT_URL, id_or_code);
-        let res = make_request(&self.bearer_auth, &url, Some(body), REQUEST::PUT);
-        return res;
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", TRANSFER_RECIPIENT_PATH, id_or_code);
+        make_request(&self.http, &path, Some(body), Method::PUT)
     }
 
     /// Deletes a transfer recipient (sets the transfer recipient to inactive)
-    pub fn delete_transfer_recipient(&self, id_or_code: &str) -> Result<Response, String> {
-        let url = format!("{}/{}", TRANSFER_RECIPIENT_URL, id_or_code);
-        let res = make_request(&self.bearer_auth, &url, None::<String>, REQUEST::DELETE);
-        return res;
+    pub fn delete_transfer_recipient(
+        &self,
+        id_or_code: &str,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", TRANSFER_RECIPIENT_PATH, id_or_code);
+        make_request(&self.http, &path, None::<String>, Method::DELETE)
     }
 }
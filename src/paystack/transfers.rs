@@ -1,39 +1,82 @@
-use chrono::{DateTime, Local};
-use reqwest::blocking::Response;
-use serde::Serialize;
-use serde_json::Value as JSON;
-
+use crate::currency::Currency;
+use crate::error::PaystackError;
+use crate::money::{amount_with_currency, Money};
+use crate::response::timestamp;
+use crate::utils::REDACTED;
+use crate::validation;
+#[cfg(feature = "blocking")]
 use crate::{
-    prelude::Currency,
-    utils::{make_get_request, make_request, REQUEST},
+    pagination::Paginator,
+    response::PaystackResponse,
+    utils::{make_get_request, make_idempotent_request, make_request, HttpClient},
 };
+use chrono::{DateTime, Local, Utc};
+#[cfg(feature = "blocking")]
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JSON;
+use std::fmt;
+#[cfg(feature = "blocking")]
+use std::sync::Arc;
 
-const TRANSFERS_URL: &str = "https://api.paystack.co/transfer";
+pub(crate) const TRANSFERS_PATH: &str = "/transfer";
+#[cfg(feature = "blocking")]
 /// The Transfers API allows you automate sending money on your integration
 /// - 💡 Feature Availability
-/// This feature is only available to businesses in Nigeria and Ghana.
-#[derive(Debug, Default)]
+///   This feature is only available to businesses in Nigeria and Ghana.
+#[derive(Debug, Clone)]
 pub struct Transfers {
-    pub(crate) bearer_auth: String,
+    pub(crate) http: Arc<HttpClient>,
 }
 
 #[derive(Debug, Serialize)]
 pub struct InitiateTransferBody<'a> {
     /// Where should we transfer from. Only `balance` for now
     pub source: &'a str,
-    /// Amount to transfer in kobo if currency is NGN and pesewas if currency is GHS.
-    pub amount: i64,
+    /// Amount and currency of the transfer, sent as `amount` in minor units and `currency`
+    #[serde(flatten, serialize_with = "amount_with_currency")]
+    pub amount: Money,
     /// Code for transfer recipient
     pub recipient: &'a str,
     /// The reason for the transfer
     pub reason: Option<&'a str>,
-    /// Specify the currency of the transfer. Defaults to NGN
-    pub currency: Option<Currency>,
     /// If specified, the field should be a unique identifier (in lowercase) for the object.
     /// Only -,_ and alphanumeric characters allowed.
+    /// Setting it lets the client safely retry the request if Paystack fails to respond.
     pub reference: Option<&'a str>,
 }
 
+impl InitiateTransferBody<'_> {
+    /// Check the amount and reference before they are sent. `initiate_transfers` runs this
+    /// first, and returns its error without calling Paystack.
+    /// ```rust
+    /// use paystack_rs::prelude::{transfers::InitiateTransferBody, Currency, Money};
+    ///
+    /// let mut body = InitiateTransferBody {
+    ///     source: "balance",
+    ///     amount: Money::from_minor(370_000, Currency::NGN),
+    ///     recipient: "RCP_gx2wn530m0i3w3m",
+    ///     reason: Some("Holiday flexing"),
+    ///     reference: Some("acv_9ee55786-2323-4760-98e2-6380c9cb3f68"),
+    /// };
+    /// assert!(body.validate().is_ok());
+    ///
+    /// body.reference = Some("ACV_9EE55786");
+    /// assert!(body.validate().is_err());
+    ///
+    /// body.reference = None;
+    /// body.amount = Money::from_minor(0, Currency::NGN);
+    /// assert!(body.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), PaystackError> {
+        validation::amount("amount", &self.amount)?;
+        if let Some(reference) = self.reference {
+            validation::transfer_reference("reference", reference)?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct InitiateBulkTransferBody<'a> {
     /// The transfer code you want to finalize
@@ -42,7 +85,26 @@ pub struct InitiateBulkTransferBody<'a> {
     pub transfers: Vec<JSON>,
 }
 
-#[derive(Debug, Serialize)]
+impl InitiateBulkTransferBody<'_> {
+    /// Check the amount and reference of each transfer before they are sent
+    pub fn validate(&self) -> Result<(), PaystackError> {
+        for (i, transfer) in self.transfers.iter().enumerate() {
+            let amount = transfer["amount"].as_i64().unwrap_or(0);
+            if amount <= 0 {
+                return Err(PaystackError::validation(format!(
+                    "transfers[{}].amount must be a positive number of minor units, got {}",
+                    i, transfer["amount"]
+                )));
+            }
+            if let Some(reference) = transfer["reference"].as_str() {
+                validation::transfer_reference(&format!("transfers[{}].reference", i), reference)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Serialize)]
 pub struct FinalizeTransferBody<'a> {
     /// The transfer code you want to finalize
     pub transfer_code: &'a str,
@@ -50,7 +112,16 @@ pub struct FinalizeTransferBody<'a> {
     pub otp: &'a str,
 }
 
-#[derive(Debug, Serialize)]
+impl fmt::Debug for FinalizeTransferBody<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FinalizeTransferBody")
+            .field("transfer_code", &self.transfer_code)
+            .field("otp", &REDACTED)
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
 pub struct ListTransfersParams<'a> {
     /// Specify how many records you want to retrieve per page. If not specify we use a default value of 50.
     #[serde(rename = "perPage")]
@@ -65,48 +136,135 @@ pub struct ListTransfersParams<'a> {
     pub to: Option<DateTime<Local>>,
 }
 
+/// A transfer, as sent in the `transfer.success`, `transfer.failed` and `transfer.reversed` webhooks
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TransferData {
+    /// Paystack's ID for the transfer
+    pub id: i64,
+    /// `test` or `live`
+    pub domain: String,
+    /// e.g. `pending`, `otp`, `success`, `failed` or `reversed`
+    pub status: String,
+    /// The transfer's code, e.g. `TRF_wpl1dem4967avzm`
+    pub transfer_code: String,
+    /// Reference of the transfer, as given when it was initiated or generated by Paystack
+    pub reference: String,
+    /// Amount in the minor unit of `currency`
+    pub amount: i64,
+    /// Currency the recipient was paid in
+    pub currency: Currency,
+    /// Where the money came from, usually `balance`
+    pub source: String,
+    /// The reason given when the transfer was initiated
+    pub reason: Option<String>,
+    /// Fee Paystack charged for the transfer, in the minor unit of `currency`
+    pub fee_charged: Option<i64>,
+    /// Why the transfer failed, if it did
+    pub failures: Option<JSON>,
+    /// What the bank said about the transfer
+    pub gateway_response: Option<String>,
+    /// The account the money was sent to
+    pub recipient: Option<JSON>,
+    /// The transfer session at the receiving bank, e.g. its NIP session ID
+    pub session: Option<JSON>,
+    /// The integration the transfer was made from
+    pub integration: Option<JSON>,
+    /// When the money reached the recipient
+    #[serde(serialize_with = "timestamp")]
+    pub transferred_at: Option<DateTime<Utc>>,
+    /// When the transfer was initiated
+    #[serde(alias = "createdAt")]
+    #[serde(serialize_with = "timestamp")]
+    pub created_at: Option<DateTime<Utc>>,
+    /// When the transfer last changed
+    #[serde(alias = "updatedAt")]
+    #[serde(serialize_with = "timestamp")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[cfg(feature = "blocking")]
 impl Transfers {
     /// Status of transfer object returned will be `pending` if OTP is disabled.
     /// In the event that an OTP is required, status will read `otp`.
-    pub fn initiate_transfers(&self, body: InitiateTransferBody) -> Result<Response, String> {
-        let res = make_request(&self.bearer_auth, TRANSFERS_URL, Some(body), REQUEST::POST);
-        return res;
+    pub fn initiate_transfers(
+        &self,
+        body: InitiateTransferBody,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        body.validate()?;
+        let idempotent = body.reference.is_some();
+        make_idempotent_request(
+            &self.http,
+            TRANSFERS_PATH,
+            Some(body),
+            Method::POST,
+            idempotent,
+        )
     }
 
     /// Finalize an initiated transfer
-    pub fn finalize_transfer(&self, body: FinalizeTransferBody) -> Result<Response, String> {
-        let url = format!("{}/finalize_transfer", TRANSFERS_URL);
-        let res = make_request(&self.bearer_auth, &url, Some(body), REQUEST::POST);
-        return res;
+    pub fn finalize_transfer(
+        &self,
+        body: FinalizeTransferBody,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/finalize_transfer", TRANSFERS_PATH);
+        make_request(&self.http, &path, Some(body), Method::POST)
     }
 
     /// You need to disable the Transfers OTP requirement to use this endpoint.
     pub fn initiate_bulk_transfer(
         &self,
         body: InitiateBulkTransferBody,
-    ) -> Result<Response, String> {
-        let url = format!("{}/bulk", TRANSFERS_URL);
-        let res = make_request(&self.bearer_auth, &url, Some(body), REQUEST::POST);
-        return res;
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        body.validate()?;
+        let path = format!("{}/bulk", TRANSFERS_PATH);
+        make_request(&self.http, &path, Some(body), Method::POST)
     }
 
     /// List the transfers made on your integration.
-    pub fn list_transfers(&self, params: ListTransfersParams) -> Result<Response, String> {
-        let res = make_get_request(&self.bearer_auth, TRANSFERS_URL, Some(params));
-        return res;
+    pub fn list_transfers(
+        &self,
+        params: ListTransfersParams,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_get_request(&self.http, TRANSFERS_PATH, Some(params))
+    }
+
+    /// Iterate over every transfer, fetching further pages as they are needed
+    pub fn list_transfers_iter<'a>(
+        &self,
+        params: ListTransfersParams<'a>,
+    ) -> Paginator<'a, JSON, ListTransfersParams<'a>> {
+        let http = self.http.clone();
+        Paginator::new(params, move |params| {
+            make_get_request(&http, TRANSFERS_PATH, Some(params))
+        })
     }
 
     /// Get details of a transfer on your integration.
-    pub fn fetch_transfer(&self, id_or_code: &str) -> Result<Response, String> {
-        let url = format!("{}/{}", TRANSFERS_URL, id_or_code);
-        let res = make_get_request(&self.bearer_auth, &url, None::<String>);
-        return res;
+    pub fn fetch_transfer(
+        &self,
+        id_or_code: &str,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        validation::lookup("id_or_code", id_or_code)?;
+        let path = format!("{}/{}", TRANSFERS_PATH, id_or_code);
+        make_get_request(&self.http, &path, None::<String>)
     }
 
     /// Verify the status of a transfer on your integration.
-    pub fn verify_transfer(&self, reference: &str) -> Result<Response, String> {
-        let url = format!("{}/verify/{}", TRANSFERS_URL, reference);
-        let res = make_get_request(&self.bearer_auth, &url, None::<String>);
-        return res;
+    /// ```rust
+    /// use paystack_rs::prelude::{Paystack, PaystackError};
+    ///
+    /// let paystack = Paystack::new("sk_test_xxxx".to_string());
+    /// // Rejected before any request is made
+    /// let err = paystack.transfers.verify_transfer("../balance").unwrap_err();
+    /// assert!(matches!(err, PaystackError::Validation(_)));
+    /// ```
+    pub fn verify_transfer(
+        &self,
+        reference: &str,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        validation::lookup("reference", reference)?;
+        let path = format!("{}/verify/{}", TRANSFERS_PATH, reference);
+        make_get_request(&self.http, &path, None::<String>)
     }
 }
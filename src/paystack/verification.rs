@@ -1,15 +1,26 @@
-use crate::utils::{make_get_request, make_request, REQUEST};
-use reqwest::blocking::Response;
+#[cfg(feature = "blocking")]
+use crate::{
+    error::PaystackError,
+    response::PaystackResponse,
+    utils::{make_get_request, make_request, HttpClient},
+};
+#[cfg(feature = "blocking")]
+use reqwest::Method;
 use serde::Serialize;
+#[cfg(feature = "blocking")]
+use serde_json::Value as JSON;
+#[cfg(feature = "blocking")]
+use std::sync::Arc;
 
+#[cfg(feature = "blocking")]
 /// The Verification API allows you perform KYC processes.
 ///
 /// *NB: due to regulations, Paystack has disabled this service.*
 ///  - 💡 Feature Availability
-/// This feature is only available to businesses in Nigeria.
-#[derive(Debug, Default)]
+///    This feature is only available to businesses in Nigeria.
+#[derive(Debug, Clone)]
 pub struct Verification {
-    pub(crate) bearer_auth: String,
+    pub(crate) http: Arc<HttpClient>,
 }
 
 #[derive(Debug, Serialize)]
@@ -37,32 +48,31 @@ pub struct ResolveAcctNoBody<'a> {
     /// You can get the [][list of banks] codes by calling the List Bank endpoint
     pub bank_code: i64,
 }
-const VERIFY_BVN_MATCH_URL: &str = "https://api.paystack.co/bvn/match";
-const RESOLVE_ACCT_NO_URL: &str = "https://api.paystack.co/bank/resolve";
-const RESOLVE_CARD_BIN: &str = "https://api.paystack.co/decision/bin";
+pub(crate) const VERIFY_BVN_MATCH_PATH: &str = "/bvn/match";
+pub(crate) const RESOLVE_ACCT_NO_PATH: &str = "/bank/resolve";
+pub(crate) const RESOLVE_CARD_BIN_PATH: &str = "/decision/bin";
 
+#[cfg(feature = "blocking")]
 impl Verification {
     /// Check if an account number and BVN are linked
-    pub fn verify_bvn_match(&self, body: VerifyBVNBody) -> Result<Response, String> {
-        let res = make_request(
-            &self.bearer_auth,
-            VERIFY_BVN_MATCH_URL,
-            Some(body),
-            REQUEST::POST,
-        );
-        return res;
+    pub fn verify_bvn_match(
+        &self,
+        body: VerifyBVNBody,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_request(&self.http, VERIFY_BVN_MATCH_PATH, Some(body), Method::POST)
     }
 
     /// Confirm an account belongs to the right customer
-    pub fn resolve_account_number(&self, params: ResolveAcctNoBody) -> Result<Response, String> {
-        let res = make_get_request(&self.bearer_auth, RESOLVE_ACCT_NO_URL, Some(params));
-        return res;
+    pub fn resolve_account_number(
+        &self,
+        params: ResolveAcctNoBody,
+    ) -> Result<PaystackResponse<JSON>, PaystackError> {
+        make_get_request(&self.http, RESOLVE_ACCT_NO_PATH, Some(params))
     }
 
     /// Get more information about a customer's card
-    pub fn resolve_card_bin(&self, bin: &str) -> Result<Response, String> {
-        let url = format!("{}/{}", RESOLVE_CARD_BIN, bin);
-        let res = make_get_request(&self.bearer_auth, &url, None::<String>);
-        return res;
+    pub fn resolve_card_bin(&self, bin: &str) -> Result<PaystackResponse<JSON>, PaystackError> {
+        let path = format!("{}/{}", RESOLVE_CARD_BIN_PATH, bin);
+        make_get_request(&self.http, &path, None::<String>)
     }
 }
@@ -0,0 +1,160 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+/// A client-side token bucket limiter that keeps a client below Paystack's rate limits.
+///
+/// Endpoints are grouped by the first segment of their path, e.g. `transaction` for
+/// `/transaction/verify/:reference` or `transfer` for `/transfer/:id`. A group can get a budget
+/// of its own; every other group draws from the shared default budget. A request that is over
+/// budget waits for a token: the blocking client sleeps, the async client yields to the runtime.
+///
+/// Clones share their buckets, so one limiter can keep several clients within a single budget.
+/// ```rust
+/// use paystack_rs::prelude::{PaystackBuilder, RateLimiter};
+/// use std::time::Duration;
+///
+/// let limiter = RateLimiter::new(50, Duration::from_secs(1))
+///     .group("transaction", 20, Duration::from_secs(1))
+///     .group("transfer", 5, Duration::from_secs(1));
+/// let paystack = PaystackBuilder::new("sk_test_xxxx".to_string())
+///     .rate_limiter(limiter)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    default: Arc<Mutex<Bucket>>,
+    groups: HashMap<String, Arc<Mutex<Bucket>>>,
+}
+
+impl RateLimiter {
+    /// Allow `requests` requests every `per`, in bursts of up to `requests`, across all groups
+    /// without a budget of their own
+    pub fn new(requests: u32, per: Duration) -> RateLimiter {
+        RateLimiter {
+            default: Arc::new(Mutex::new(Bucket::new(requests, per))),
+            groups: HashMap::new(),
+        }
+    }
+
+    /// Give an endpoint group, e.g. `transaction`, its own budget of `requests` requests every `per`
+    pub fn group(mut self, group: &str, requests: u32, per: Duration) -> RateLimiter {
+        self.groups.insert(
+            group.trim_matches('/').to_string(),
+            Arc::new(Mutex::new(Bucket::new(requests, per))),
+        );
+        self
+    }
+
+    /// Take a token for a request to `path` and return how long the caller must wait before
+    /// sending it. Tokens are reserved in order, so concurrent callers queue up fairly.
+    pub(crate) fn reserve(&self, path: &str) -> Duration {
+        let group = path.trim_start_matches('/').split('/').next().unwrap_or("");
+        let bucket = self.groups.get(group).unwrap_or(&self.default);
+        // A panic while holding the lock can't leave the bucket half-updated, so keep using it
+        let mut bucket = bucket
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        bucket.reserve(Instant::now())
+    }
+}
+
+#[derive(Debug)]
+struct Bucket {
+    capacity: f64,
+    /// Tokens added per second
+    rate: f64,
+    /// Available tokens; negative when callers are already waiting for tokens to be refilled
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl Bucket {
+    fn new(requests: u32, per: Duration) -> Bucket {
+        let capacity = f64::from(requests.max(1));
+        Bucket {
+            capacity,
+            rate: capacity / per.as_secs_f64().max(f64::EPSILON),
+            tokens: capacity,
+            refilled_at: Instant::now(),
+        }
+    }
+
+    fn reserve(&mut self, now: Instant) -> Duration {
+        let elapsed = now
+            .saturating_duration_since(self.refilled_at)
+            .as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.capacity);
+        self.refilled_at = now;
+
+        self.tokens -= 1.0;
+        if self.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-self.tokens / self.rate)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bucket_waits_once_empty_and_refills_over_time() {
+        let start = Instant::now();
+        let mut bucket = Bucket::new(2, Duration::from_secs(1));
+        bucket.refilled_at = start;
+
+        // A burst of `requests` goes through at once
+        assert_eq!(bucket.reserve(start), Duration::ZERO);
+        assert_eq!(bucket.reserve(start), Duration::ZERO);
+        // Then each request waits for its token, queued behind the ones already waiting
+        assert_eq!(bucket.reserve(start), Duration::from_millis(500));
+        assert_eq!(bucket.reserve(start), Duration::from_secs(1));
+
+        // Once the queue has drained, a token refills every 500ms
+        let later = start + Duration::from_millis(1500);
+        assert_eq!(bucket.reserve(later), Duration::ZERO);
+        assert_eq!(bucket.reserve(later), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn bucket_never_holds_more_than_its_capacity() {
+        let start = Instant::now();
+        let mut bucket = Bucket::new(1, Duration::from_secs(1));
+        bucket.refilled_at = start;
+
+        let much_later = start + Duration::from_secs(60);
+        assert_eq!(bucket.reserve(much_later), Duration::ZERO);
+        assert_eq!(bucket.reserve(much_later), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn groups_have_separate_buckets() {
+        let hour = Duration::from_secs(3600);
+        let limiter = RateLimiter::new(1, hour).group("transfer", 1, hour);
+
+        assert_eq!(
+            limiter.reserve("/transaction/verify/T685312322670591"),
+            Duration::ZERO
+        );
+        assert!(limiter.reserve("/transaction/totals") > Duration::ZERO);
+        // `transfer` has a budget of its own, untouched by the transactions
+        assert_eq!(limiter.reserve("/transfer/TRF_1"), Duration::ZERO);
+        assert!(limiter.reserve("/transfer") > Duration::ZERO);
+        // Groups without one share the default bucket
+        assert!(limiter.reserve("/customer") > Duration::ZERO);
+    }
+
+    #[test]
+    fn clones_share_their_buckets() {
+        let limiter = RateLimiter::new(1, Duration::from_secs(3600));
+        let clone = limiter.clone();
+        assert_eq!(limiter.reserve("/plan"), Duration::ZERO);
+        assert!(clone.reserve("/plan") > Duration::ZERO);
+    }
+}
@@ -0,0 +1,109 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::{de, Deserialize, Deserializer, Serializer};
+use serde_json::Value as JSON;
+
+/// The envelope Paystack wraps every successful response in, with `data` already deserialized
+/// ```no_run
+/// use paystack_rs::prelude::{ListTransactionsParams, Paystack, PaystackResponse, TransactionData};
+///
+/// let paystack = Paystack::new("sk_test_xxxx".to_string());
+/// let params = ListTransactionsParams::default();
+/// let res: Result<PaystackResponse<Vec<TransactionData>>, _> =
+///     paystack.transaction.list_transactions(params);
+/// if let Ok(res) = res {
+///     let page_count = res.meta.and_then(|meta| meta.page_count);
+/// }
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+pub struct PaystackResponse<T> {
+    /// Whether the request succeeded. Always `true`, since failures are returned as errors.
+    pub status: bool,
+    /// Human readable summary of the result, e.g. `Authorization URL created`
+    pub message: String,
+    /// The payload. `null` for endpoints that only confirm an action.
+    pub data: T,
+    /// Pagination details, sent by `list_*` endpoints
+    pub meta: Option<Meta>,
+}
+
+/// Pagination details of a list response. Page based endpoints fill in the counts, cursor based
+/// ones such as `list_banks` fill in `next` and `previous`.
+/// ```rust
+/// use paystack_rs::prelude::Meta;
+///
+/// let meta: Meta =
+///     serde_json::from_str(r#"{"total": 120, "perPage": "50", "page": 1, "pageCount": 3}"#).unwrap();
+/// assert_eq!(meta.per_page, Some(50));
+/// assert_eq!(meta.next, None);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct Meta {
+    /// Number of records across all pages
+    #[serde(default, deserialize_with = "lenient_u64")]
+    pub total: Option<u64>,
+    /// Number of records before this page
+    #[serde(default, deserialize_with = "lenient_u64")]
+    pub skipped: Option<u64>,
+    /// Number of records per page
+    #[serde(rename = "perPage", default, deserialize_with = "lenient_u64")]
+    pub per_page: Option<u64>,
+    /// The number of this page, starting from 1
+    #[serde(default, deserialize_with = "lenient_u64")]
+    pub page: Option<u64>,
+    /// Number of pages
+    #[serde(rename = "pageCount", default, deserialize_with = "lenient_u64")]
+    pub page_count: Option<u64>,
+    /// Cursor of the next page, if there is one
+    pub next: Option<String>,
+    /// Cursor of the previous page, if there is one
+    pub previous: Option<String>,
+}
+
+/// Paystack sends some counts as strings, e.g. `"perPage": "50"`
+pub(crate) fn lenient_u64<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<JSON>::deserialize(deserializer)? {
+        None | Some(JSON::Null) => Ok(None),
+        Some(JSON::Number(number)) => number
+            .as_u64()
+            .map(Some)
+            .ok_or_else(|| de::Error::custom(format!("invalid count {}", number))),
+        Some(JSON::String(string)) => string
+            .parse()
+            .map(Some)
+            .map_err(|_| de::Error::custom(format!("invalid count {:?}", string))),
+        Some(other) => Err(de::Error::custom(format!("invalid count {}", other))),
+    }
+}
+
+/// Paystack sends some amounts as strings, e.g. `"amount": "10000"` in refund webhooks
+pub(crate) fn lenient_i64<'de, D>(deserializer: D) -> Result<Option<i64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<JSON>::deserialize(deserializer)? {
+        None | Some(JSON::Null) => Ok(None),
+        Some(JSON::Number(number)) => number
+            .as_i64()
+            .map(Some)
+            .ok_or_else(|| de::Error::custom(format!("invalid amount {}", number))),
+        Some(JSON::String(string)) => string
+            .parse()
+            .map(Some)
+            .map_err(|_| de::Error::custom(format!("invalid amount {:?}", string))),
+        Some(other) => Err(de::Error::custom(format!("invalid amount {}", other))),
+    }
+}
+
+/// Write a timestamp the way Paystack does, with milliseconds, e.g. `2024-08-22T09:15:02.000Z`
+pub(crate) fn timestamp<S>(time: &Option<DateTime<Utc>>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match time {
+        Some(time) => serializer.serialize_str(&time.to_rfc3339_opts(SecondsFormat::Millis, true)),
+        None => serializer.serialize_none(),
+    }
+}
@@ -0,0 +1,329 @@
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use reqwest::{
+    header::{HeaderMap, RETRY_AFTER},
+    StatusCode,
+};
+use std::time::Duration;
+
+/// How a client retries requests that Paystack rate-limited (`429`) or failed to serve (`5xx`),
+/// and requests that never reached Paystack because the connection failed or timed out.
+///
+/// GET requests are always safe to retry. POST requests are only retried when the caller supplied
+/// a `reference`, since Paystack refuses a second transaction with the same reference instead of
+/// charging twice.
+/// ```rust
+/// use paystack_rs::prelude::{PaystackBuilder, RetryPolicy};
+/// use std::time::Duration;
+///
+/// let paystack = PaystackBuilder::new("sk_test_xxxx".to_string())
+///     .retry_policy(
+///         RetryPolicy::default()
+///             .max_attempts(5)
+///             .base_delay(Duration::from_millis(200)),
+///     )
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    /// Up to 3 attempts, waiting about 500ms and then about 1s, never more than 30s
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            jitter: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Send every request exactly once
+    pub fn none() -> RetryPolicy {
+        RetryPolicy::default().max_attempts(1)
+    }
+
+    /// How many times a request is sent at most, counting the first attempt. `0` is treated as `1`.
+    pub fn max_attempts(mut self, max_attempts: u32) -> RetryPolicy {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// How long to wait before the first retry. The wait doubles after every further attempt.
+    pub fn base_delay(mut self, base_delay: Duration) -> RetryPolicy {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// The longest a single wait may last, including one asked for through `Retry-After`
+    pub fn max_delay(mut self, max_delay: Duration) -> RetryPolicy {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Randomize each backoff wait between half and all of its length, so that clients which
+    /// failed together don't retry together. On by default.
+    pub fn jitter(mut self, jitter: bool) -> RetryPolicy {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Whether a request that has been sent `attempt` times may be sent again
+    pub(crate) fn can_retry(&self, attempt: u32) -> bool {
+        attempt < self.max_attempts
+    }
+
+    /// Whether a response with this status is worth retrying
+    pub(crate) fn retries_status(&self, status: StatusCode) -> bool {
+        status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+    }
+
+    /// Whether a request that failed with this error is worth retrying
+    #[cfg(any(feature = "blocking", feature = "async"))]
+    pub(crate) fn retries_error(&self, err: &reqwest::Error) -> bool {
+        err.is_connect() || err.is_timeout()
+    }
+
+    /// How long to wait after the given attempt failed. `Retry-After`, when the response has one,
+    /// wins over the backoff.
+    pub(crate) fn delay(&self, attempt: u32, headers: Option<&HeaderMap>) -> Duration {
+        if let Some(retry_after) = headers.and_then(retry_after) {
+            return retry_after.min(self.max_delay);
+        }
+
+        let exponent = attempt.saturating_sub(1).min(31);
+        let backoff = self
+            .base_delay
+            .checked_mul(1 << exponent)
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay);
+        if self.jitter {
+            let half = backoff / 2;
+            half + half.mul_f64(rand::thread_rng().gen::<f64>())
+        } else {
+            backoff
+        }
+    }
+}
+
+/// Read a `Retry-After` header given either as seconds or as an HTTP date
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let at = DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        at.with_timezone(&Utc)
+            .signed_duration_since(Utc::now())
+            .to_std()
+            .unwrap_or_default(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy::default()
+            .base_delay(Duration::from_millis(100))
+            .max_delay(Duration::from_secs(1))
+            .jitter(false)
+    }
+
+    fn retry_after_header(value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_str(value).unwrap());
+        headers
+    }
+
+    #[test]
+    fn backoff_doubles_until_max_delay() {
+        let policy = policy();
+        let delays: Vec<_> = (1..=6).map(|attempt| policy.delay(attempt, None)).collect();
+        let millis = |ms| Duration::from_millis(ms);
+        assert_eq!(
+            delays,
+            [
+                millis(100),
+                millis(200),
+                millis(400),
+                millis(800),
+                millis(1000),
+                millis(1000)
+            ]
+        );
+        assert_eq!(policy.delay(u32::MAX, None), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn jitter_keeps_between_half_and_all_of_the_backoff() {
+        let policy = policy().jitter(true);
+        for _ in 0..100 {
+            let delay = policy.delay(3, None);
+            assert!(delay >= Duration::from_millis(200) && delay <= Duration::from_millis(400));
+        }
+    }
+
+    #[test]
+    fn retry_after_seconds_wins_over_backoff() {
+        let policy = policy().max_delay(Duration::from_secs(30));
+        let headers = retry_after_header("7");
+        assert_eq!(policy.delay(1, Some(&headers)), Duration::from_secs(7));
+        // Still capped, so a misbehaving server can't stall the client
+        let headers = retry_after_header("120");
+        assert_eq!(policy.delay(1, Some(&headers)), Duration::from_secs(30));
+    }
+
+    #[test]
+    fn retry_after_http_date() {
+        let at = Utc::now() + chrono::Duration::seconds(10);
+        let headers = retry_after_header(&at.format("%a, %d %b %Y %H:%M:%S GMT").to_string());
+        let delay = retry_after(&headers).unwrap();
+        assert!(delay > Duration::from_secs(8) && delay <= Duration::from_secs(10));
+
+        let past = retry_after_header("Wed, 21 Oct 2015 07:28:00 GMT");
+        assert_eq!(retry_after(&past), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn unreadable_retry_after_falls_back_to_backoff() {
+        let headers = retry_after_header("soon");
+        assert_eq!(
+            policy().delay(2, Some(&headers)),
+            Duration::from_millis(200)
+        );
+    }
+
+    #[test]
+    fn retries_rate_limits_and_server_errors_only() {
+        let policy = RetryPolicy::default();
+        assert!(policy.retries_status(StatusCode::TOO_MANY_REQUESTS));
+        assert!(policy.retries_status(StatusCode::SERVICE_UNAVAILABLE));
+        assert!(!policy.retries_status(StatusCode::BAD_REQUEST));
+        assert!(!policy.retries_status(StatusCode::UNAUTHORIZED));
+        assert!(policy.can_retry(2));
+        assert!(!policy.can_retry(3));
+        assert!(!RetryPolicy::none().can_retry(1));
+    }
+
+    #[cfg(feature = "blocking")]
+    mod client {
+        use crate::prelude::{
+            ChargeAuthorizationBody, CheckAuthorizationBody, Currency, Money, Paystack,
+            PaystackBuilder, RetryPolicy,
+        };
+        use reqwest::StatusCode;
+        use std::{
+            io::{BufRead, BufReader, Read, Write},
+            net::TcpListener,
+            sync::{
+                atomic::{AtomicUsize, Ordering},
+                Arc,
+            },
+            thread,
+            time::Duration,
+        };
+
+        const VERIFIED: &str = r#"{"status":true,"message":"Verification successful","data":{"reference":"T685312322670591"}}"#;
+
+        /// Answer each request with the next status and body, counting the requests received
+        fn serve(responses: Vec<(u16, &'static str)>) -> (String, Arc<AtomicUsize>) {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            let hits = Arc::new(AtomicUsize::new(0));
+            let counter = hits.clone();
+            thread::spawn(move || {
+                for (status, body) in responses {
+                    let (stream, _) = listener.accept().unwrap();
+                    let mut reader = BufReader::new(stream);
+                    let mut length = 0;
+                    loop {
+                        let mut line = String::new();
+                        reader.read_line(&mut line).unwrap();
+                        if let Some(value) = line.to_lowercase().strip_prefix("content-length:") {
+                            length = value.trim().parse().unwrap();
+                        }
+                        if line == "\r\n" {
+                            break;
+                        }
+                    }
+                    reader.read_exact(&mut vec![0; length]).unwrap();
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    let response = format!(
+                        "HTTP/1.1 {} X\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                        status,
+                        body.len(),
+                        body
+                    );
+                    reader.get_mut().write_all(response.as_bytes()).unwrap();
+                }
+            });
+            (url, hits)
+        }
+
+        fn client(url: &str) -> Paystack {
+            PaystackBuilder::new("sk_test_xxxx".to_string())
+                .base_url(url)
+                .retry_policy(
+                    RetryPolicy::default()
+                        .base_delay(Duration::from_millis(1))
+                        .jitter(false),
+                )
+                .build()
+                .unwrap()
+        }
+
+        #[test]
+        fn get_is_retried_after_a_server_error() {
+            let (url, hits) = serve(vec![(503, "{}"), (200, VERIFIED)]);
+            let res = client(&url)
+                .transaction
+                .verify_transaction("T685312322670591".to_string())
+                .unwrap();
+            assert_eq!(res.data.reference, "T685312322670591");
+            assert_eq!(hits.load(Ordering::SeqCst), 2);
+        }
+
+        #[test]
+        fn post_without_reference_is_sent_once() {
+            let (url, hits) = serve(vec![(503, "{}"), (200, VERIFIED)]);
+            let body = CheckAuthorizationBody {
+                amount: Money::from_minor(50_000, Currency::NGN),
+                email: "customer@email.com".to_string(),
+                authorization_code: "AUTH_72btv547".to_string(),
+            };
+            let err = client(&url)
+                .transaction
+                .check_authorization(body)
+                .unwrap_err();
+            assert_eq!(err.status(), Some(StatusCode::SERVICE_UNAVAILABLE));
+            assert_eq!(hits.load(Ordering::SeqCst), 1);
+        }
+
+        #[test]
+        fn post_with_reference_is_retried() {
+            let (url, hits) = serve(vec![(503, "{}"), (200, VERIFIED)]);
+            let body = ChargeAuthorizationBody {
+                amount: Money::from_minor(50_000, Currency::NGN),
+                email: "customer@email.com".to_string(),
+                authorization_code: "AUTH_72btv547".to_string(),
+                reference: Some("T685312322670591".to_string()),
+                ..Default::default()
+            };
+            client(&url).transaction.charge_authorization(body).unwrap();
+            assert_eq!(hits.load(Ordering::SeqCst), 2);
+        }
+    }
+}
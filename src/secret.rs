@@ -0,0 +1,69 @@
+use std::{fmt, sync::Arc};
+use zeroize::Zeroizing;
+
+/// A Paystack secret key. It is never shown by `Debug` or `Display`, and its memory is wiped when
+/// the last clone is dropped. Clones share a single copy, so a client, its resources and its
+/// webhook verifier all hold the same one.
+/// ```rust
+/// use paystack_rs::prelude::{KeyMode, SecretKey};
+///
+/// let key = SecretKey::new("sk_test_xxxx".to_string());
+/// assert_eq!(format!("{:?}", key), "SecretKey([redacted])");
+/// assert_eq!(key.expose_secret(), "sk_test_xxxx");
+/// assert_eq!(key.mode(), Some(KeyMode::Test));
+/// ```
+#[derive(Clone)]
+pub struct SecretKey(Arc<Zeroizing<String>>);
+
+impl SecretKey {
+    /// Take ownership of a key, so that no copy of it is left behind
+    pub fn new(key: String) -> SecretKey {
+        SecretKey(Arc::new(Zeroizing::new(key)))
+    }
+
+    /// The key itself. Take care not to log or otherwise copy it.
+    pub fn expose_secret(&self) -> &str {
+        self.0.as_str()
+    }
+
+    /// Whether this is a test or a live key, going by its `sk_test_` or `sk_live_` prefix.
+    /// `None` when it has neither.
+    pub fn mode(&self) -> Option<KeyMode> {
+        if self.0.starts_with("sk_test_") {
+            Some(KeyMode::Test)
+        } else if self.0.starts_with("sk_live_") {
+            Some(KeyMode::Live)
+        } else {
+            None
+        }
+    }
+}
+
+/// Which of an integration's two environments a secret key belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyMode {
+    /// An `sk_test_` key: no real money moves
+    Test,
+    /// An `sk_live_` key: charges and transfers are real
+    Live,
+}
+
+impl From<String> for SecretKey {
+    fn from(key: String) -> Self {
+        SecretKey::new(key)
+    }
+}
+
+impl fmt::Debug for SecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SecretKey")
+            .field(&format_args!("[redacted]"))
+            .finish()
+    }
+}
+
+impl fmt::Display for SecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[redacted]")
+    }
+}
@@ -1,101 +1,299 @@
+#[cfg(feature = "blocking")]
+use crate::builder::Config;
+use crate::{
+    error::{PaystackError, PaystackErrorBody},
+    response::PaystackResponse,
+    secret::SecretKey,
+};
+#[cfg(feature = "blocking")]
 use reqwest::{
-    blocking::{Client, Response},
+    blocking::{RequestBuilder, Response},
     header::{AUTHORIZATION, CONTENT_TYPE},
-    StatusCode,
 };
+#[cfg(any(feature = "blocking", feature = "async"))]
+use reqwest::{header::HeaderMap, Method};
+use reqwest::{header::HeaderValue, StatusCode};
+use serde::de::DeserializeOwned;
+#[cfg(feature = "blocking")]
 use serde::Serialize;
+use serde_json::Value as JSON;
+#[cfg(feature = "blocking")]
 use serde_urlencoded::*;
-use std::fmt::Debug;
+#[cfg(any(feature = "blocking", feature = "async"))]
+use std::time::{Duration, Instant};
+#[cfg(feature = "blocking")]
+use std::{fmt::Debug, thread};
+#[cfg(any(feature = "blocking", feature = "async"))]
+use tracing::Span;
+use zeroize::Zeroizing;
+
+#[cfg(feature = "async")]
+pub(crate) mod asynchronous;
+
+/// Replaces secrets such as PINs and OTPs in `Debug` output
+pub(crate) const REDACTED: &str = "[redacted]";
+
+/// Headers a Paystack response may carry its request id in, checked in order
+#[cfg(any(feature = "blocking", feature = "async"))]
+const REQUEST_ID_HEADERS: [&str; 2] = ["x-paystack-request-id", "x-request-id"];
 
-pub(crate) enum REQUEST {
-    POST,
-    PUT,
-    DELETE,
+/// The connection pool and settings shared by every resource struct of a blocking client.
+/// Cloning the `Arc` around it is all it takes to hand it to another resource or thread.
+#[cfg(feature = "blocking")]
+#[derive(Debug)]
+pub(crate) struct HttpClient {
+    pub(crate) key: SecretKey,
+    pub(crate) config: Config,
+    pub(crate) client: reqwest::blocking::Client,
 }
 
-pub(crate) fn make_get_request<T>(
-    bearer_auth: &str,
-    url: &str,
+#[cfg(feature = "blocking")]
+impl HttpClient {
+    pub(crate) fn new(key: SecretKey, config: Config) -> Result<HttpClient, PaystackError> {
+        let client = config
+            .blocking_client()
+            .map_err(|err| PaystackError::Config(err.to_string()))?;
+        Ok(HttpClient {
+            key,
+            config,
+            client,
+        })
+    }
+}
+
+#[cfg(feature = "blocking")]
+pub(crate) fn make_get_request<T, R>(
+    http: &HttpClient,
+    path: &str,
     queries: Option<T>,
-) -> Result<Response, String>
+) -> Result<PaystackResponse<R>, PaystackError>
 where
     T: Serialize + Debug,
+    R: DeserializeOwned,
 {
-    let reqwest_client = Client::new();
-    let formatted_err_msg = format!(
-        "[PAYSTACK ERROR]: Error making GET request to url: {}",
-        url.to_string()
-    );
-    let queries = &ser::to_string(queries).unwrap();
+    let span = request_span(&Method::GET, path);
+    let _entered = span.enter();
+    tracing::trace!(queries = ?queries, "request queries");
+    let url = &http.config.url(path);
+    let queries = ser::to_string(queries)
+        .map_err(|err| PaystackError::query_encode(Method::GET, url, err))?;
     let full_url = format!("{}?{}", url, queries);
-    let res = reqwest_client
-        .get(full_url)
-        .header(AUTHORIZATION, bearer_auth.to_string())
-        .send()
-        .expect(formatted_err_msg.as_str());
-
-    match res.status() {
-        StatusCode::OK => return Ok(res),
-        StatusCode::BAD_REQUEST => return Err("Bad request. Please check the body".to_string()),
-        StatusCode::INTERNAL_SERVER_ERROR => {
-            return Err("An error occured on the paystack server: please try again".to_string())
-        }
-        _ => {
-            println!("Response from google is: {:?}", res);
-            return Ok(res);
-        }
-    }
+    let authorization = bearer_header(&http.key)?;
+
+    send(http, Method::GET, path, url, true, || {
+        http.client
+            .get(&full_url)
+            .header(AUTHORIZATION, authorization.clone())
+    })
 }
 
-pub(crate) fn make_request<T>(
-    bearer_auth: &str,
-    url: &str,
+#[cfg(feature = "blocking")]
+pub(crate) fn make_request<T, R>(
+    http: &HttpClient,
+    path: &str,
     body: Option<T>,
-    verb: REQUEST,
-) -> Result<Response, String>
+    verb: Method,
+) -> Result<PaystackResponse<R>, PaystackError>
 where
     T: Debug + Serialize,
+    R: DeserializeOwned,
 {
-    let reqwest_client = Client::new();
-    let formatted_err_msg = format!(
-        "[PAYSTACK ERROR]: Error making POST request to paystack with URL: {} and body: {:?}",
-        url, body
-    );
+    make_idempotent_request(http, path, body, verb, false)
+}
+
+/// Like [`make_request`], but retried under the client's retry policy when `idempotent` is set,
+/// i.e. when the caller supplied a reference that keeps Paystack from acting on the request twice
+#[cfg(feature = "blocking")]
+pub(crate) fn make_idempotent_request<T, R>(
+    http: &HttpClient,
+    path: &str,
+    body: Option<T>,
+    verb: Method,
+    idempotent: bool,
+) -> Result<PaystackResponse<R>, PaystackError>
+where
+    T: Debug + Serialize,
+    R: DeserializeOwned,
+{
+    let span = request_span(&verb, path);
+    let _entered = span.enter();
+    tracing::trace!(body = ?body, "request body");
+    let url = &http.config.url(path);
+    let serialized_body = serde_json::to_string(&body)
+        .map_err(|err| PaystackError::serialize(verb.clone(), url, err))?;
+    let retryable = idempotent || verb == Method::GET;
+    let authorization = bearer_header(&http.key)?;
+
+    send(http, verb.clone(), path, url, retryable, || {
+        http.client
+            .request(verb.clone(), url)
+            .header(AUTHORIZATION, authorization.clone())
+            .header(CONTENT_TYPE, "application/json")
+            .body(serialized_body.clone())
+    })
+}
 
-    let serialized_body =
-        serde_json::to_string(&body).expect("Error serializing POST request body");
-    let builder = match verb {
-        REQUEST::POST => reqwest_client.post(url),
-        REQUEST::DELETE => reqwest_client.delete(url),
-        REQUEST::PUT => reqwest_client.put(url),
-    };
-
-    let res = builder
-        .header(AUTHORIZATION, bearer_auth)
-        .header(CONTENT_TYPE, "application/json".to_string())
-        .body(serialized_body)
-        .send()
-        .expect(formatted_err_msg.as_str());
-
-    match res.status() {
-        StatusCode::UNAUTHORIZED => {
-            println!("Oops! Unauthorized request. Please ensure you've set the correct headers");
-            return Err("Unauthorized request. please check header values".to_string());
+/// Send the request built by `request` once the rate limiter lets it through, sending a fresh
+/// copy again while the retry policy allows it and the failure is worth retrying
+#[cfg(feature = "blocking")]
+fn send<F, R>(
+    http: &HttpClient,
+    method: Method,
+    path: &str,
+    url: &str,
+    retryable: bool,
+    request: F,
+) -> Result<PaystackResponse<R>, PaystackError>
+where
+    F: Fn() -> RequestBuilder,
+    R: DeserializeOwned,
+{
+    let policy = &http.config.retry;
+    let mut attempt = 1;
+    loop {
+        if let Some(limiter) = &http.config.rate_limiter {
+            let wait = limiter.reserve(path);
+            if !wait.is_zero() {
+                tracing::debug!(
+                    wait_ms = wait.as_millis() as u64,
+                    "waiting for rate limiter"
+                );
+                thread::sleep(wait);
+            }
         }
-        StatusCode::BAD_REQUEST => {
-            return Err(
-                "Bad request. Please check whatever you're passing in the request. Seems broken"
-                    .to_string(),
-            )
+
+        let started = Instant::now();
+        let result = request().send();
+        match &result {
+            Ok(res) => trace_response(res.status(), res.headers(), started),
+            Err(err) => trace_failure(err, started),
         }
-        StatusCode::OK => {
-            println!("Yay!! you got it!!");
-            return Ok(res);
+        if retryable && policy.can_retry(attempt) {
+            let delay = match &result {
+                Ok(res) if policy.retries_status(res.status()) => {
+                    Some(policy.delay(attempt, Some(res.headers())))
+                }
+                Err(err) if policy.retries_error(err) => Some(policy.delay(attempt, None)),
+                _ => None,
+            };
+            if let Some(delay) = delay {
+                trace_retry(attempt, delay);
+                thread::sleep(delay);
+                attempt += 1;
+                continue;
+            }
         }
-        _ => {
-            // the below is meant as a light joke.. chill out pls
-            println!("Dunno... Looks Ok but since its not an error i specially check for, here is your result, man... or woman... or they/them");
-            return Ok(res);
+
+        let res = result.map_err(|err| PaystackError::transport(method.clone(), url, err))?;
+        return read_response(method, url, res);
+    }
+}
+
+/// Read a response into a [`PaystackResponse`], turning everything but a success into a
+/// [`PaystackError`] that keeps Paystack's own error message when the body carries one.
+#[cfg(feature = "blocking")]
+fn read_response<R>(
+    method: Method,
+    url: &str,
+    res: Response,
+) -> Result<PaystackResponse<R>, PaystackError>
+where
+    R: DeserializeOwned,
+{
+    let status = res.status();
+    let body = res
+        .text()
+        .map_err(|err| PaystackError::transport(method, url, err))?;
+    parse_response(status, body)
+}
+
+/// Deserialize the body of a response. Endpoints that only confirm an action leave `data` out,
+/// which is read as `null`.
+pub(crate) fn parse_response<R>(
+    status: StatusCode,
+    body: String,
+) -> Result<PaystackResponse<R>, PaystackError>
+where
+    R: DeserializeOwned,
+{
+    if !status.is_success() {
+        return Err(api_error(status, body));
+    }
+
+    let mut envelope: JSON = serde_json::from_str(&body)?;
+    if let JSON::Object(fields) = &mut envelope {
+        if fields.get("status") == Some(&JSON::Bool(false)) {
+            return Err(api_error(status, body));
         }
-    };
+        fields.entry("data").or_insert(JSON::Null);
+    }
+    Ok(serde_json::from_value(envelope)?)
+}
+
+/// The `Authorization` header for a secret key, marked sensitive so reqwest never shows it
+pub(crate) fn bearer_header(key: &SecretKey) -> Result<HeaderValue, PaystackError> {
+    let bearer = Zeroizing::new(format!("Bearer {}", key.expose_secret()));
+    let mut value = HeaderValue::from_str(&bearer).map_err(|_| {
+        PaystackError::Config(
+            "the secret key contains characters not allowed in a header".to_string(),
+        )
+    })?;
+    value.set_sensitive(true);
+    Ok(value)
+}
+
+/// The span every event of one call to Paystack is recorded in. It never holds the bearer token,
+/// and request bodies are only recorded through their `Debug` impls, which redact PINs and OTPs.
+#[cfg(any(feature = "blocking", feature = "async"))]
+pub(crate) fn request_span(method: &Method, path: &str) -> Span {
+    tracing::debug_span!("paystack_request", method = %method, path = %path)
+}
+
+/// Record the response to one attempt of a request
+#[cfg(any(feature = "blocking", feature = "async"))]
+pub(crate) fn trace_response(status: StatusCode, headers: &HeaderMap, started: Instant) {
+    let request_id = REQUEST_ID_HEADERS
+        .iter()
+        .find_map(|name| headers.get(*name)?.to_str().ok());
+    tracing::debug!(
+        status = status.as_u16(),
+        latency_ms = started.elapsed().as_millis() as u64,
+        request_id,
+        "received response"
+    );
+}
+
+/// Record an attempt that got no response. The error itself is left out since its message
+/// carries the full URL, query string included.
+#[cfg(any(feature = "blocking", feature = "async"))]
+pub(crate) fn trace_failure(err: &reqwest::Error, started: Instant) {
+    tracing::warn!(
+        latency_ms = started.elapsed().as_millis() as u64,
+        connect = err.is_connect(),
+        timeout = err.is_timeout(),
+        "request failed"
+    );
+}
+
+/// Record that an attempt is about to be retried
+#[cfg(any(feature = "blocking", feature = "async"))]
+pub(crate) fn trace_retry(attempt: u32, delay: Duration) {
+    tracing::warn!(
+        attempt,
+        delay_ms = delay.as_millis() as u64,
+        "retrying request"
+    );
+}
+
+/// Build the error for a non-success response from its status and raw body
+pub(crate) fn api_error(status: StatusCode, body: String) -> PaystackError {
+    match serde_json::from_str::<PaystackErrorBody>(&body) {
+        Ok(err) => PaystackError::Api {
+            status,
+            message: err.message,
+            errors: err.errors,
+            code: err.code,
+        },
+        Err(_) => PaystackError::Http { status, body },
+    }
 }
@@ -0,0 +1,179 @@
+use crate::{
+    builder::Config,
+    error::PaystackError,
+    response::PaystackResponse,
+    secret::SecretKey,
+    utils::{
+        bearer_header, parse_response, request_span, trace_failure, trace_response, trace_retry,
+    },
+};
+use reqwest::{
+    header::{AUTHORIZATION, CONTENT_TYPE},
+    Method, RequestBuilder, Response,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_urlencoded::*;
+use std::{fmt::Debug, time::Instant};
+use tracing::Instrument;
+
+/// The connection pool and settings shared by every resource struct of an async client
+#[derive(Debug)]
+pub(crate) struct HttpClient {
+    pub(crate) key: SecretKey,
+    pub(crate) config: Config,
+    pub(crate) client: reqwest::Client,
+}
+
+impl HttpClient {
+    pub(crate) fn new(key: SecretKey, config: Config) -> Result<HttpClient, PaystackError> {
+        let client = config
+            .async_client()
+            .map_err(|err| PaystackError::Config(err.to_string()))?;
+        Ok(HttpClient {
+            key,
+            config,
+            client,
+        })
+    }
+}
+
+pub(crate) async fn make_get_request<T, R>(
+    http: &HttpClient,
+    path: &str,
+    queries: Option<T>,
+) -> Result<PaystackResponse<R>, PaystackError>
+where
+    T: Serialize + Debug,
+    R: DeserializeOwned,
+{
+    let span = request_span(&Method::GET, path);
+    span.in_scope(|| tracing::trace!(queries = ?queries, "request queries"));
+    let url = &http.config.url(path);
+    let queries = ser::to_string(queries)
+        .map_err(|err| PaystackError::query_encode(Method::GET, url, err))?;
+    let full_url = format!("{}?{}", url, queries);
+    let authorization = bearer_header(&http.key)?;
+
+    send(http, Method::GET, path, url, true, || {
+        http.client
+            .get(&full_url)
+            .header(AUTHORIZATION, authorization.clone())
+    })
+    .instrument(span)
+    .await
+}
+
+pub(crate) async fn make_request<T, R>(
+    http: &HttpClient,
+    path: &str,
+    body: Option<T>,
+    verb: Method,
+) -> Result<PaystackResponse<R>, PaystackError>
+where
+    T: Debug + Serialize,
+    R: DeserializeOwned,
+{
+    make_idempotent_request(http, path, body, verb, false).await
+}
+
+/// Async counterpart of the blocking `make_idempotent_request`
+pub(crate) async fn make_idempotent_request<T, R>(
+    http: &HttpClient,
+    path: &str,
+    body: Option<T>,
+    verb: Method,
+    idempotent: bool,
+) -> Result<PaystackResponse<R>, PaystackError>
+where
+    T: Debug + Serialize,
+    R: DeserializeOwned,
+{
+    let span = request_span(&verb, path);
+    span.in_scope(|| tracing::trace!(body = ?body, "request body"));
+    let url = &http.config.url(path);
+    let serialized_body = serde_json::to_string(&body)
+        .map_err(|err| PaystackError::serialize(verb.clone(), url, err))?;
+    let retryable = idempotent || verb == Method::GET;
+    let authorization = bearer_header(&http.key)?;
+
+    send(http, verb.clone(), path, url, retryable, || {
+        http.client
+            .request(verb.clone(), url)
+            .header(AUTHORIZATION, authorization.clone())
+            .header(CONTENT_TYPE, "application/json")
+            .body(serialized_body.clone())
+    })
+    .instrument(span)
+    .await
+}
+
+/// Async counterpart of the blocking `send`, waiting between attempts without blocking the runtime
+async fn send<F, R>(
+    http: &HttpClient,
+    method: Method,
+    path: &str,
+    url: &str,
+    retryable: bool,
+    request: F,
+) -> Result<PaystackResponse<R>, PaystackError>
+where
+    F: Fn() -> RequestBuilder,
+    R: DeserializeOwned,
+{
+    let policy = &http.config.retry;
+    let mut attempt = 1;
+    loop {
+        if let Some(limiter) = &http.config.rate_limiter {
+            let wait = limiter.reserve(path);
+            if !wait.is_zero() {
+                tracing::debug!(
+                    wait_ms = wait.as_millis() as u64,
+                    "waiting for rate limiter"
+                );
+                tokio::time::sleep(wait).await;
+            }
+        }
+
+        let started = Instant::now();
+        let result = request().send().await;
+        match &result {
+            Ok(res) => trace_response(res.status(), res.headers(), started),
+            Err(err) => trace_failure(err, started),
+        }
+        if retryable && policy.can_retry(attempt) {
+            let delay = match &result {
+                Ok(res) if policy.retries_status(res.status()) => {
+                    Some(policy.delay(attempt, Some(res.headers())))
+                }
+                Err(err) if policy.retries_error(err) => Some(policy.delay(attempt, None)),
+                _ => None,
+            };
+            if let Some(delay) = delay {
+                trace_retry(attempt, delay);
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+                continue;
+            }
+        }
+
+        let res = result.map_err(|err| PaystackError::transport(method.clone(), url, err))?;
+        return read_response(method, url, res).await;
+    }
+}
+
+/// Async counterpart of the blocking `read_response`
+async fn read_response<R>(
+    method: Method,
+    url: &str,
+    res: Response,
+) -> Result<PaystackResponse<R>, PaystackError>
+where
+    R: DeserializeOwned,
+{
+    let status = res.status();
+    let body = res
+        .text()
+        .await
+        .map_err(|err| PaystackError::transport(method, url, err))?;
+    parse_response(status, body)
+}
@@ -0,0 +1,127 @@
+//! Checks run on request bodies before they are sent, so that mistakes Paystack would reject
+//! are reported locally with the offending field.
+use crate::error::PaystackError;
+use crate::money::Money;
+
+/// Longest metadata sent with a request, in bytes of JSON
+pub(crate) const MAX_METADATA_LEN: usize = 8192;
+
+/// Transaction references may only hold `-`, `.`, `=` and alphanumeric characters
+pub(crate) fn reference(field: &str, reference: &str) -> Result<(), PaystackError> {
+    if reference.is_empty() {
+        return Err(PaystackError::validation(format!("{} is empty", field)));
+    }
+    match reference
+        .chars()
+        .find(|c| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '=')))
+    {
+        Some(c) => Err(PaystackError::validation(format!(
+            "{} {:?} contains {:?}; only -, ., = and alphanumeric characters are allowed",
+            field, reference, c
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// References looked up by a GET are put in the path, so they can't be empty, hold characters
+/// that would end it or be a `.` or `..` segment. Their format isn't checked: Paystack may have
+/// generated them, or older integrations created them under other rules.
+pub(crate) fn lookup(field: &str, reference: &str) -> Result<(), PaystackError> {
+    if reference.is_empty() {
+        return Err(PaystackError::validation(format!("{} is empty", field)));
+    }
+    if reference == "." || reference == ".." {
+        return Err(PaystackError::validation(format!(
+            "{} {:?} can't be part of a path",
+            field, reference
+        )));
+    }
+    match reference.chars().find(|c| matches!(c, '/' | '?' | '#')) {
+        Some(c) => Err(PaystackError::validation(format!(
+            "{} {:?} contains {:?}, which can't be part of a path",
+            field, reference, c
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Transfer references may only hold `-`, `_`, lowercase letters and digits
+pub(crate) fn transfer_reference(field: &str, reference: &str) -> Result<(), PaystackError> {
+    if reference.is_empty() {
+        return Err(PaystackError::validation(format!("{} is empty", field)));
+    }
+    match reference
+        .chars()
+        .find(|c| !(c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '-' | '_')))
+    {
+        Some(c) => Err(PaystackError::validation(format!(
+            "{} {:?} contains {:?}; only -, _, lowercase letters and digits are allowed",
+            field, reference, c
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// A plausible address: something before a single `@`, and a dotted domain after it
+pub(crate) fn email(field: &str, email: &str) -> Result<(), PaystackError> {
+    let valid = match email.split_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty()
+                && !domain.contains('@')
+                && domain.contains('.')
+                && !domain.starts_with('.')
+                && !domain.ends_with('.')
+                && !email.chars().any(char::is_whitespace)
+                && email.len() <= 254
+        }
+        None => false,
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err(PaystackError::validation(format!(
+            "{} {:?} is not an email address",
+            field, email
+        )))
+    }
+}
+
+/// Amounts are charged or paid out, so they must be above zero
+pub(crate) fn amount(field: &str, amount: &Money) -> Result<(), PaystackError> {
+    if amount.minor() > 0 {
+        Ok(())
+    } else {
+        Err(PaystackError::validation(format!(
+            "{} must be positive, got {}",
+            field, amount
+        )))
+    }
+}
+
+/// An amount sent without its currency is read in the currency of the amount it goes with
+pub(crate) fn same_currency(field: &str, amount: &Money, of: &Money) -> Result<(), PaystackError> {
+    if amount.currency() == of.currency() {
+        Ok(())
+    } else {
+        Err(PaystackError::validation(format!(
+            "{} is in {} but the amount is in {}",
+            field,
+            amount.currency(),
+            of.currency()
+        )))
+    }
+}
+
+/// Metadata must fit in [`MAX_METADATA_LEN`] once serialized
+pub(crate) fn metadata(field: &str, metadata: &str) -> Result<(), PaystackError> {
+    if metadata.len() > MAX_METADATA_LEN {
+        Err(PaystackError::validation(format!(
+            "{} is {} bytes long, over the limit of {}",
+            field,
+            metadata.len(),
+            MAX_METADATA_LEN
+        )))
+    } else {
+        Ok(())
+    }
+}
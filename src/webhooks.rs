@@ -0,0 +1,198 @@
+//! Receiving Paystack webhooks. Paystack signs every webhook with an HMAC-SHA512 of the raw
+//! request body, keyed with your secret key, and sends it hex encoded in the
+//! `x-paystack-signature` header. Check it against the exact bytes received, before parsing them.
+
+mod allowlist;
+mod event;
+#[cfg(any(feature = "axum", feature = "actix-web"))]
+mod extract;
+#[cfg(feature = "blocking")]
+mod router;
+mod seen;
+mod simulator;
+
+pub use allowlist::{IpAllowlist, FORWARDED_FOR_HEADER, PAYSTACK_WEBHOOK_IPS};
+#[cfg(any(feature = "blocking", feature = "async"))]
+pub(crate) use event::with_events;
+pub use event::Event;
+#[cfg(any(feature = "axum", feature = "actix-web"))]
+pub use extract::VerifiedEvent;
+#[cfg(feature = "blocking")]
+pub use router::{EventHandler, WebhookRouter};
+#[cfg(any(feature = "blocking", feature = "async"))]
+pub(crate) use seen::claim;
+pub use seen::{FileSeenEvents, MemorySeenEvents, SeenEvents};
+pub use simulator::{Delivery, DeliveryAttempt, SignedWebhook, WebhookSimulator};
+
+use crate::secret::SecretKey;
+use hmac::{Hmac, Mac};
+use reqwest::{header::HeaderMap, StatusCode};
+use sha2::Sha512;
+use std::{fmt, net::IpAddr};
+
+/// The header Paystack sends the body's signature in
+pub const SIGNATURE_HEADER: &str = "x-paystack-signature";
+
+/// What a webhook handler returns when it could not process an event. The router answers
+/// Paystack with a 500 so that the webhook is sent again later.
+pub type HandlerError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Verifies that webhooks come from Paystack, using the secret key of the integration they were
+/// sent to. A `Paystack` client carries one as `paystack.webhooks`; services that only receive
+/// webhooks can build one from the key alone.
+/// ```rust
+/// use paystack_rs::prelude::{SecretKey, WebhookError, Webhooks};
+///
+/// let webhooks = Webhooks::new(SecretKey::new("sk_test_xxxx".to_string()));
+/// let body = br#"{"event":"charge.success","data":{}}"#;
+///
+/// // What Paystack sends in the x-paystack-signature header
+/// let signature = webhooks.sign(body);
+///
+/// assert_eq!(webhooks.verify(body, &signature), Ok(()));
+/// assert_eq!(
+///     webhooks.verify(br#"{"event":"charge.success","data":{"amount":1}}"#, &signature),
+///     Err(WebhookError::InvalidSignature)
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Webhooks {
+    key: SecretKey,
+}
+
+/// Why a webhook was rejected
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WebhookError {
+    /// The request has no `x-paystack-signature` header, or it is not valid text
+    MissingSignature,
+    /// The signature does not match the body, so the webhook was not sent by Paystack or the body
+    /// was altered on the way
+    InvalidSignature,
+    /// The body is signed but is not a webhook this crate can read, e.g. `data` doesn't match the
+    /// model of its event
+    InvalidPayload(String),
+    /// The address that sent the request could not be worked out, e.g. `X-Forwarded-For` has
+    /// fewer addresses than there are trusted proxies
+    UnknownSource,
+    /// The request was sent from an address that is not on the allowlist
+    ForbiddenSource(IpAddr),
+}
+
+impl Webhooks {
+    /// Verify webhooks signed with this secret key
+    pub fn new(key: SecretKey) -> Webhooks {
+        Webhooks { key }
+    }
+
+    /// Check the hex encoded `signature` against the raw `body` of the request. The comparison
+    /// takes the same time wherever the signatures differ.
+    pub fn verify(&self, body: &[u8], signature: &str) -> Result<(), WebhookError> {
+        let signature =
+            hex::decode(signature.trim()).map_err(|_| WebhookError::InvalidSignature)?;
+        self.mac(body)
+            .verify_slice(&signature)
+            .map_err(|_| WebhookError::InvalidSignature)
+    }
+
+    /// Like [`Webhooks::verify`], reading the signature from the request's
+    /// `x-paystack-signature` header
+    pub fn verify_headers(&self, body: &[u8], headers: &HeaderMap) -> Result<(), WebhookError> {
+        let signature = headers
+            .get(SIGNATURE_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .ok_or(WebhookError::MissingSignature)?;
+        self.verify(body, signature)
+    }
+
+    /// Verify the webhook, then parse it
+    /// ```rust
+    /// use paystack_rs::prelude::{Event, SecretKey, Webhooks};
+    ///
+    /// let webhooks = Webhooks::new(SecretKey::new("sk_test_xxxx".to_string()));
+    /// let body = br#"{"event":"transfer.failed","data":{"id":37272792,"status":"failed","transfer_code":"TRF_2x5j67tnnw1t98k","amount":30000,"currency":"NGN"}}"#;
+    /// let signature = webhooks.sign(body);
+    ///
+    /// if let Event::TransferFailed(transfer) = webhooks.verify_event(body, &signature).unwrap() {
+    ///     assert_eq!(transfer.transfer_code, "TRF_2x5j67tnnw1t98k");
+    /// }
+    /// ```
+    pub fn verify_event(&self, body: &[u8], signature: &str) -> Result<Event, WebhookError> {
+        self.verify(body, signature)?;
+        parse_event(body)
+    }
+
+    /// Like [`Webhooks::verify_event`], reading the signature from the request's
+    /// `x-paystack-signature` header
+    pub fn verify_event_headers(
+        &self,
+        body: &[u8],
+        headers: &HeaderMap,
+    ) -> Result<Event, WebhookError> {
+        self.verify_headers(body, headers)?;
+        parse_event(body)
+    }
+
+    /// The hex encoded signature Paystack would send for `body`, to sign webhooks in tests
+    pub fn sign(&self, body: &[u8]) -> String {
+        hex::encode(self.mac(body).finalize().into_bytes())
+    }
+
+    /// Check the sender against the allowlist, if there is one, then verify and parse the webhook
+    pub(crate) fn accept(
+        &self,
+        allowlist: Option<&IpAllowlist>,
+        peer: Option<IpAddr>,
+        body: &[u8],
+        headers: &HeaderMap,
+    ) -> Result<Event, WebhookError> {
+        if let Some(allowlist) = allowlist {
+            allowlist.check(peer.ok_or(WebhookError::UnknownSource)?, headers)?;
+        }
+        self.verify_event_headers(body, headers)
+    }
+
+    fn mac(&self, body: &[u8]) -> Hmac<Sha512> {
+        let mut mac = Hmac::<Sha512>::new_from_slice(self.key.expose_secret().as_bytes())
+            .expect("HMAC accepts keys of any length");
+        mac.update(body);
+        mac
+    }
+}
+
+impl WebhookError {
+    /// The status to answer Paystack with: 401 for a missing or wrong signature, 400 for a body
+    /// that cannot be read and 403 for a sender that is not allowed
+    pub fn status(&self) -> StatusCode {
+        match self {
+            WebhookError::MissingSignature | WebhookError::InvalidSignature => {
+                StatusCode::UNAUTHORIZED
+            }
+            WebhookError::InvalidPayload(_) => StatusCode::BAD_REQUEST,
+            WebhookError::UnknownSource | WebhookError::ForbiddenSource(_) => StatusCode::FORBIDDEN,
+        }
+    }
+}
+
+fn parse_event(body: &[u8]) -> Result<Event, WebhookError> {
+    serde_json::from_slice(body).map_err(|err| WebhookError::InvalidPayload(err.to_string()))
+}
+
+impl fmt::Display for WebhookError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WebhookError::MissingSignature => {
+                write!(f, "webhook has no {} header", SIGNATURE_HEADER)
+            }
+            WebhookError::InvalidSignature => {
+                write!(f, "webhook signature does not match its body")
+            }
+            WebhookError::InvalidPayload(reason) => write!(f, "invalid webhook body: {}", reason),
+            WebhookError::UnknownSource => write!(f, "cannot tell where the webhook came from"),
+            WebhookError::ForbiddenSource(ip) => {
+                write!(f, "webhook sent from {}, which is not allowed", ip)
+            }
+        }
+    }
+}
+
+impl std::error::Error for WebhookError {}
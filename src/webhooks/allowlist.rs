@@ -0,0 +1,108 @@
+use super::WebhookError;
+use reqwest::header::HeaderMap;
+use std::net::{IpAddr, Ipv4Addr};
+
+/// The addresses Paystack sends webhooks from
+pub const PAYSTACK_WEBHOOK_IPS: [IpAddr; 3] = [
+    IpAddr::V4(Ipv4Addr::new(52, 31, 139, 75)),
+    IpAddr::V4(Ipv4Addr::new(52, 49, 173, 169)),
+    IpAddr::V4(Ipv4Addr::new(52, 214, 14, 220)),
+];
+
+/// The header proxies add the address they received a request from to
+pub const FORWARDED_FOR_HEADER: &str = "x-forwarded-for";
+
+/// The addresses webhooks are accepted from, checked on top of the signature. Defaults to
+/// [`PAYSTACK_WEBHOOK_IPS`], trusting no proxy.
+///
+/// Behind proxies, the peer of the connection is the last proxy, and each proxy appends the
+/// address it was reached from to `X-Forwarded-For`. With `trusted_proxies(n)`, the sender is
+/// the `n`th address from the end of that header; anything before it could have been written by
+/// the sender and is ignored.
+/// ```rust
+/// use paystack_rs::prelude::{IpAllowlist, WebhookError};
+/// use reqwest::header::HeaderMap;
+/// use std::net::IpAddr;
+///
+/// let load_balancer: IpAddr = "10.0.0.2".parse().unwrap();
+/// let allowlist = IpAllowlist::default().trusted_proxies(1);
+///
+/// let mut headers = HeaderMap::new();
+/// headers.insert("x-forwarded-for", "1.2.3.4, 52.31.139.75".parse().unwrap());
+/// assert_eq!(allowlist.check(load_balancer, &headers), Ok("52.31.139.75".parse().unwrap()));
+///
+/// headers.insert("x-forwarded-for", "52.31.139.75, 1.2.3.4".parse().unwrap());
+/// assert_eq!(
+///     allowlist.check(load_balancer, &headers),
+///     Err(WebhookError::ForbiddenSource("1.2.3.4".parse().unwrap()))
+/// );
+///
+/// // Send test webhooks from your own machine
+/// let local = IpAllowlist::new(vec!["127.0.0.1".parse().unwrap()]);
+/// assert!(local.check("127.0.0.1".parse().unwrap(), &HeaderMap::new()).is_ok());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IpAllowlist {
+    allowed: Vec<IpAddr>,
+    trusted_proxies: usize,
+}
+
+impl Default for IpAllowlist {
+    fn default() -> IpAllowlist {
+        IpAllowlist::new(PAYSTACK_WEBHOOK_IPS.to_vec())
+    }
+}
+
+impl IpAllowlist {
+    /// Accept webhooks from these addresses only, e.g. your own machine while testing
+    pub fn new(allowed: Vec<IpAddr>) -> IpAllowlist {
+        IpAllowlist {
+            allowed: allowed.into_iter().map(|ip| ip.to_canonical()).collect(),
+            trusted_proxies: 0,
+        }
+    }
+
+    /// Number of proxies in front of the service, each adding an address to `X-Forwarded-For`.
+    /// Defaults to 0, which ignores the header.
+    pub fn trusted_proxies(mut self, count: usize) -> IpAllowlist {
+        self.trusted_proxies = count;
+        self
+    }
+
+    /// Whether webhooks are accepted from `ip`
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        self.allowed.contains(&ip.to_canonical())
+    }
+
+    /// The address that sent the request, given the `peer` of the connection and the request's
+    /// headers
+    pub fn sender(&self, peer: IpAddr, headers: &HeaderMap) -> Result<IpAddr, WebhookError> {
+        if self.trusted_proxies == 0 {
+            return Ok(peer.to_canonical());
+        }
+        let mut forwarded = Vec::new();
+        for value in headers.get_all(FORWARDED_FOR_HEADER) {
+            let value = value.to_str().map_err(|_| WebhookError::UnknownSource)?;
+            forwarded.extend(value.split(',').map(str::trim));
+        }
+        let sender = forwarded
+            .len()
+            .checked_sub(self.trusted_proxies)
+            .map(|index| forwarded[index])
+            .ok_or(WebhookError::UnknownSource)?;
+        sender
+            .parse::<IpAddr>()
+            .map(|ip| ip.to_canonical())
+            .map_err(|_| WebhookError::UnknownSource)
+    }
+
+    /// Check that the request was sent from an allowed address, returning that address
+    pub fn check(&self, peer: IpAddr, headers: &HeaderMap) -> Result<IpAddr, WebhookError> {
+        let sender = self.sender(peer, headers)?;
+        if self.contains(sender) {
+            Ok(sender)
+        } else {
+            Err(WebhookError::ForbiddenSource(sender))
+        }
+    }
+}
@@ -0,0 +1,272 @@
+use crate::paystack::{
+    disputes::DisputeData,
+    refund::RefundData,
+    subscription::{SubscriptionData, SubscriptionInvoice},
+    transactions::TransactionData,
+    transfers::TransferData,
+};
+use serde::{ser::SerializeMap, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value as JSON;
+
+/// Declares the events Paystack sends along with the type their `data` is parsed into
+macro_rules! events {
+    ($($(#[$doc:meta])* $name:literal => $variant:ident($data:ty) $(=> $method:ident)?,)*) => {
+        /// A webhook sent by Paystack, with its `data` parsed into the model of the object the event
+        /// is about. Events this version of the crate doesn't know arrive as `Unknown`, so a new
+        /// event type never makes a webhook fail to parse. So do known events whose `data` no
+        /// longer fits its model, with the raw JSON kept as it was sent.
+        /// ```rust
+        /// use paystack_rs::prelude::Event;
+        ///
+        /// let event: Event = serde_json::from_str(
+        ///     r#"{
+        ///         "event": "charge.success",
+        ///         "data": {"id": 302961, "status": "success", "reference": "qTPrJoy9Bx", "amount": 10000, "currency": "NGN"}
+        ///     }"#,
+        /// )
+        /// .unwrap();
+        /// match event {
+        ///     Event::ChargeSuccess(transaction) => assert_eq!(transaction.reference, "qTPrJoy9Bx"),
+        ///     other => panic!("unexpected {}", other.name()),
+        /// }
+        ///
+        /// let event: Event =
+        ///     serde_json::from_str(r#"{"event": "bank.transfer.rejected", "data": {"id": 1}}"#).unwrap();
+        /// assert_eq!(event.name(), "bank.transfer.rejected");
+        /// assert!(matches!(event, Event::Unknown { .. }));
+        ///
+        /// // A field Paystack started sending in another shape
+        /// let event: Event =
+        ///     serde_json::from_str(r#"{"event": "transfer.success", "data": {"amount": [50000]}}"#).unwrap();
+        /// assert_eq!(event.name(), "transfer.success");
+        /// assert!(matches!(event, Event::Unknown { data, .. } if data["amount"][0] == 50000));
+        /// ```
+        #[derive(Debug, Clone)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum Event {
+            $($(#[$doc])* $variant($data),)*
+            /// An event this version of the crate doesn't know, or whose `data` doesn't fit its
+            /// model, left as it was sent
+            Unknown {
+                /// The event's name, e.g. `bank.transfer.rejected`
+                event: String,
+                /// The event's `data`
+                data: JSON,
+            },
+        }
+
+        impl Event {
+            /// The event's name as Paystack sends it, e.g. `charge.success`
+            pub fn name(&self) -> &str {
+                match self {
+                    $(Event::$variant(_) => $name,)*
+                    Event::Unknown { event, .. } => event,
+                }
+            }
+
+            /// Whether this is a known event whose `data` didn't fit its model
+            #[cfg(any(feature = "blocking", feature = "async"))]
+            pub(crate) fn is_malformed(&self) -> bool {
+                match self {
+                    Event::Unknown { event, .. } => matches!(event.as_str(), $($name)|*),
+                    _ => false,
+                }
+            }
+
+            fn from_parts(event: String, data: JSON) -> Event {
+                let parsed = match event.as_str() {
+                    $($name => <$data>::deserialize(&data).map(Event::$variant),)*
+                    _ => return Event::Unknown { event, data },
+                };
+                parsed.unwrap_or_else(|err| {
+                    // Keep the delivery rather than have Paystack retry a payload that will never parse
+                    tracing::warn!(event = %event, error = %err, "webhook data does not match its model");
+                    Event::Unknown { event, data }
+                })
+            }
+        }
+
+        impl Serialize for Event {
+            /// The way Paystack sends it, e.g. `{"event":"charge.success","data":{...}}`
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let mut map = serializer.serialize_map(Some(2))?;
+                map.serialize_entry("event", self.name())?;
+                match self {
+                    $(Event::$variant(data) => map.serialize_entry("data", data)?,)*
+                    Event::Unknown { data, .. } => map.serialize_entry("data", data)?,
+                }
+                map.end()
+            }
+        }
+    };
+}
+
+/// Hands every event Paystack sends to the `$callback` macro, with the type its `data` is
+/// parsed into and, for events with a model, the router method that registers a handler for it.
+/// [`Event`] and both routers are generated from this list, so an event added here is parsed and
+/// routed everywhere.
+macro_rules! with_events {
+    ($callback:ident) => {
+        $callback! {
+            /// A customer paid
+            "charge.success" => ChargeSuccess(TransactionData) => on_charge_success,
+            /// A customer disputed a transaction
+            "charge.dispute.create" => ChargeDisputeCreate(DisputeData) => on_charge_dispute_create,
+            /// A dispute is still waiting on the integration
+            "charge.dispute.remind" => ChargeDisputeRemind(DisputeData) => on_charge_dispute_remind,
+            /// A dispute was resolved
+            "charge.dispute.resolve" => ChargeDisputeResolve(DisputeData) => on_charge_dispute_resolve,
+            /// A transfer reached its recipient
+            "transfer.success" => TransferSuccess(TransferData) => on_transfer_success,
+            /// A transfer could not be completed
+            "transfer.failed" => TransferFailed(TransferData) => on_transfer_failed,
+            /// A transfer was sent back to the integration's balance
+            "transfer.reversed" => TransferReversed(TransferData) => on_transfer_reversed,
+            /// A customer subscribed to a plan
+            "subscription.create" => SubscriptionCreate(SubscriptionData) => on_subscription_create,
+            /// A subscription was cancelled or completed
+            "subscription.disable" => SubscriptionDisable(SubscriptionData) => on_subscription_disable,
+            /// A subscription will not be charged again
+            "subscription.not_renew" => SubscriptionNotRenew(SubscriptionData) => on_subscription_not_renew,
+            /// Subscriptions whose cards expire this month, as a list
+            "subscription.expiring_cards" => SubscriptionExpiringCards(JSON),
+            /// An invoice was raised for an upcoming subscription payment
+            "invoice.create" => InvoiceCreate(SubscriptionInvoice) => on_invoice_create,
+            /// A subscription invoice changed, usually because it was paid
+            "invoice.update" => InvoiceUpdate(SubscriptionInvoice) => on_invoice_update,
+            /// Charging a subscription invoice failed
+            "invoice.payment_failed" => InvoicePaymentFailed(SubscriptionInvoice) => on_invoice_payment_failed,
+            /// A refund was requested
+            "refund.pending" => RefundPending(RefundData) => on_refund_pending,
+            /// A refund is being processed
+            "refund.processing" => RefundProcessing(RefundData) => on_refund_processing,
+            /// A refund reached the customer
+            "refund.processed" => RefundProcessed(RefundData) => on_refund_processed,
+            /// A refund could not be processed
+            "refund.failed" => RefundFailed(RefundData) => on_refund_failed,
+            /// A dedicated virtual account was assigned to a customer
+            "dedicatedaccount.assign.success" => DedicatedAccountAssignSuccess(JSON),
+            /// A dedicated virtual account could not be assigned
+            "dedicatedaccount.assign.failed" => DedicatedAccountAssignFailed(JSON),
+            /// A customer's identity was validated
+            "customeridentification.success" => CustomerIdentificationSuccess(JSON),
+            /// A customer's identity could not be validated
+            "customeridentification.failed" => CustomerIdentificationFailed(JSON),
+            /// A payment request was sent
+            "paymentrequest.pending" => PaymentRequestPending(JSON),
+            /// A payment request was paid
+            "paymentrequest.success" => PaymentRequestSuccess(JSON),
+        }
+    };
+}
+
+#[cfg(any(feature = "blocking", feature = "async"))]
+pub(crate) use with_events;
+
+with_events!(events);
+
+impl Event {
+    /// Identifies the event across deliveries, so that a webhook Paystack sends again can be
+    /// recognised: the event's name followed by the reference, code or ID of what it is about,
+    /// e.g. `charge.success:qTPrJoy9Bx`. `None` when the payload carries none of them, in which
+    /// case the event isn't deduplicated.
+    /// ```rust
+    /// use paystack_rs::prelude::Event;
+    ///
+    /// let event: Event = serde_json::from_str(
+    ///     r#"{"event": "refund.processed", "data": {"refund_reference": "132013318360", "amount": "10000"}}"#,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(event.key().as_deref(), Some("refund.processed:132013318360"));
+    ///
+    /// // Partial refunds of one transaction share its reference, so it can't tell them apart
+    /// let event: Event = serde_json::from_str(
+    ///     r#"{"event": "refund.pending", "data": {"transaction_reference": "T685312322670591", "refund_reference": null}}"#,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(event.key(), None);
+    /// ```
+    pub fn key(&self) -> Option<String> {
+        let id = match self {
+            Event::ChargeSuccess(transaction) => {
+                non_empty(&transaction.reference).or_else(|| non_zero(transaction.id))
+            }
+            Event::ChargeDisputeCreate(dispute)
+            | Event::ChargeDisputeRemind(dispute)
+            | Event::ChargeDisputeResolve(dispute) => non_zero(dispute.id),
+            Event::TransferSuccess(transfer)
+            | Event::TransferFailed(transfer)
+            | Event::TransferReversed(transfer) => non_empty(&transfer.transfer_code),
+            Event::SubscriptionCreate(subscription)
+            | Event::SubscriptionDisable(subscription)
+            | Event::SubscriptionNotRenew(subscription) => {
+                non_empty(&subscription.subscription_code)
+            }
+            // An invoice is updated once per status it goes through
+            Event::InvoiceCreate(invoice)
+            | Event::InvoiceUpdate(invoice)
+            | Event::InvoicePaymentFailed(invoice) => {
+                non_empty(&invoice.invoice_code).map(|code| format!("{}:{}", code, invoice.status))
+            }
+            // A transaction can be refunded in parts, so its reference doesn't identify a refund
+            Event::RefundPending(refund)
+            | Event::RefundProcessing(refund)
+            | Event::RefundProcessed(refund)
+            | Event::RefundFailed(refund) => refund
+                .id
+                .map(|id| id.to_string())
+                .or_else(|| refund.refund_reference.as_deref().and_then(non_empty)),
+            Event::SubscriptionExpiringCards(_) => None,
+            Event::DedicatedAccountAssignSuccess(data)
+            | Event::DedicatedAccountAssignFailed(data)
+            | Event::CustomerIdentificationSuccess(data)
+            | Event::CustomerIdentificationFailed(data)
+            | Event::PaymentRequestPending(data)
+            | Event::PaymentRequestSuccess(data)
+            | Event::Unknown { data, .. } => json_id(data),
+        }?;
+        Some(format!("{}:{}", self.name(), id))
+    }
+}
+
+fn non_empty(value: &str) -> Option<String> {
+    Some(value.to_string()).filter(|value| !value.is_empty())
+}
+
+fn non_zero(id: i64) -> Option<String> {
+    Some(id).filter(|id| *id != 0).map(|id| id.to_string())
+}
+
+/// The first identifier found in a payload that has no model
+fn json_id(data: &JSON) -> Option<String> {
+    let lookups: [&[&str]; 5] = [
+        &["id"],
+        &["reference"],
+        &["request_code"],
+        &["dedicated_account", "id"],
+        &["customer_code"],
+    ];
+    lookups.iter().find_map(|path| {
+        let value = path.iter().try_fold(data, |value, key| value.get(key))?;
+        match value {
+            JSON::String(id) => non_empty(id),
+            JSON::Number(id) => Some(id.to_string()),
+            _ => None,
+        }
+    })
+}
+
+/// The envelope every webhook comes in
+#[derive(Deserialize)]
+struct RawEvent {
+    event: String,
+    #[serde(default)]
+    data: JSON,
+}
+
+impl<'de> Deserialize<'de> for Event {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let RawEvent { event, data } = RawEvent::deserialize(deserializer)?;
+        Ok(Event::from_parts(event, data))
+    }
+}
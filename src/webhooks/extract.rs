@@ -0,0 +1,183 @@
+use super::{Event, WebhookError, SIGNATURE_HEADER};
+
+/// Extracts the webhook a request carries, after checking its signature against the raw body.
+/// Requests with a missing or wrong signature are answered with a 401 before the handler runs,
+/// and bodies that are not a webhook with a 400.
+///
+/// With the `axum` feature, the secret key comes from the router's state: either the async
+/// [`Paystack`](crate::asynchronous::Paystack) client, a [`Webhooks`](super::Webhooks), or a
+/// state either can be taken from with `FromRef`. With the `actix-web` feature, it comes from
+/// the app data: a `web::Data` holding the async client or a `Webhooks`.
+#[derive(Debug, Clone)]
+pub struct VerifiedEvent(pub Event);
+
+/// The signature a request carries, if it is valid text
+fn signature(value: Option<&str>) -> Result<String, WebhookError> {
+    value
+        .map(str::to_string)
+        .ok_or(WebhookError::MissingSignature)
+}
+
+#[cfg(feature = "axum")]
+mod axum_extract {
+    use super::{signature, VerifiedEvent, SIGNATURE_HEADER};
+    use crate::{
+        asynchronous::Paystack,
+        webhooks::{WebhookError, Webhooks},
+    };
+    use axum::{
+        body::Bytes,
+        extract::{FromRef, FromRequest, Request},
+        http::StatusCode,
+        response::{IntoResponse, Response},
+    };
+
+    impl FromRef<Paystack> for Webhooks {
+        fn from_ref(paystack: &Paystack) -> Webhooks {
+            paystack.webhooks.clone()
+        }
+    }
+
+    /// Read the key from the state of an axum router
+    /// ```rust
+    /// use axum::{body::Body, extract::FromRequest, http::Request, routing::post, Router};
+    /// use paystack_rs::{asynchronous::Paystack, prelude::Event, webhooks::VerifiedEvent};
+    ///
+    /// async fn webhook(VerifiedEvent(event): VerifiedEvent) {
+    ///     if let Event::ChargeSuccess(transaction) = event {
+    ///         println!("{} paid", transaction.reference);
+    ///     }
+    /// }
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let paystack = Paystack::new("sk_test_xxxx".to_string());
+    /// let app: Router = Router::new()
+    ///     .route("/paystack/webhook", post(webhook))
+    ///     .with_state(paystack.clone());
+    ///
+    /// let body = r#"{"event":"charge.success","data":{"reference":"qTPrJoy9Bx"}}"#;
+    /// let request = Request::post("/paystack/webhook")
+    ///     .header("x-paystack-signature", paystack.webhooks.sign(body.as_bytes()))
+    ///     .body(Body::from(body))
+    ///     .unwrap();
+    /// let VerifiedEvent(event) = VerifiedEvent::from_request(request, &paystack).await.unwrap();
+    /// assert_eq!(event.name(), "charge.success");
+    ///
+    /// let forged = Request::post("/paystack/webhook").body(Body::from(body)).unwrap();
+    /// let rejection = VerifiedEvent::from_request(forged, &paystack).await.unwrap_err();
+    /// assert_eq!(rejection.status(), 401);
+    /// # }
+    /// ```
+    impl<S> FromRequest<S> for VerifiedEvent
+    where
+        Webhooks: FromRef<S>,
+        S: Send + Sync,
+    {
+        type Rejection = Response;
+
+        async fn from_request(request: Request, state: &S) -> Result<Self, Self::Rejection> {
+            let webhooks = Webhooks::from_ref(state);
+            let signature = signature(
+                request
+                    .headers()
+                    .get(SIGNATURE_HEADER)
+                    .and_then(|value| value.to_str().ok()),
+            );
+            let body = Bytes::from_request(request, state)
+                .await
+                .map_err(IntoResponse::into_response)?;
+            let event = webhooks
+                .verify_event(&body, &signature.map_err(IntoResponse::into_response)?)
+                .map_err(IntoResponse::into_response)?;
+            Ok(VerifiedEvent(event))
+        }
+    }
+
+    impl IntoResponse for WebhookError {
+        fn into_response(self) -> Response {
+            let status = StatusCode::from_u16(self.status().as_u16())
+                .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+            (status, self.to_string()).into_response()
+        }
+    }
+}
+
+#[cfg(feature = "actix-web")]
+mod actix_extract {
+    use super::{signature, VerifiedEvent, SIGNATURE_HEADER};
+    use crate::{
+        asynchronous::Paystack,
+        webhooks::{WebhookError, Webhooks},
+    };
+    use actix_web::{
+        dev::Payload, error::ErrorInternalServerError, http::StatusCode, web, FromRequest,
+        HttpRequest, ResponseError,
+    };
+    use futures_util::future::LocalBoxFuture;
+
+    /// Read the key from the app data of an actix-web app
+    /// ```rust
+    /// use actix_web::{test::TestRequest, web, App, FromRequest};
+    /// use paystack_rs::{asynchronous::Paystack, prelude::Event, webhooks::VerifiedEvent};
+    ///
+    /// async fn webhook(VerifiedEvent(event): VerifiedEvent) -> &'static str {
+    ///     match event {
+    ///         Event::TransferFailed(_) => "retrying",
+    ///         _ => "ok",
+    ///     }
+    /// }
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let paystack = web::Data::new(Paystack::new("sk_test_xxxx".to_string()));
+    /// let app = App::new()
+    ///     .app_data(paystack.clone())
+    ///     .route("/paystack/webhook", web::post().to(webhook));
+    ///
+    /// let (request, mut payload) = TestRequest::post()
+    ///     .app_data(paystack)
+    ///     .insert_header(("x-paystack-signature", "00"))
+    ///     .set_payload(r#"{"event":"transfer.failed","data":{}}"#)
+    ///     .to_http_parts();
+    /// let error = VerifiedEvent::from_request(&request, &mut payload).await.unwrap_err();
+    /// assert_eq!(error.as_response_error().status_code(), 401);
+    /// # }
+    /// ```
+    impl FromRequest for VerifiedEvent {
+        type Error = actix_web::Error;
+        type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+        fn from_request(request: &HttpRequest, payload: &mut Payload) -> Self::Future {
+            let webhooks = request
+                .app_data::<web::Data<Webhooks>>()
+                .map(|webhooks| webhooks.get_ref().clone())
+                .or_else(|| {
+                    request
+                        .app_data::<web::Data<Paystack>>()
+                        .map(|paystack| paystack.webhooks.clone())
+                });
+            let signature = signature(
+                request
+                    .headers()
+                    .get(SIGNATURE_HEADER)
+                    .and_then(|value| value.to_str().ok()),
+            );
+            let body = web::Bytes::from_request(request, payload);
+            Box::pin(async move {
+                let webhooks = webhooks.ok_or_else(|| {
+                    ErrorInternalServerError("no Paystack client or Webhooks in the app data")
+                })?;
+                let body = body.await?;
+                Ok(VerifiedEvent(webhooks.verify_event(&body, &signature?)?))
+            })
+        }
+    }
+
+    impl ResponseError for WebhookError {
+        fn status_code(&self) -> StatusCode {
+            StatusCode::from_u16(self.status().as_u16())
+                .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
@@ -0,0 +1,356 @@
+use super::{claim, with_events, Event, HandlerError, IpAllowlist, SeenEvents};
+use crate::paystack::{
+    disputes::DisputeData,
+    refund::RefundData,
+    subscription::{SubscriptionData, SubscriptionInvoice},
+    transactions::TransactionData,
+    transfers::TransferData,
+    Paystack,
+};
+use reqwest::{header::HeaderMap, StatusCode};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    net::IpAddr,
+};
+
+/// Handles one kind of event. Implemented for closures taking the client and the event, so
+/// most handlers are registered as closures; implement it on a type to share state between
+/// events or to test the handler on its own.
+pub trait EventHandler: Send + Sync {
+    /// Process the event, returning an error to have Paystack send it again later
+    fn handle(&self, paystack: &Paystack, event: Event) -> Result<(), HandlerError>;
+}
+
+impl<F> EventHandler for F
+where
+    F: Fn(&Paystack, Event) -> Result<(), HandlerError> + Send + Sync,
+{
+    fn handle(&self, paystack: &Paystack, event: Event) -> Result<(), HandlerError> {
+        self(paystack, event)
+    }
+}
+
+/// Verifies incoming webhooks, parses them and hands each event to the handler registered for
+/// it, along with the client so that handlers can call back into Paystack. Feed it the raw body
+/// and headers of the request and answer Paystack with the status it returns.
+///
+/// Handlers run before the status is returned and Paystack gives up on a webhook after a few
+/// seconds, so hand slow work to a queue rather than doing it in the handler.
+/// ```rust
+/// use paystack_rs::prelude::{Paystack, WebhookRouter};
+/// use reqwest::{header::HeaderMap, StatusCode};
+///
+/// let paystack = Paystack::new("sk_test_xxxx".to_string());
+/// let webhooks = paystack.webhooks.clone();
+/// let router = WebhookRouter::new(paystack)
+///     .on_charge_success(|paystack, transaction| {
+///         // Make sure the payment went through before fulfilling the order
+///         let verified = paystack.transaction.verify_transaction(transaction.reference)?;
+///         println!("fulfilling order for {}", verified.data.amount);
+///         Ok(())
+///     })
+///     .on_transfer_failed(|_, transfer| {
+///         println!("transfer {} failed", transfer.transfer_code);
+///         Ok(())
+///     });
+///
+/// let body = br#"{"event":"transfer.failed","data":{"transfer_code":"TRF_2x5j67tnnw1t98k"}}"#;
+/// let mut headers = HeaderMap::new();
+/// headers.insert("x-paystack-signature", webhooks.sign(body).parse().unwrap());
+///
+/// assert_eq!(router.handle(body, &headers), StatusCode::OK);
+/// assert_eq!(router.handle(body, &HeaderMap::new()), StatusCode::UNAUTHORIZED);
+/// ```
+pub struct WebhookRouter {
+    paystack: Paystack,
+    handlers: HashMap<String, Box<dyn EventHandler>>,
+    /// The events whose handler takes the model of their `data` rather than the [`Event`]
+    typed: HashSet<&'static str>,
+    fallback: Option<Box<dyn EventHandler>>,
+    allowlist: Option<IpAllowlist>,
+    seen: Option<Box<dyn SeenEvents>>,
+}
+
+/// Registers a typed handler for each event whose `data` has a model, one event at a time
+macro_rules! typed_handlers {
+    () => {};
+    ($(#[doc = $doc:literal])* $name:literal => $variant:ident($data:ty) => $method:ident, $($rest:tt)*) => {
+        impl WebhookRouter {
+            #[doc = concat!("Handle `", $name, "`:")]
+            $(#[doc = $doc])*
+            pub fn $method<F>(self, handler: F) -> WebhookRouter
+            where
+                F: Fn(&Paystack, $data) -> Result<(), HandlerError> + Send + Sync + 'static,
+            {
+                let mut router = self.on($name, move |paystack: &Paystack, event: Event| match event {
+                    Event::$variant(data) => handler(paystack, data),
+                    other => Err(format!("{} data does not fit its model", other.name()).into()),
+                });
+                router.typed.insert($name);
+                router
+            }
+        }
+        typed_handlers! { $($rest)* }
+    };
+    ($(#[doc = $doc:literal])* $name:literal => $variant:ident($data:ty), $($rest:tt)*) => {
+        typed_handlers! { $($rest)* }
+    };
+}
+
+impl WebhookRouter {
+    /// Route webhooks verified with the client's secret key
+    pub fn new(paystack: Paystack) -> WebhookRouter {
+        WebhookRouter {
+            paystack,
+            handlers: HashMap::new(),
+            typed: HashSet::new(),
+            fallback: None,
+            allowlist: None,
+            seen: None,
+        }
+    }
+
+    /// Handle every event named `event`, e.g. `dedicatedaccount.assign.success`. Registering
+    /// another handler for the same event replaces this one.
+    pub fn on<H>(mut self, event: &str, handler: H) -> WebhookRouter
+    where
+        H: EventHandler + 'static,
+    {
+        self.handlers.insert(event.to_string(), Box::new(handler));
+        self.typed.remove(event);
+        self
+    }
+
+    /// Handle the events no other handler is registered for, and the known events whose `data`
+    /// doesn't fit the model their typed handler takes, as [`Event::Unknown`]. Without one the
+    /// former are acknowledged and dropped, and the latter fail with a 500.
+    pub fn fallback<H>(mut self, handler: H) -> WebhookRouter
+    where
+        H: EventHandler + 'static,
+    {
+        self.fallback = Some(Box::new(handler));
+        self
+    }
+
+    /// Only accept webhooks sent from the allowlist's addresses. The sender can then only be
+    /// checked by [`WebhookRouter::handle_from`], so [`WebhookRouter::handle`] rejects every
+    /// webhook with a 403.
+    pub fn allowlist(mut self, allowlist: IpAllowlist) -> WebhookRouter {
+        self.allowlist = Some(allowlist);
+        self
+    }
+
+    /// Skip the events `seen` already recorded, acknowledging them without running their
+    /// handlers again
+    pub fn seen_events<S>(mut self, seen: S) -> WebhookRouter
+    where
+        S: SeenEvents + 'static,
+    {
+        self.seen = Some(Box::new(seen));
+        self
+    }
+
+    /// Verify, parse and dispatch a webhook. Returns 200 once the event is handled or when
+    /// nothing handles it, 500 when its handler fails or can't take its `data`, and the status
+    /// of the [`WebhookError`] when the webhook is rejected.
+    ///
+    /// [`WebhookError`]: super::WebhookError
+    pub fn handle(&self, body: &[u8], headers: &HeaderMap) -> StatusCode {
+        self.dispatch(None, body, headers)
+    }
+
+    /// Like [`WebhookRouter::handle`], first checking the sender against the allowlist given
+    /// the `peer` address of the connection
+    pub fn handle_from(&self, peer: IpAddr, body: &[u8], headers: &HeaderMap) -> StatusCode {
+        self.dispatch(Some(peer), body, headers)
+    }
+
+    fn dispatch(&self, peer: Option<IpAddr>, body: &[u8], headers: &HeaderMap) -> StatusCode {
+        let accepted = self
+            .paystack
+            .webhooks
+            .accept(self.allowlist.as_ref(), peer, body, headers);
+        let event = match accepted {
+            Ok(event) => event,
+            Err(err) => {
+                tracing::warn!(error = %err, "rejected webhook");
+                return err.status();
+            }
+        };
+        let claim = match claim(self.seen.as_deref(), &event) {
+            Ok(claim) => claim,
+            Err(status) => return status,
+        };
+        let name = event.name().to_string();
+        let typed = self.typed.contains(name.as_str());
+        let handler = match (self.handlers.get(&name), &self.fallback) {
+            // A typed handler can't take data that doesn't fit its model, so the fallback gets
+            // it as it was sent. Without a fallback the typed handler fails it instead.
+            (Some(_), Some(fallback)) if typed && event.is_malformed() => Some(fallback),
+            (handler, fallback) => handler.or(fallback.as_ref()),
+        };
+        let handler = match handler {
+            Some(handler) => handler,
+            None => {
+                claim.keep();
+                return StatusCode::OK;
+            }
+        };
+        match handler.handle(&self.paystack, event) {
+            Ok(()) => {
+                claim.keep();
+                StatusCode::OK
+            }
+            Err(err) => {
+                tracing::warn!(event = %name, error = %err, "webhook handler failed");
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+with_events!(typed_handlers);
+
+impl fmt::Debug for WebhookRouter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebhookRouter")
+            .field("events", &self.handlers.keys().collect::<Vec<_>>())
+            .field("fallback", &self.fallback.is_some())
+            .field("allowlist", &self.allowlist)
+            .field("seen", &self.seen.is_some())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::webhooks::MemorySeenEvents;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    };
+
+    /// A `charge.success` whose amount came as a list, so it can't be a `TransactionData`
+    const MALFORMED: &[u8] =
+        br#"{"event":"charge.success","data":{"id":302961,"reference":"qTPrJoy9Bx","amount":[10000]}}"#;
+
+    fn paystack() -> Paystack {
+        Paystack::new("sk_test_xxxx".to_string())
+    }
+
+    fn signed(paystack: &Paystack, body: &[u8]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "x-paystack-signature",
+            paystack.webhooks.sign(body).parse().unwrap(),
+        );
+        headers
+    }
+
+    fn counting_router(paystack: &Paystack, charged: &Arc<AtomicUsize>) -> WebhookRouter {
+        let charged = charged.clone();
+        WebhookRouter::new(paystack.clone())
+            .seen_events(MemorySeenEvents::new())
+            .on_charge_success(move |_, _| {
+                charged.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            })
+    }
+
+    #[test]
+    fn malformed_event_without_fallback_fails_every_delivery() {
+        let paystack = paystack();
+        let charged = Arc::new(AtomicUsize::new(0));
+        let router = counting_router(&paystack, &charged);
+        let headers = signed(&paystack, MALFORMED);
+
+        assert_eq!(
+            router.handle(MALFORMED, &headers),
+            StatusCode::INTERNAL_SERVER_ERROR
+        );
+        // The claim was handed back, so Paystack's retry isn't acknowledged as a duplicate
+        assert_eq!(
+            router.handle(MALFORMED, &headers),
+            StatusCode::INTERNAL_SERVER_ERROR
+        );
+        assert_eq!(charged.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn malformed_event_goes_to_fallback_as_sent() {
+        let paystack = paystack();
+        let charged = Arc::new(AtomicUsize::new(0));
+        let fallen_back = Arc::new(Mutex::new(Vec::new()));
+        let recorded = fallen_back.clone();
+        let router = counting_router(&paystack, &charged).fallback(move |_: &Paystack, event| {
+            recorded.lock().unwrap().push(event);
+            Ok(())
+        });
+
+        let headers = signed(&paystack, MALFORMED);
+        assert_eq!(router.handle(MALFORMED, &headers), StatusCode::OK);
+        assert_eq!(charged.load(Ordering::SeqCst), 0);
+        match fallen_back.lock().unwrap().as_slice() {
+            [Event::Unknown { event, data }] => {
+                assert_eq!(event, "charge.success");
+                assert_eq!(data["amount"][0], 10000);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+
+        let body = br#"{"event":"charge.success","data":{"reference":"T100","amount":10000}}"#;
+        assert_eq!(
+            router.handle(body, &signed(&paystack, body)),
+            StatusCode::OK
+        );
+        assert_eq!(charged.load(Ordering::SeqCst), 1);
+        assert_eq!(fallen_back.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn untyped_handler_gets_malformed_event() {
+        let paystack = paystack();
+        let handled = Arc::new(AtomicUsize::new(0));
+        let counter = handled.clone();
+        let router = WebhookRouter::new(paystack.clone()).on(
+            "charge.success",
+            move |_: &Paystack, event: Event| {
+                assert!(matches!(event, Event::Unknown { .. }));
+                counter.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            },
+        );
+
+        let headers = signed(&paystack, MALFORMED);
+        assert_eq!(router.handle(MALFORMED, &headers), StatusCode::OK);
+        assert_eq!(handled.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn panicking_handler_runs_again_on_retry() {
+        let paystack = paystack();
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let counter = attempts.clone();
+        let router = WebhookRouter::new(paystack.clone())
+            .seen_events(MemorySeenEvents::new())
+            .on_transfer_success(move |_, _| {
+                if counter.fetch_add(1, Ordering::SeqCst) == 0 {
+                    panic!("handler crashed");
+                }
+                Ok(())
+            });
+
+        let body = br#"{"event":"transfer.success","data":{"transfer_code":"TRF_1"}}"#;
+        let headers = signed(&paystack, body);
+        let crashed = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            router.handle(body, &headers)
+        }));
+        assert!(crashed.is_err());
+
+        assert_eq!(router.handle(body, &headers), StatusCode::OK);
+        assert_eq!(router.handle(body, &headers), StatusCode::OK);
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+    }
+}
@@ -0,0 +1,275 @@
+use super::Event;
+use reqwest::StatusCode;
+use std::{
+    collections::{HashSet, VecDeque},
+    fmt,
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+/// Remembers the events that were handled, by [`Event::key`], so that the router acknowledges
+/// the copies Paystack sends again without running their handlers twice. Implement it over a
+/// shared database to deduplicate across several instances of a service.
+///
+/// The router claims an event with `insert` before handling it, so two deliveries arriving at
+/// once don't both run, and hands it back with `remove` when the handler fails or panics, so
+/// that Paystack's next attempt runs it again.
+/// ```rust
+/// use paystack_rs::prelude::{MemorySeenEvents, Paystack, WebhookRouter};
+/// use reqwest::{header::HeaderMap, StatusCode};
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use std::sync::Arc;
+///
+/// let fulfilled = Arc::new(AtomicUsize::new(0));
+/// let counter = fulfilled.clone();
+/// let paystack = Paystack::new("sk_test_xxxx".to_string());
+/// let router = WebhookRouter::new(paystack.clone())
+///     .seen_events(MemorySeenEvents::new())
+///     .on_charge_success(move |_, _| {
+///         counter.fetch_add(1, Ordering::SeqCst);
+///         Ok(())
+///     });
+///
+/// let body = br#"{"event":"charge.success","data":{"reference":"qTPrJoy9Bx"}}"#;
+/// let mut headers = HeaderMap::new();
+/// headers.insert("x-paystack-signature", paystack.webhooks.sign(body).parse().unwrap());
+///
+/// // Paystack retried the webhook
+/// assert_eq!(router.handle(body, &headers), StatusCode::OK);
+/// assert_eq!(router.handle(body, &headers), StatusCode::OK);
+/// assert_eq!(fulfilled.load(Ordering::SeqCst), 1);
+/// ```
+pub trait SeenEvents: Send + Sync {
+    /// Record `key`, returning `false` if it was already recorded
+    fn insert(&self, key: &str) -> io::Result<bool>;
+    /// Forget `key`
+    fn remove(&self, key: &str) -> io::Result<()>;
+}
+
+/// Keeps the keys of handled events in memory, so they are forgotten when the process exits.
+/// Paystack retries a webhook for up to 72 hours, so a service that restarts more often than
+/// that should use [`FileSeenEvents`] or a store of its own.
+/// ```rust
+/// use paystack_rs::prelude::{MemorySeenEvents, SeenEvents};
+///
+/// let seen = MemorySeenEvents::with_capacity(2);
+/// assert!(seen.insert("charge.success:ref_1").unwrap());
+/// assert!(!seen.insert("charge.success:ref_1").unwrap());
+///
+/// seen.insert("charge.success:ref_2").unwrap();
+/// seen.insert("charge.success:ref_3").unwrap();
+/// // The oldest key made way for the newest
+/// assert!(seen.insert("charge.success:ref_1").unwrap());
+/// ```
+#[derive(Debug, Default)]
+pub struct MemorySeenEvents {
+    capacity: Option<usize>,
+    keys: Mutex<MemoryKeys>,
+}
+
+#[derive(Debug, Default)]
+struct MemoryKeys {
+    set: HashSet<String>,
+    order: VecDeque<String>,
+}
+
+impl MemorySeenEvents {
+    /// Remember every key for as long as the process runs
+    pub fn new() -> MemorySeenEvents {
+        MemorySeenEvents::default()
+    }
+
+    /// Remember the `capacity` most recent keys, forgetting the oldest to make room
+    pub fn with_capacity(capacity: usize) -> MemorySeenEvents {
+        MemorySeenEvents {
+            capacity: Some(capacity),
+            keys: Mutex::default(),
+        }
+    }
+}
+
+impl SeenEvents for MemorySeenEvents {
+    fn insert(&self, key: &str) -> io::Result<bool> {
+        // Each update leaves the keys consistent, so a panic while holding the lock is harmless
+        let mut keys = self
+            .keys
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if !keys.set.insert(key.to_string()) {
+            return Ok(false);
+        }
+        keys.order.push_back(key.to_string());
+        if let Some(capacity) = self.capacity {
+            while keys.order.len() > capacity {
+                if let Some(oldest) = keys.order.pop_front() {
+                    keys.set.remove(&oldest);
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    fn remove(&self, key: &str) -> io::Result<()> {
+        let mut keys = self
+            .keys
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if keys.set.remove(key) {
+            keys.order.retain(|seen| seen != key);
+        }
+        Ok(())
+    }
+}
+
+/// Keeps the keys of handled events in a file, one per line, so they survive restarts. Keys are
+/// appended as events are claimed; the file is rewritten when one is removed.
+/// ```rust
+/// use paystack_rs::prelude::{FileSeenEvents, SeenEvents};
+///
+/// let path = std::env::temp_dir().join(format!("paystack-seen-{}.log", std::process::id()));
+/// let seen = FileSeenEvents::open(&path).unwrap();
+/// assert!(seen.insert("transfer.success:TRF_1").unwrap());
+///
+/// // Another run of the service still knows about it
+/// let reopened = FileSeenEvents::open(&path).unwrap();
+/// assert!(!reopened.insert("transfer.success:TRF_1").unwrap());
+/// # std::fs::remove_file(&path).unwrap();
+/// ```
+pub struct FileSeenEvents {
+    path: PathBuf,
+    state: Mutex<FileState>,
+}
+
+struct FileState {
+    keys: HashSet<String>,
+    file: File,
+}
+
+impl FileSeenEvents {
+    /// Use the keys recorded in `path`, creating the file if needed
+    pub fn open(path: impl AsRef<Path>) -> io::Result<FileSeenEvents> {
+        let path = path.as_ref().to_path_buf();
+        let keys = match fs::read_to_string(&path) {
+            Ok(contents) => contents
+                .lines()
+                .filter(|line| !line.is_empty())
+                .map(str::to_string)
+                .collect(),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => HashSet::new(),
+            Err(err) => return Err(err),
+        };
+        let file = append(&path)?;
+        Ok(FileSeenEvents {
+            path,
+            state: Mutex::new(FileState { keys, file }),
+        })
+    }
+}
+
+fn append(path: &Path) -> io::Result<File> {
+    OpenOptions::new().create(true).append(true).open(path)
+}
+
+impl SeenEvents for FileSeenEvents {
+    fn insert(&self, key: &str) -> io::Result<bool> {
+        if key.contains('\n') {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "event keys cannot contain line breaks",
+            ));
+        }
+        let mut state = self
+            .state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if state.keys.contains(key) {
+            return Ok(false);
+        }
+        writeln!(state.file, "{}", key)?;
+        state.file.sync_data()?;
+        state.keys.insert(key.to_string());
+        Ok(true)
+    }
+
+    fn remove(&self, key: &str) -> io::Result<()> {
+        let mut state = self
+            .state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if !state.keys.remove(key) {
+            return Ok(());
+        }
+        // Write the remaining keys next to the file and swap it in, so a crash halfway through
+        // leaves the previous list in place
+        let mut staging = self.path.clone().into_os_string();
+        staging.push(".tmp");
+        let mut contents = String::new();
+        for key in &state.keys {
+            contents.push_str(key);
+            contents.push('\n');
+        }
+        fs::write(&staging, contents)?;
+        fs::rename(&staging, &self.path)?;
+        state.file = append(&self.path)?;
+        Ok(())
+    }
+}
+
+impl fmt::Debug for FileSeenEvents {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FileSeenEvents")
+            .field("path", &self.path)
+            .finish()
+    }
+}
+
+/// An event recorded in the seen store while its handler runs. Dropping it without calling
+/// [`Claim::keep`], when the handler fails, panics or is cancelled, hands the event back so
+/// that it runs again when Paystack retries.
+pub(crate) struct Claim<'a> {
+    claimed: Option<(&'a dyn SeenEvents, String)>,
+}
+
+/// Claim an event before handling it. An empty claim when it can't be tracked, and the status
+/// to answer with right away when it is a duplicate or the store failed.
+pub(crate) fn claim<'a>(
+    seen: Option<&'a dyn SeenEvents>,
+    event: &Event,
+) -> Result<Claim<'a>, StatusCode> {
+    let (seen, key) = match (seen, event.key()) {
+        (Some(seen), Some(key)) => (seen, key),
+        _ => return Ok(Claim { claimed: None }),
+    };
+    match seen.insert(&key) {
+        Ok(true) => Ok(Claim {
+            claimed: Some((seen, key)),
+        }),
+        Ok(false) => {
+            tracing::debug!(key = %key, "acknowledged duplicate webhook");
+            Err(StatusCode::OK)
+        }
+        Err(err) => {
+            tracing::warn!(key = %key, error = %err, "could not record webhook");
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
+impl Claim<'_> {
+    /// Keep the event recorded, once it is handled
+    pub(crate) fn keep(mut self) {
+        self.claimed = None;
+    }
+}
+
+impl Drop for Claim<'_> {
+    fn drop(&mut self) {
+        if let Some((seen, key)) = self.claimed.take() {
+            if let Err(err) = seen.remove(&key) {
+                tracing::warn!(key = %key, error = %err, "could not release webhook");
+            }
+        }
+    }
+}
@@ -0,0 +1,352 @@
+use super::{Event, Webhooks, SIGNATURE_HEADER};
+use crate::{
+    money::Money,
+    paystack::{refund::RefundData, transactions::TransactionData, transfers::TransferData},
+    secret::{KeyMode, SecretKey},
+};
+use chrono::Utc;
+use rand::{distributions::Alphanumeric, Rng};
+use reqwest::{
+    header::{HeaderMap, HeaderValue, CONTENT_TYPE},
+    StatusCode,
+};
+use std::time::Duration;
+
+/// How long to wait for the receiving service to answer each attempt
+#[cfg(any(feature = "blocking", feature = "async"))]
+const ATTEMPT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Produces webhooks the way Paystack does, to test a service that receives them without a
+/// live account: events are serialized like Paystack's, signed with the given key and, if
+/// asked, posted to a URL and sent again on the schedule Paystack follows until they are
+/// answered with a 200.
+/// ```rust
+/// use paystack_rs::prelude::{
+///     Currency, Money, Paystack, SecretKey, WebhookRouter, WebhookSimulator,
+/// };
+/// use paystack_rs::webhooks::Event;
+/// use reqwest::StatusCode;
+///
+/// let simulator = WebhookSimulator::new(SecretKey::new("sk_test_xxxx".to_string()));
+/// let event = Event::charge_success("order-1042", Money::from_major(2_500, Currency::NGN).unwrap());
+/// let webhook = simulator.sign(&event);
+///
+/// let router = WebhookRouter::new(Paystack::new("sk_test_xxxx".to_string()))
+///     .on_charge_success(|_, transaction| {
+///         assert_eq!(transaction.amount, 250_000);
+///         Ok(())
+///     });
+/// assert_eq!(router.handle(&webhook.body, &webhook.headers()), StatusCode::OK);
+/// ```
+/// Posting it to a service, here one that fails the first attempt:
+/// ```rust
+/// # use std::io::{BufRead, BufReader, Read, Write};
+/// # use std::net::TcpListener;
+/// # /// Answer each request with the next status
+/// # fn serve(statuses: Vec<u16>) -> String {
+/// #     let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+/// #     let url = format!("http://{}/webhook", listener.local_addr().unwrap());
+/// #     std::thread::spawn(move || {
+/// #         for status in statuses {
+/// #             let (stream, _) = listener.accept().unwrap();
+/// #             let mut reader = BufReader::new(stream);
+/// #             let mut length = 0;
+/// #             loop {
+/// #                 let mut line = String::new();
+/// #                 reader.read_line(&mut line).unwrap();
+/// #                 if let Some(value) = line.to_lowercase().strip_prefix("content-length:") {
+/// #                     length = value.trim().parse().unwrap();
+/// #                 }
+/// #                 if line == "\r\n" {
+/// #                     break;
+/// #                 }
+/// #             }
+/// #             reader.read_exact(&mut vec![0; length]).unwrap();
+/// #             let response = format!("HTTP/1.1 {} X\r\ncontent-length: 0\r\nconnection: close\r\n\r\n", status);
+/// #             reader.get_mut().write_all(response.as_bytes()).unwrap();
+/// #         }
+/// #     });
+/// #     url
+/// # }
+/// use paystack_rs::prelude::{Currency, Money, SecretKey, WebhookSimulator};
+/// use paystack_rs::webhooks::{DeliveryAttempt, Event};
+/// use reqwest::StatusCode;
+/// use std::time::Duration;
+///
+/// let url = serve(vec![503, 200]);
+/// let simulator = WebhookSimulator::new(SecretKey::new("sk_test_xxxx".to_string()))
+///     // Paystack waits an hour before trying again; the test shouldn't
+///     .retry_schedule(vec![Duration::from_millis(10); 3]);
+/// let event = Event::transfer_failed("payout-77", Money::from_major(500, Currency::NGN).unwrap());
+///
+/// let delivery = simulator.deliver(&url, &event);
+/// assert!(delivery.delivered());
+/// assert_eq!(
+///     delivery.attempts,
+///     vec![
+///         DeliveryAttempt::Status(StatusCode::SERVICE_UNAVAILABLE),
+///         DeliveryAttempt::Status(StatusCode::OK)
+///     ]
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct WebhookSimulator {
+    webhooks: Webhooks,
+    schedule: Vec<Duration>,
+}
+
+/// A serialized and signed webhook, as Paystack would send it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignedWebhook {
+    /// The JSON body
+    pub body: Vec<u8>,
+    /// The hex encoded signature of the body, sent in `x-paystack-signature`
+    pub signature: String,
+}
+
+/// The attempts made at delivering a webhook, in order
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Delivery {
+    /// One entry per attempt; the last one is a 200 when the webhook was delivered
+    pub attempts: Vec<DeliveryAttempt>,
+}
+
+/// The outcome of one attempt at delivering a webhook
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeliveryAttempt {
+    /// The service answered with this status
+    Status(StatusCode),
+    /// The request failed before a status came back, e.g. the connection was refused
+    Failed(String),
+}
+
+impl WebhookSimulator {
+    /// Sign webhooks with `key`, retrying them on the schedule Paystack uses for its mode
+    pub fn new(key: SecretKey) -> WebhookSimulator {
+        let mode = key.mode().unwrap_or(KeyMode::Test);
+        WebhookSimulator {
+            webhooks: Webhooks::new(key),
+            schedule: WebhookSimulator::paystack_schedule(mode),
+        }
+    }
+
+    /// The delays before each retry of a webhook that wasn't answered with a 200. Live
+    /// integrations are retried every 3 minutes for the first 4 tries, then hourly for 72
+    /// hours; test integrations hourly for 72 hours.
+    pub fn paystack_schedule(mode: KeyMode) -> Vec<Duration> {
+        const MINUTE: Duration = Duration::from_secs(60);
+        const HOUR: Duration = Duration::from_secs(60 * 60);
+        let quick = match mode {
+            KeyMode::Live => 4,
+            KeyMode::Test => 0,
+        };
+        let mut schedule = vec![3 * MINUTE; quick];
+        schedule.extend(vec![HOUR; 72]);
+        schedule
+    }
+
+    /// Wait these delays between attempts instead, e.g. milliseconds in a test. An empty
+    /// schedule makes a single attempt.
+    pub fn retry_schedule(mut self, schedule: Vec<Duration>) -> WebhookSimulator {
+        self.schedule = schedule;
+        self
+    }
+
+    /// Serialize and sign the event
+    pub fn sign(&self, event: &Event) -> SignedWebhook {
+        let body = serde_json::to_vec(event).expect("events serialize to JSON");
+        let signature = self.webhooks.sign(&body);
+        SignedWebhook { body, signature }
+    }
+
+    /// Post the signed event to `url`, trying again on the retry schedule until it is answered
+    /// with a 200. Blocks for as long as the schedule runs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the TLS backend cannot be initialized, like `reqwest::blocking::Client::new`.
+    #[cfg(feature = "blocking")]
+    pub fn deliver(&self, url: &str, event: &Event) -> Delivery {
+        let client = reqwest::blocking::Client::builder()
+            .timeout(ATTEMPT_TIMEOUT)
+            .build()
+            .expect("failed to initialize HTTP client");
+        let webhook = self.sign(event);
+        let mut delivery = Delivery::default();
+        for delay in std::iter::once(None).chain(self.schedule.iter().map(Some)) {
+            if let Some(delay) = delay {
+                std::thread::sleep(*delay);
+            }
+            let attempt = client
+                .post(url)
+                .headers(webhook.headers())
+                .body(webhook.body.clone())
+                .send()
+                .map(|response| response.status());
+            if delivery.record(event, attempt) {
+                break;
+            }
+        }
+        delivery
+    }
+
+    /// The async flavour of [`WebhookSimulator::deliver`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if the TLS backend cannot be initialized, like `reqwest::Client::new`.
+    #[cfg(feature = "async")]
+    pub async fn deliver_async(&self, url: &str, event: &Event) -> Delivery {
+        let client = reqwest::Client::builder()
+            .timeout(ATTEMPT_TIMEOUT)
+            .build()
+            .expect("failed to initialize HTTP client");
+        let webhook = self.sign(event);
+        let mut delivery = Delivery::default();
+        for delay in std::iter::once(None).chain(self.schedule.iter().map(Some)) {
+            if let Some(delay) = delay {
+                tokio::time::sleep(*delay).await;
+            }
+            let attempt = client
+                .post(url)
+                .headers(webhook.headers())
+                .body(webhook.body.clone())
+                .send()
+                .await
+                .map(|response| response.status());
+            if delivery.record(event, attempt) {
+                break;
+            }
+        }
+        delivery
+    }
+}
+
+impl SignedWebhook {
+    /// The headers Paystack sends along with the body
+    pub fn headers(&self) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        headers.insert(
+            SIGNATURE_HEADER,
+            HeaderValue::from_str(&self.signature).expect("hex is a valid header value"),
+        );
+        headers
+    }
+}
+
+impl Delivery {
+    /// Whether the last attempt was answered with a 200
+    pub fn delivered(&self) -> bool {
+        self.attempts.last() == Some(&DeliveryAttempt::Status(StatusCode::OK))
+    }
+
+    /// Record an attempt, returning whether it delivered the webhook
+    #[cfg(any(feature = "blocking", feature = "async"))]
+    fn record(&mut self, event: &Event, attempt: Result<StatusCode, reqwest::Error>) -> bool {
+        let attempt = match attempt {
+            Ok(status) => DeliveryAttempt::Status(status),
+            Err(err) => DeliveryAttempt::Failed(err.without_url().to_string()),
+        };
+        tracing::debug!(event = %event.name(), attempt = ?attempt, "delivered simulated webhook");
+        self.attempts.push(attempt);
+        self.delivered()
+    }
+}
+
+/// Builders for the events tests need most. Fields they leave out keep their defaults, and the
+/// payloads can be adjusted before signing since every field is public.
+impl Event {
+    /// A `charge.success` for a card payment of `amount` with this reference
+    pub fn charge_success(reference: &str, amount: Money) -> Event {
+        let now = Some(Utc::now());
+        Event::ChargeSuccess(TransactionData {
+            id: random_id(),
+            domain: "test".to_string(),
+            status: "success".to_string(),
+            reference: reference.to_string(),
+            amount: amount.minor(),
+            requested_amount: Some(amount.minor()),
+            currency: amount.currency().clone(),
+            channel: "card".to_string(),
+            gateway_response: Some("Successful".to_string()),
+            created_at: now,
+            paid_at: now,
+            ..Default::default()
+        })
+    }
+
+    /// A `transfer.success` for a transfer of `amount` with this reference
+    pub fn transfer_success(reference: &str, amount: Money) -> Event {
+        Event::TransferSuccess(transfer(reference, amount, "success"))
+    }
+
+    /// A `transfer.failed` for a transfer of `amount` with this reference
+    pub fn transfer_failed(reference: &str, amount: Money) -> Event {
+        Event::TransferFailed(transfer(reference, amount, "failed"))
+    }
+
+    /// A `transfer.reversed` for a transfer of `amount` with this reference
+    pub fn transfer_reversed(reference: &str, amount: Money) -> Event {
+        Event::TransferReversed(transfer(reference, amount, "reversed"))
+    }
+
+    /// A `refund.processed` refunding `amount` of the transaction with this reference. Like
+    /// Paystack's, it names the refund by the processor's reference only, and sends the amount
+    /// as a string.
+    /// ```rust
+    /// use paystack_rs::prelude::{Currency, Money, SecretKey, WebhookSimulator};
+    /// use paystack_rs::webhooks::Event;
+    ///
+    /// let simulator = WebhookSimulator::new(SecretKey::new("sk_test_xxxx".to_string()));
+    /// let event = Event::refund_processed("T685312322670591", Money::from_minor(10_000, Currency::NGN));
+    /// let body: serde_json::Value = serde_json::from_slice(&simulator.sign(&event).body).unwrap();
+    /// assert_eq!(body["data"]["amount"], "10000");
+    /// assert_eq!(body["data"]["transaction_reference"], "T685312322670591");
+    /// ```
+    pub fn refund_processed(transaction_reference: &str, amount: Money) -> Event {
+        Event::RefundProcessed(RefundData {
+            domain: Some("test".to_string()),
+            integration: Some(random_id()),
+            status: "processed".to_string(),
+            amount: amount.minor(),
+            currency: amount.currency().clone(),
+            transaction_reference: Some(transaction_reference.to_string()),
+            refund_reference: Some(random_id().to_string()),
+            processor: Some("mpgs_zen".to_string()),
+            ..Default::default()
+        })
+    }
+}
+
+fn transfer(reference: &str, amount: Money, status: &str) -> TransferData {
+    let now = Some(Utc::now());
+    TransferData {
+        id: random_id(),
+        domain: "test".to_string(),
+        status: status.to_string(),
+        transfer_code: random_code("TRF_"),
+        reference: reference.to_string(),
+        amount: amount.minor(),
+        currency: amount.currency().clone(),
+        source: "balance".to_string(),
+        transferred_at: now.filter(|_| status == "success"),
+        created_at: now,
+        updated_at: now,
+        ..Default::default()
+    }
+}
+
+fn random_id() -> i64 {
+    rand::thread_rng().gen_range(1_000_000..1_000_000_000)
+}
+
+/// A code like the ones Paystack generates, e.g. `TRF_wpl1dem4967avzm`
+fn random_code(prefix: &str) -> String {
+    let code: String = rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(15)
+        .map(char::from)
+        .collect();
+    format!("{}{}", prefix, code.to_lowercase())
+}